@@ -0,0 +1,104 @@
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+/// AI game launcher
+///
+/// Starts the graphical installer when called without a command.
+#[derive(Debug, Parser)]
+#[command(version, args_conflicts_with_subcommands = true)]
+pub struct Cli {
+    /// Open the installer with a game selected.
+    ///
+    /// Either a link like `aigl://install/<game>?name=<bot name>` or a downloaded `.aigl` game file.
+    #[arg(value_name = "LINK")]
+    pub link: Option<String>,
+    /// Where to look for game configs: an https:// URL, a directory, or a single file.
+    ///
+    /// Can be given multiple times. These sources are searched before those in
+    /// AIGL_GAME_SOURCES, the user config file, and the public game list.
+    #[arg(long = "game-source", value_name = "SOURCE")]
+    pub game_sources: Vec<String>,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Generate the game catalog from game config files.
+    Catalog {
+        /// Directory that contains the game config files.
+        #[arg(long, default_value = "resources/games")]
+        games_dir: PathBuf,
+        /// Write the catalog to this file instead of stdout.
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// Check an installed game for problems.
+    Doctor {
+        /// The game directory.
+        #[arg(default_value = ".")]
+        path: PathBuf,
+        /// Apply all available fixes.
+        #[arg(long)]
+        fix: bool,
+    },
+    /// Generate a key pair for signing game configs.
+    ///
+    /// Writes the private key to a file and prints the public key.
+    Keygen {
+        /// File for the private key, keep it secret.
+        private_key: PathBuf,
+    },
+    /// Open `aigl://` links and `.aigl` files with this launcher.
+    ///
+    /// Installs a desktop entry and MIME type for the current user, only supported on Linux.
+    RegisterLinks,
+    /// Print the JSON schema for game config files.
+    Schema {
+        /// Write the schema to this file instead of stdout.
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// Generate a theme for the launcher from an accent color.
+    ///
+    /// Save it as a `.ron` file in the `themes` directory of the user config to use it.
+    Theme {
+        /// Color of highlighted elements, e.g., "#3e63dd".
+        #[arg(long)]
+        accent: String,
+        /// Tint of the background and text colors.
+        #[arg(long, default_value = "#8b8d98")]
+        gray: String,
+        /// Generate a light theme instead of a dark one.
+        #[arg(long)]
+        light: bool,
+        /// Write the theme to this file instead of stdout.
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+    /// Sign game config files.
+    ///
+    /// Writes a detached signature next to each file, e.g., `game.toml.sig`.
+    Sign {
+        /// Private key created by `aigl keygen`.
+        #[arg(long)]
+        key: PathBuf,
+        /// The game config files.
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+    },
+    /// Check game config files for mistakes.
+    Validate {
+        /// The game config files.
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+    },
+    /// Check that game config files are signed by a publisher built into the launcher.
+    ///
+    /// Fails if a file has no signature next to it or is signed by another key.
+    Verify {
+        /// The game config files.
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+    },
+}
@@ -0,0 +1,15 @@
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aigl_project::catalog::Catalog;
+
+pub fn run(games_dir: PathBuf, output: Option<PathBuf>) -> Result<ExitCode> {
+    let catalog = Catalog::from_game_dir(&games_dir)?.to_toml_string()?;
+    match output {
+        Some(path) => std::fs::write(&path, catalog)
+            .with_context(|| format!("Failed to write catalog to {}", path.display()))?,
+        None => print!("{catalog}"),
+    }
+    Ok(ExitCode::SUCCESS)
+}
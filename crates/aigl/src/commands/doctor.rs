@@ -0,0 +1,65 @@
+use anyhow::Result;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aigl_project::{Finding, Project, Severity};
+
+pub fn run(path: PathBuf, fix: bool) -> Result<ExitCode> {
+    let runtime = super::start_tokio_runtime()?;
+    let result = runtime.block_on(Box::pin(check(path, fix)));
+    runtime.shutdown_background();
+    result
+}
+
+async fn check(path: PathBuf, fix: bool) -> Result<ExitCode> {
+    let mut project = Project::open(path).await?;
+    let mut findings = project.check().await;
+    report(&findings);
+
+    if fix {
+        let fixes: Vec<_> = findings.iter().filter_map(|f| f.fix.clone()).collect();
+        if fixes.is_empty() {
+            return Ok(exit_code(&findings));
+        }
+        for fix in dedup(fixes) {
+            println!("Applying fix: {}", fix.description());
+            project.fix(&fix).await?;
+        }
+        println!("\nChecking again");
+        findings = project.check().await;
+        report(&findings);
+    } else if findings.iter().any(|f| f.fix.is_some()) {
+        println!("\nRun with --fix to apply the suggested fixes.");
+    }
+    Ok(exit_code(&findings))
+}
+
+fn report(findings: &[Finding]) {
+    if findings.is_empty() {
+        println!("No problems found.");
+    }
+    for finding in findings {
+        println!("{finding}");
+        if let Some(fix) = &finding.fix {
+            println!("    fix: {}", fix.description());
+        }
+    }
+}
+
+fn dedup<T: PartialEq>(items: Vec<T>) -> Vec<T> {
+    let mut unique = Vec::with_capacity(items.len());
+    for item in items {
+        if !unique.contains(&item) {
+            unique.push(item);
+        }
+    }
+    unique
+}
+
+fn exit_code(findings: &[Finding]) -> ExitCode {
+    if findings.iter().any(|f| f.severity == Severity::Error) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
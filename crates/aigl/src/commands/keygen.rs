@@ -0,0 +1,31 @@
+use anyhow::{Context, Result, bail};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aigl_project::signing::generate_key_pair;
+
+pub fn run(private_key_path: PathBuf) -> Result<ExitCode> {
+    if private_key_path.exists() {
+        bail!("{} already exists", private_key_path.display());
+    }
+    let (private_key, public_key) = generate_key_pair()?;
+    write_private(&private_key_path, private_key.as_bytes()).with_context(|| {
+        format!(
+            "Failed to write private key to {}",
+            private_key_path.display()
+        )
+    })?;
+    println!("Wrote private key to {}", private_key_path.display());
+    println!("Public key: {public_key}");
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Create a new file that only the current user can read and write.
+fn write_private(path: &Path, content: &[u8]) -> std::io::Result<()> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(path)?.write_all(content)
+}
@@ -0,0 +1,39 @@
+mod catalog;
+mod doctor;
+mod keygen;
+mod register_links;
+mod schema;
+mod sign;
+mod theme;
+mod validate;
+mod verify;
+
+use anyhow::Result;
+use std::process::ExitCode;
+
+use crate::cli::Command;
+
+pub fn run(command: Command) -> Result<ExitCode> {
+    match command {
+        Command::Catalog { games_dir, output } => catalog::run(games_dir, output),
+        Command::Doctor { path, fix } => doctor::run(path, fix),
+        Command::Keygen { private_key } => keygen::run(private_key),
+        Command::RegisterLinks => register_links::run(),
+        Command::Schema { output } => schema::run(output),
+        Command::Sign { key, files } => sign::run(key, files),
+        Command::Theme {
+            accent,
+            gray,
+            light,
+            output,
+        } => theme::run(&accent, &gray, light, output),
+        Command::Validate { files } => validate::run(files),
+        Command::Verify { files } => verify::run(files),
+    }
+}
+
+fn start_tokio_runtime() -> Result<tokio::runtime::Runtime> {
+    Ok(tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?)
+}
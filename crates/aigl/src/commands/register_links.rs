@@ -0,0 +1,33 @@
+use anyhow::Result;
+use std::process::ExitCode;
+
+#[cfg(target_os = "linux")]
+pub fn run() -> Result<ExitCode> {
+    use aigl_project::link::{GAME_FILE_EXTENSION, LINK_SCHEME};
+    use aigl_system::desktop::{FileType, MimeHandler};
+
+    let executable = std::env::current_exe()?;
+    let handler = MimeHandler {
+        id: "aigl",
+        name: "AI Game Launcher",
+        executable: &executable,
+        url_schemes: &[LINK_SCHEME],
+        file_types: &[FileType {
+            mime_type: "application/x-aigl",
+            comment: "AI game",
+            extension: GAME_FILE_EXTENSION,
+        }],
+    };
+    let entry = handler.install()?;
+    println!(
+        "Registered {} for {LINK_SCHEME}:// links and .{GAME_FILE_EXTENSION} files in {}",
+        executable.display(),
+        entry.display()
+    );
+    Ok(ExitCode::SUCCESS)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn run() -> Result<ExitCode> {
+    anyhow::bail!("Registering links is only supported on Linux");
+}
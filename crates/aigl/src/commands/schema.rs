@@ -0,0 +1,15 @@
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aigl_project::schema::game_config_schema_string;
+
+pub fn run(output: Option<PathBuf>) -> Result<ExitCode> {
+    let schema = game_config_schema_string();
+    match output {
+        Some(path) => std::fs::write(&path, schema)
+            .with_context(|| format!("Failed to write schema to {}", path.display()))?,
+        None => print!("{schema}"),
+    }
+    Ok(ExitCode::SUCCESS)
+}
@@ -0,0 +1,17 @@
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aigl_project::signing::{sign, signature_path};
+
+pub fn run(key_path: PathBuf, files: Vec<PathBuf>) -> Result<ExitCode> {
+    let private_key = std::fs::read_to_string(&key_path)
+        .with_context(|| format!("Failed to read private key {}", key_path.display()))?;
+    for file in files {
+        let content =
+            std::fs::read(&file).with_context(|| format!("Failed to read {}", file.display()))?;
+        std::fs::write(signature_path(&file), sign(&content, &private_key)?)?;
+        println!("Signed {}", file.display());
+    }
+    Ok(ExitCode::SUCCESS)
+}
@@ -0,0 +1,15 @@
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aigl_app::generate_theme_ron;
+
+pub fn run(accent: &str, gray: &str, light: bool, output: Option<PathBuf>) -> Result<ExitCode> {
+    let theme = generate_theme_ron(accent, gray, !light)?;
+    match output {
+        Some(path) => std::fs::write(&path, theme)
+            .with_context(|| format!("Failed to write theme to {}", path.display()))?,
+        None => print!("{theme}"),
+    }
+    Ok(ExitCode::SUCCESS)
+}
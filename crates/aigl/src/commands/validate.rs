@@ -0,0 +1,59 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aigl_project::ValidationError;
+use aigl_project::config::game::GameConfig;
+
+pub fn run(files: Vec<PathBuf>) -> Result<ExitCode> {
+    let mut n_invalid = 0;
+    for file in &files {
+        let content = std::fs::read_to_string(file)
+            .with_context(|| format!("Failed to read {}", file.display()))?;
+        if let Err(errors) = GameConfig::validate_toml_str(&content) {
+            n_invalid += 1;
+            for error in &errors.0 {
+                report(file, &content, error);
+            }
+        }
+    }
+
+    if n_invalid == 0 {
+        println!("All game configs are valid.");
+        Ok(ExitCode::SUCCESS)
+    } else {
+        eprintln!("{n_invalid} of {} game configs are invalid.", files.len());
+        Ok(ExitCode::FAILURE)
+    }
+}
+
+fn report(file: &Path, content: &str, error: &ValidationError) {
+    let Some(span) = &error.span else {
+        eprintln!("{}: error: {}", file.display(), error.message);
+        return;
+    };
+    let start = span.start;
+    eprintln!(
+        "{}:{}:{}: error: {}",
+        file.display(),
+        start.line,
+        start.column,
+        error.message
+    );
+    if let Some(line) = content.lines().nth(start.line - 1) {
+        let width = if span.end.line == start.line {
+            span.end.column.saturating_sub(start.column).max(1)
+        } else {
+            line.chars().count().saturating_sub(start.column - 1).max(1)
+        };
+        let gutter = start.line.to_string().len();
+        eprintln!("{:gutter$} |", "");
+        eprintln!("{} | {line}", start.line);
+        eprintln!(
+            "{:gutter$} | {}{}",
+            "",
+            " ".repeat(start.column - 1),
+            "^".repeat(width)
+        );
+    }
+}
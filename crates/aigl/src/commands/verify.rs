@@ -0,0 +1,45 @@
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aigl_project::signing::{TrustPolicy, Verification, signature_path};
+
+pub fn run(files: Vec<PathBuf>) -> Result<ExitCode> {
+    // Only the built-in keys so that the result does not depend on the user config.
+    let policy = TrustPolicy::builtin_only();
+    let mut n_unverified = 0;
+    for file in &files {
+        let content =
+            std::fs::read(file).with_context(|| format!("Failed to read {}", file.display()))?;
+        let signature_path = signature_path(file);
+        let signature = match std::fs::read_to_string(&signature_path) {
+            Ok(signature) => Some(signature),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("Failed to read {}", signature_path.display()));
+            }
+        };
+        match policy.verify(&content, signature.as_deref())? {
+            Verification::Verified { publisher } => {
+                println!("{}: signed by {publisher}", file.display());
+            }
+            verification => {
+                n_unverified += 1;
+                if let Some(warning) = verification.warning() {
+                    eprintln!("{}: {warning}", file.display());
+                }
+            }
+        }
+    }
+
+    if n_unverified == 0 {
+        Ok(ExitCode::SUCCESS)
+    } else {
+        eprintln!(
+            "{n_unverified} of {} game configs are not signed by a built-in publisher.",
+            files.len()
+        );
+        Ok(ExitCode::FAILURE)
+    }
+}
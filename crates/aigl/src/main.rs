@@ -1,6 +1,68 @@
-// Hide the console window on Windows in release.
+// Hide the console window on Windows in release, commands attach to the terminal instead.
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-fn main() {
-    aigl_app::GameInstallApp::run();
+mod cli;
+mod commands;
+
+use aigl_project::link::InstallLink;
+use aigl_project::signing::TrustPolicy;
+use aigl_project::sources::GameSources;
+use anyhow::Context;
+use clap::Parser;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    // Before parsing so that the help and argument errors are shown, too.
+    // Starting the installer without arguments, e.g., from the desktop, never needs the console.
+    if std::env::args_os().nth(1).is_some() {
+        aigl_system::console::attach_to_parent_console();
+    }
+    let cli = cli::Cli::parse();
+    match cli.command {
+        None => {
+            run_app(&cli);
+            ExitCode::SUCCESS
+        }
+        Some(command) => match commands::run(command) {
+            Ok(code) => code,
+            Err(err) => {
+                eprintln!("Error: {err:?}");
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
+
+/// Start the installer, with default settings for those that cannot be loaded.
+///
+/// Release builds on Windows have no console, so errors are shown in the installer.
+fn run_app(cli: &cli::Cli) {
+    let mut errors = Vec::new();
+    let link = cli.link.as_deref().and_then(|link| {
+        std::env::current_dir()
+            .map_err(anyhow::Error::from)
+            .and_then(|cwd| InstallLink::parse(link, &cwd))
+            .with_context(|| format!("Cannot open {link}"))
+            .map_err(|err| errors.push(format!("{err:#}")))
+            .ok()
+    });
+    let game_sources = GameSources::resolve(&cli.game_sources)
+        .context("Only the public game list is searched")
+        .unwrap_or_else(|err| {
+            errors.push(format!("{err:#}"));
+            GameSources::default()
+        });
+    let trust_policy = TrustPolicy::load()
+        .context("Only games signed by the built-in publishers can be installed")
+        .unwrap_or_else(|err| {
+            errors.push(format!("{err:#}"));
+            TrustPolicy::builtin_only()
+        });
+    aigl_app::GameInstallApp::run(
+        env!("CARGO_PKG_VERSION"),
+        game_sources,
+        trust_policy,
+        link,
+        errors,
+    );
 }
@@ -0,0 +1,168 @@
+/// A piece of text with a uniform style.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct AnsiSpan {
+    pub(crate) text: String,
+    pub(crate) style: AnsiStyle,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct AnsiStyle {
+    /// One of the 16 standard colors, 8-15 are the bright variants.
+    pub(crate) color: Option<u8>,
+    pub(crate) bold: bool,
+}
+
+/// Splits lines into styled spans according to ANSI SGR escape sequences.
+///
+/// The style carries over to the next line like in a terminal.
+/// Other escape sequences are removed.
+#[derive(Debug, Default)]
+pub(crate) struct AnsiParser {
+    style: AnsiStyle,
+}
+
+impl AnsiParser {
+    pub(crate) fn parse_line(&mut self, line: &str) -> Vec<AnsiSpan> {
+        let mut spans = Vec::new();
+        let mut text = String::new();
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c != '\x1b' {
+                text.push(c);
+                continue;
+            }
+            if chars.next_if_eq(&'[').is_none() {
+                continue;
+            }
+            let mut params = String::new();
+            // The final byte of a control sequence is in the range '@'..='~'.
+            let Some(command) = chars.by_ref().find(|c| {
+                let is_final = ('@'..='~').contains(c);
+                if !is_final {
+                    params.push(*c);
+                }
+                is_final
+            }) else {
+                break;
+            };
+            if command != 'm' {
+                continue;
+            }
+            if !text.is_empty() {
+                spans.push(AnsiSpan {
+                    text: std::mem::take(&mut text),
+                    style: self.style,
+                });
+            }
+            self.apply_sgr(&params);
+        }
+        if !text.is_empty() || spans.is_empty() {
+            spans.push(AnsiSpan {
+                text,
+                style: self.style,
+            });
+        }
+        spans
+    }
+
+    fn apply_sgr(&mut self, params: &str) {
+        let mut codes = params
+            .split(';')
+            .map(|code| code.parse::<u16>().unwrap_or(0));
+        while let Some(code) = codes.next() {
+            match code {
+                0 => self.style = AnsiStyle::default(),
+                1 => self.style.bold = true,
+                22 => self.style.bold = false,
+                30..=37 => self.style.color = Some(code as u8 - 30),
+                90..=97 => self.style.color = Some(code as u8 - 90 + 8),
+                39 => self.style.color = None,
+                // Extended colors are `5;N` (256 color palette) or `2;R;G;B`.
+                // Only the standard foreground colors are supported, but the
+                // parameters must be skipped so they are not read as codes.
+                38 | 48 => {
+                    let color = match codes.next() {
+                        Some(5) => codes.next().filter(|color| *color < 16),
+                        Some(2) => {
+                            codes.by_ref().take(3).for_each(drop);
+                            None
+                        }
+                        _ => None,
+                    };
+                    if code == 38 {
+                        self.style.color = color.map(|color| color as u8);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn span(text: &str, color: Option<u8>, bold: bool) -> AnsiSpan {
+        AnsiSpan {
+            text: text.into(),
+            style: AnsiStyle { color, bold },
+        }
+    }
+
+    #[test]
+    fn plain_text_is_a_single_span() {
+        assert_eq!(
+            AnsiParser::default().parse_line("hello"),
+            vec![span("hello", None, false)]
+        );
+    }
+
+    #[test]
+    fn sgr_sequences_set_color_and_weight() {
+        assert_eq!(
+            AnsiParser::default().parse_line("a\x1b[1;31mb\x1b[0mc\x1b[92md"),
+            vec![
+                span("a", None, false),
+                span("b", Some(1), true),
+                span("c", None, false),
+                span("d", Some(10), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn style_carries_over_to_the_next_line() {
+        let mut parser = AnsiParser::default();
+        parser.parse_line("\x1b[33mwarning");
+        assert_eq!(
+            parser.parse_line("more"),
+            vec![span("more", Some(3), false)]
+        );
+    }
+
+    #[test]
+    fn other_escape_sequences_are_removed() {
+        assert_eq!(
+            AnsiParser::default().parse_line("\x1b[2Kdone\x1b[1A!"),
+            vec![span("done!", None, false)]
+        );
+    }
+
+    #[test]
+    fn extended_color_parameters_are_not_read_as_codes() {
+        assert_eq!(
+            AnsiParser::default().parse_line("\x1b[38;5;1ma\x1b[38;2;1;0;0mb\x1b[1;48;5;1mc"),
+            vec![
+                span("a", Some(1), false),
+                span("b", None, false),
+                span("c", None, true),
+            ]
+        );
+        assert_eq!(
+            AnsiParser::default().parse_line("\x1b[31;48;2;1;22;39;1md"),
+            vec![span("d", Some(1), true)]
+        );
+    }
+}
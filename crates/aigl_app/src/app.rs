@@ -1,39 +1,111 @@
+use anyhow::Result;
 use eframe::egui;
 use eframe::egui::RichText;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, RwLock};
 
+use crate::bot_tests::{BotTestsThreadData, run_tests};
 use crate::components;
-use crate::game_config::fetch_game_config;
+use crate::doctor::{DoctorThreadData, check};
+use crate::game_config::{CatalogThreadData, FetchedGame, fetch_game_config, load_catalog};
+use crate::game_run::{GameRunThreadData, run_game};
+use crate::home::{
+    HomeThreadData, ProjectAction, ProjectActionThreadData, load_projects, register_project,
+    run_project_action,
+};
+use crate::i18n::{self, LanguageChoice, t};
 use crate::install::{InstallThreadData, install};
-use crate::theme::Theme;
-use aigl_project::{BotArg, config::game::GameConfig, dir_is_incomplete};
+use crate::lint::{LintAction, LintThreadData, run_lint_action};
+use crate::match_setup::{MatchSetupThreadData, load_match, save_match};
+use crate::messages;
+use crate::plan::{PlanThreadData, plan_install};
+use crate::theme::{Theme, ThemeChoice, ThemeLibrary};
+use aigl_project::config::game::{GameConfig, Players};
+use aigl_project::link::InstallLink;
+use aigl_project::preferences::Preferences;
+use aigl_project::signing::{TrustPolicy, Verification};
+use aigl_project::sources::{GameSource, GameSources};
+use aigl_project::user_file::UserFile;
+use aigl_project::{
+    BotArg, BotArgValue, Fix, MatchPlayer, TestSelection, dir_is_incomplete, validate_match,
+};
 use aigl_system::fs::path_available_as_output_directory;
 
 pub struct GameInstallApp {
+    /// Version of the launcher binary, not of this crate.
+    launcher_version: &'static str,
     screen: Screen,
+    game_sources: GameSources,
+    trust_policy: TrustPolicy,
     game_config: Option<GameConfig>,
+    theme_library: ThemeLibrary,
+    theme_choice: ThemeChoice,
+    /// The choice and OS preference that the current theme was selected for.
+    applied_theme: Option<(ThemeChoice, Option<egui::Theme>)>,
+    language_choice: LanguageChoice,
+    applied_language: Option<LanguageChoice>,
+    /// Choices that are remembered between runs.
+    preferences: Preferences,
+    zoom_factor: f32,
+    /// The widget that had keyboard focus at the end of the previous frame.
+    focused_widget: Option<egui::Id>,
+    /// The screen that was shown in the previous frame.
+    shown_screen: Option<Screen>,
+    /// Set when a new screen is shown until its primary input is focused.
+    focus_pending: bool,
     // Store all states at the same time so we have access to all data as needed.
+    home_state: HomeState,
     select_game_state: SelectGameState,
     configure_player_state: ConfigurePlayerState,
     select_location_state: SelectLocationState,
+    overview_state: OverviewState,
     install_state: InstallState,
+    doctor_state: DoctorState,
+    bot_tests_state: BotTestsState,
+    lint_state: LintState,
+    match_setup_state: MatchSetupState,
+    game_run_state: GameRunState,
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 enum Screen {
+    #[default]
+    Home,
     SelectGame,
     ConfigurePlayer,
     SelectLocation,
     Overview,
     Installing,
     Finished,
+    Doctor,
+    BotTests,
+    Lint,
+    MatchSetup,
+    Console,
+}
+
+#[derive(Debug, Default)]
+struct HomeState {
+    thread: Option<std::thread::JoinHandle<()>>,
+    thread_data: Arc<RwLock<HomeThreadData>>,
+    action_thread: Option<std::thread::JoinHandle<()>>,
+    action_data: Arc<RwLock<ProjectActionThreadData>>,
+    /// Project that the player wants to uninstall, waiting for confirmation.
+    confirm_uninstall: Option<PathBuf>,
+    error: Option<String>,
 }
 
 #[derive(Debug, Default)]
 struct SelectGameState {
     game_code: String,
+    search: String,
+    /// The source that provided the selected game.
+    game_source: Option<GameSource>,
+    verification: Option<Verification>,
     error: Option<String>,
+    catalog_thread: Option<std::thread::JoinHandle<()>>,
+    catalog_data: Arc<RwLock<CatalogThreadData>>,
 }
 
 #[derive(Debug, Default)]
@@ -42,6 +114,8 @@ struct ConfigurePlayerState {
     name: String,
     args: Vec<BotArg>,
     custom_id: bool,
+    /// Set when adding a bot to an installed project instead of installing a new game.
+    project_path: Option<PathBuf>,
 }
 
 #[derive(Debug, Default)]
@@ -50,62 +124,273 @@ struct SelectLocationState {
     error: Option<String>,
 }
 
+#[derive(Debug, Default)]
+struct OverviewState {
+    thread: Option<std::thread::JoinHandle<()>>,
+    thread_data: Arc<RwLock<PlanThreadData>>,
+}
+
 #[derive(Debug, Default)]
 struct InstallState {
     thread: Option<std::thread::JoinHandle<()>>,
     thread_data: Arc<RwLock<InstallThreadData>>,
 }
 
+#[derive(Debug, Default)]
+struct DoctorState {
+    project_path: PathBuf,
+    thread: Option<std::thread::JoinHandle<()>>,
+    thread_data: Arc<RwLock<DoctorThreadData>>,
+}
+
+#[derive(Debug, Default)]
+struct BotTestsState {
+    project_path: PathBuf,
+    bot_id: String,
+    /// The screen that the tests were started from.
+    back_screen: Screen,
+    thread: Option<std::thread::JoinHandle<()>>,
+    thread_data: Arc<RwLock<BotTestsThreadData>>,
+}
+
+#[derive(Debug, Default)]
+struct LintState {
+    project_path: PathBuf,
+    bot_id: String,
+    /// The screen that the code tools were opened from.
+    back_screen: Screen,
+    thread: Option<std::thread::JoinHandle<()>>,
+    thread_data: Arc<RwLock<LintThreadData>>,
+}
+
+#[derive(Debug, Default)]
+struct MatchSetupState {
+    project_path: PathBuf,
+    players: Vec<MatchPlayer>,
+    bot_ids: Vec<String>,
+    /// `None` until the match is loaded.
+    limits: Option<Players>,
+    thread: Option<std::thread::JoinHandle<()>>,
+    thread_data: Arc<RwLock<MatchSetupThreadData>>,
+}
+
+#[derive(Debug, Default)]
+struct GameRunState {
+    project_path: PathBuf,
+    search: String,
+    /// Tells the thread to kill the game.
+    stop: Arc<AtomicBool>,
+    thread: Option<std::thread::JoinHandle<()>>,
+    thread_data: Arc<RwLock<GameRunThreadData>>,
+}
+
+impl ConfigurePlayerState {
+    /// An empty form with the name of the player's last bot.
+    fn new(preferences: &Preferences) -> Self {
+        let Some(name) = preferences.player_name.clone() else {
+            return Self::default();
+        };
+        Self {
+            id: aigl_project::bot_id_from_name(&name),
+            name,
+            ..Default::default()
+        }
+    }
+}
+
+/// The default bot args of `config` with the player's preferred color in the first color arg.
+fn default_bot_args(config: &GameConfig, preferences: &Preferences) -> Vec<BotArg> {
+    let mut args: Vec<_> = config
+        .localized_template_args(&i18n::content_languages())
+        .into_iter()
+        .map(BotArg::default_from_template_arg)
+        .collect();
+    let color_arg = args.iter_mut().find_map(|arg| match &mut arg.value {
+        BotArgValue::Color(rgb) => Some(rgb),
+        _ => None,
+    });
+    if let (Some(rgb), Some(color)) = (color_arg, &preferences.color) {
+        if let BotArgValue::Color(preferred) = BotArgValue::color_from_string(color) {
+            *rgb = preferred;
+        }
+    }
+    args
+}
+
+/// The screen to show after fetching the game of an install link.
+///
+/// Stays on the game selection to show the error if the game cannot be fetched.
+fn screen_after_link(fetched: &Result<FetchedGame>) -> Screen {
+    if fetched.is_ok() {
+        Screen::ConfigurePlayer
+    } else {
+        Screen::SelectGame
+    }
+}
+
 impl GameInstallApp {
-    pub fn run() {
+    /// Start the installer, optionally with the game of `link` selected.
+    ///
+    /// `startup_errors` are shown on the home screen, e.g., why settings could not be loaded.
+    pub fn run(
+        launcher_version: &'static str,
+        game_sources: GameSources,
+        trust_policy: TrustPolicy,
+        link: Option<InstallLink>,
+        startup_errors: Vec<String>,
+    ) {
         let options = eframe::NativeOptions {
             viewport: egui::ViewportBuilder::default(),
             centered: true,
             ..Default::default()
         };
+        // The app name identifies the app's storage, so it is not translated.
         eframe::run_native(
             "Install AI Game",
             options,
-            Box::new(|cc| Ok(Box::new(Self::new(cc)))),
+            Box::new(|cc| {
+                Ok(Box::new(Self::new(
+                    cc,
+                    launcher_version,
+                    game_sources,
+                    trust_policy,
+                    link,
+                    startup_errors,
+                )))
+            }),
         )
         .unwrap();
     }
 
-    fn new(cc: &eframe::CreationContext<'_>) -> Self {
-        Theme::get_selected().apply(&cc.egui_ctx);
+    fn new(
+        cc: &eframe::CreationContext<'_>,
+        launcher_version: &'static str,
+        mut game_sources: GameSources,
+        trust_policy: TrustPolicy,
+        link: Option<InstallLink>,
+        startup_errors: Vec<String>,
+    ) -> Self {
+        if let Some(source) = link.as_ref().and_then(|link| link.game_source.clone()) {
+            game_sources.0.insert(0, source);
+        }
 
         let mut fonts = egui::FontDefinitions::default();
         egui_phosphor::add_to_fonts(&mut fonts, egui_phosphor::Variant::Regular);
         cc.egui_ctx.set_fonts(fonts);
 
-        Self {
-            screen: Screen::SelectGame,
+        let preferences = Preferences::load().unwrap_or_default();
+        let zoom_factor = preferences
+            .zoom_factor
+            .map_or(components::DEFAULT_ZOOM_FACTOR, |zoom_factor| {
+                components::step_zoom_factor(zoom_factor, 0)
+            });
+        // The zoom shortcuts are handled in `handle_zoom_keys` so that the zoom factor is saved.
+        cc.egui_ctx
+            .options_mut(|options| options.zoom_with_keyboard = false);
+        cc.egui_ctx.set_zoom_factor(zoom_factor);
+
+        let select_game_state = SelectGameState::default();
+        let catalog_data = select_game_state.catalog_data.clone();
+        let catalog_sources = game_sources.clone();
+        let select_game_state = SelectGameState {
+            game_code: preferences.game_code.clone().unwrap_or_default(),
+            catalog_thread: Some(std::thread::spawn(move || {
+                load_catalog(catalog_data, catalog_sources)
+            })),
+            ..select_game_state
+        };
+
+        let mut app = Self {
+            launcher_version,
+            screen: Screen::Home,
+            game_sources,
+            trust_policy,
             game_config: None,
-            select_game_state: Default::default(),
+            theme_library: ThemeLibrary::load(),
+            theme_choice: preferences
+                .theme
+                .clone()
+                .map_or(ThemeChoice::System, ThemeChoice::Named),
+            applied_theme: None,
+            language_choice: LanguageChoice::from_code(preferences.language.as_deref()),
+            applied_language: None,
+            preferences,
+            zoom_factor,
+            focused_widget: None,
+            shown_screen: None,
+            focus_pending: false,
+            home_state: HomeState {
+                error: (!startup_errors.is_empty()).then(|| startup_errors.join("\n")),
+                ..Default::default()
+            },
+            select_game_state,
             configure_player_state: Default::default(),
             select_location_state: Default::default(),
+            overview_state: Default::default(),
             install_state: Default::default(),
+            doctor_state: Default::default(),
+            bot_tests_state: Default::default(),
+            lint_state: Default::default(),
+            match_setup_state: Default::default(),
+            game_run_state: Default::default(),
+        };
+        app.start_loading_projects();
+        if let Some(link) = link {
+            app.open_install_link(link);
         }
+        app
     }
 
     fn next_screen(&mut self, ui: &mut egui::Ui) {
         match self.screen {
+            Screen::Home => {}
             Screen::SelectGame => self.screen = Screen::ConfigurePlayer,
-            Screen::ConfigurePlayer => self.screen = Screen::SelectLocation,
-            Screen::SelectLocation => self.screen = Screen::Overview,
+            Screen::ConfigurePlayer => {
+                let state = &self.configure_player_state;
+                if let Some(project_path) = state.project_path.clone() {
+                    let action = ProjectAction::AddBot {
+                        bot_id: state.id.clone(),
+                        bot_name: state.name.clone(),
+                        args: state.args.clone(),
+                    };
+                    self.remember_player();
+                    self.start_project_action(project_path, action);
+                    self.screen = Screen::Home;
+                } else {
+                    self.prefill_install_location();
+                    self.screen = Screen::SelectLocation;
+                }
+            }
+            Screen::SelectLocation => {
+                self.start_planning();
+                self.screen = Screen::Overview;
+            }
             Screen::Overview => {
+                self.remember_player();
                 self.start_installation();
                 self.screen = Screen::Installing
             }
             Screen::Installing => self.screen = Screen::Finished,
-            Screen::Finished => self.exit(ui),
+            Screen::Finished
+            | Screen::Doctor
+            | Screen::BotTests
+            | Screen::Lint
+            | Screen::MatchSetup
+            | Screen::Console => self.exit(ui),
         }
     }
 
     fn previous_screen(&mut self, ui: &mut egui::Ui) {
         match self.screen {
-            Screen::SelectGame => self.exit(ui),
-            Screen::ConfigurePlayer => self.screen = Screen::SelectGame,
+            Screen::Home => self.exit(ui),
+            Screen::SelectGame => self.screen = Screen::Home,
+            Screen::ConfigurePlayer => {
+                if self.configure_player_state.project_path.is_some() {
+                    self.screen = Screen::Home;
+                } else {
+                    self.screen = Screen::SelectGame;
+                }
+            }
             Screen::SelectLocation => self.screen = Screen::ConfigurePlayer,
             Screen::Overview => self.screen = Screen::SelectLocation,
             Screen::Installing => {
@@ -113,6 +398,13 @@ impl GameInstallApp {
                 self.screen = Screen::Overview;
             }
             Screen::Finished => self.exit(ui),
+            Screen::Doctor | Screen::MatchSetup | Screen::Console => {
+                self.stop_game();
+                self.start_loading_projects();
+                self.screen = Screen::Home;
+            }
+            Screen::BotTests => self.screen = self.bot_tests_state.back_screen,
+            Screen::Lint => self.screen = self.lint_state.back_screen,
         }
     }
 
@@ -123,11 +415,72 @@ impl GameInstallApp {
                 self.previous_screen(ui);
             }
             _ => {
+                if let Some(thread) = self.stop_game() {
+                    // Give the thread a moment to kill the game before the launcher exits.
+                    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(2);
+                    while !thread.is_finished() && std::time::Instant::now() < deadline {
+                        std::thread::sleep(std::time::Duration::from_millis(20));
+                    }
+                }
                 ui.ctx().send_viewport_cmd(egui::ViewportCommand::Close);
             }
         }
     }
 
+    /// Remember the player's choices to prefill them next time.
+    fn remember_player(&mut self) {
+        let state = &self.configure_player_state;
+        let preferences = &mut self.preferences;
+        preferences.player_name = Some(state.name.clone());
+        if let Some(color) = state
+            .args
+            .iter()
+            .find(|arg| matches!(arg.value, BotArgValue::Color(_)))
+        {
+            preferences.color = Some(color.value.render_value());
+        }
+        if state.project_path.is_none() {
+            preferences.game_code = Some(self.select_game_state.game_code.clone());
+            preferences.install_parent = Path::new(&self.select_location_state.install_location)
+                .parent()
+                .filter(|parent| !parent.as_os_str().is_empty())
+                .map(Path::to_path_buf);
+        }
+        // Failing to save only means that the choices are not prefilled next time.
+        let _ = preferences.save();
+    }
+
+    /// Forget the remembered choices and clear the screens that were prefilled with them.
+    fn forget_player(&mut self) {
+        self.preferences.forget_player();
+        if let Err(err) = self.preferences.save() {
+            self.home_state.error = Some(format!("{err:#}"));
+        }
+        self.select_game_state.game_code.clear();
+        self.configure_player_state = Default::default();
+        self.select_location_state = Default::default();
+    }
+
+    /// Suggest a folder named after the game next to the last installed game.
+    fn prefill_install_location(&mut self) {
+        let state = &mut self.select_location_state;
+        let Some(parent) = &self.preferences.install_parent else {
+            return;
+        };
+        // A code can also be the path or URL of a game config.
+        let Some(dir_name) = Path::new(&self.select_game_state.game_code).file_stem() else {
+            return;
+        };
+        if !state.install_location.is_empty() {
+            return;
+        }
+        let path = parent.join(dir_name);
+        state.error = path_available_as_output_directory(&path)
+            .err()
+            .map(|err| err.to_string());
+        state.install_location = path.display().to_string();
+    }
+
     fn cancel_installation(&mut self) {
         if matches!(self.screen, Screen::Installing) {
             if let Some(thread) = self.install_state.thread.take() {
@@ -137,93 +490,377 @@ impl GameInstallApp {
             if dir_is_incomplete(&path) {
                 let _ = std::fs::remove_dir_all(path);
             }
-            self.install_state.thread_data.write().unwrap().error = None;
+            *self.install_state.thread_data.write().unwrap() = Default::default();
         }
     }
 
     fn show_central_panel(&mut self, ui: &mut egui::Ui) {
         match self.screen {
+            Screen::Home => self.show_home_central_panel(ui),
             Screen::SelectGame => self.show_select_game_central_panel(ui),
             Screen::ConfigurePlayer => self.show_configure_player_central_panel(ui),
             Screen::SelectLocation => self.show_select_location_central_panel(ui),
             Screen::Overview => self.show_overview_central_panel(ui),
             Screen::Installing => self.show_installing_central_panel(ui),
             Screen::Finished => self.show_finished_central_panel(ui),
+            Screen::Doctor => self.show_doctor_central_panel(ui),
+            Screen::BotTests => self.show_bot_tests_central_panel(ui),
+            Screen::Lint => self.show_lint_central_panel(ui),
+            Screen::MatchSetup => self.show_match_setup_central_panel(ui),
+            Screen::Console => self.show_console_central_panel(ui),
         };
     }
 
+    fn show_home_central_panel(&mut self, ui: &mut egui::Ui) {
+        let state = &mut self.home_state;
+        let mut install_new = false;
+        let mut reload = false;
+        let mut forget = false;
+        ui.horizontal(|ui| {
+            let install_response = ui.add(components::icon_button(
+                &t!("home-install-new"),
+                egui_phosphor::regular::DOWNLOAD_SIMPLE,
+            ));
+            if std::mem::take(&mut self.focus_pending) {
+                install_response.request_focus();
+            }
+            install_new = install_response.clicked();
+            if ui
+                .add(components::icon_button(
+                    &t!("home-open-folder"),
+                    egui_phosphor::regular::FOLDER_OPEN,
+                ))
+                .clicked()
+            {
+                if let Some(path) = rfd::FileDialog::new().pick_folder() {
+                    match register_project(path) {
+                        Ok(()) => {
+                            state.error = None;
+                            reload = true;
+                        }
+                        Err(err) => state.error = Some(format!("{err:#}")),
+                    }
+                }
+            }
+            forget = ui
+                .add_enabled(
+                    self.preferences.remembers_player(),
+                    components::icon_button(&t!("home-forget-me"), egui_phosphor::regular::USER),
+                )
+                .on_hover_text(t!("home-forget-me-hint"))
+                .clicked();
+        });
+        if let Some(error) = &state.error {
+            ui.colored_label(ui.visuals().error_fg_color, error);
+        }
+
+        let busy = state
+            .action_thread
+            .as_ref()
+            .is_some_and(|t| !t.is_finished());
+        if busy {
+            ui.spinner();
+        } else if let Ok(data) = state.action_data.read() {
+            if let Some(error) = &data.error {
+                ui.colored_label(ui.visuals().error_fg_color, t!("error", error = error));
+            } else if let Some(message) = &data.message {
+                ui.label(message);
+            }
+        }
+
+        if let Some(path) = state.confirm_uninstall.clone() {
+            ui.add_space(10.0);
+            ui.colored_label(
+                ui.visuals().warn_fg_color,
+                t!("confirm-uninstall", path = path.display()),
+            );
+            let mut confirmed = false;
+            ui.horizontal(|ui| {
+                if ui
+                    .add(components::icon_button(
+                        &t!("uninstall"),
+                        egui_phosphor::regular::TRASH,
+                    ))
+                    .clicked()
+                {
+                    state.confirm_uninstall = None;
+                    confirmed = true;
+                } else if ui.button(t!("cancel")).clicked() {
+                    state.confirm_uninstall = None;
+                }
+            });
+            if confirmed {
+                self.start_project_action(path, ProjectAction::Uninstall);
+            }
+            return;
+        }
+
+        ui.add_space(10.0);
+        let loading = state.thread.as_ref().is_some_and(|t| !t.is_finished());
+        let mut clicked = None;
+        if loading {
+            ui.spinner();
+        } else if let Ok(data) = state.thread_data.read() {
+            if let Some(error) = &data.error {
+                ui.colored_label(ui.visuals().error_fg_color, error);
+            }
+            if let Some((path, button)) = components::project_list(ui, &data.projects, busy) {
+                let game_config = data
+                    .projects
+                    .iter()
+                    .find(|project| project.path == path)
+                    .and_then(|project| project.game_config.clone());
+                clicked = Some((path, button, game_config));
+            }
+        }
+
+        if install_new {
+            self.configure_player_state = ConfigurePlayerState::new(&self.preferences);
+            self.screen = Screen::SelectGame;
+        } else if forget {
+            self.forget_player();
+        } else if reload {
+            self.start_loading_projects();
+        } else if let Some((path, button, game_config)) = clicked {
+            self.on_project_clicked(ui, path, button, game_config);
+        }
+    }
+
+    fn on_project_clicked(
+        &mut self,
+        ui: &mut egui::Ui,
+        path: PathBuf,
+        button: components::ProjectClicked,
+        game_config: Option<GameConfig>,
+    ) {
+        use components::ProjectClicked;
+        match button {
+            ProjectClicked::Play => {
+                self.start_game(path);
+                self.screen = Screen::Console;
+            }
+            ProjectClicked::AddBot => {
+                self.configure_player_state = ConfigurePlayerState {
+                    args: game_config
+                        .as_ref()
+                        .map(|config| default_bot_args(config, &self.preferences))
+                        .unwrap_or_default(),
+                    project_path: Some(path),
+                    ..ConfigurePlayerState::new(&self.preferences)
+                };
+                self.screen = Screen::ConfigurePlayer;
+            }
+            ProjectClicked::RunTests { bot } => self.open_bot_tests(path, bot),
+            ProjectClicked::CheckCode { bot } => self.open_lint(path, bot, LintAction::Check),
+            ProjectClicked::FormatCode { bot } => self.open_lint(path, bot, LintAction::Format),
+            ProjectClicked::SetUpMatch => {
+                self.match_setup_state = MatchSetupState {
+                    project_path: path,
+                    ..Default::default()
+                };
+                self.start_loading_match();
+                self.screen = Screen::MatchSetup;
+            }
+            ProjectClicked::UpdateGame => {
+                self.start_project_action(path, ProjectAction::UpdateGame)
+            }
+            ProjectClicked::UpdateBotTemplate => {
+                self.start_project_action(path, ProjectAction::UpdateBotTemplate)
+            }
+            ProjectClicked::OpenFolder => {
+                if let Err(err) = aigl_system::open::open_in_default_app(&path) {
+                    ui.colored_label(ui.visuals().error_fg_color, err.to_string());
+                }
+            }
+            ProjectClicked::Check => {
+                self.doctor_state.project_path = path;
+                self.start_check(None);
+                self.screen = Screen::Doctor;
+            }
+            ProjectClicked::Uninstall => self.home_state.confirm_uninstall = Some(path),
+            ProjectClicked::Forget => self.start_project_action(path, ProjectAction::Forget),
+        }
+    }
+
     fn show_select_game_central_panel(&mut self, ui: &mut egui::Ui) {
         let state = &mut self.select_game_state;
+        let mut fetch = false;
+
+        ui.label(t!("select-game-choose"));
+        let search_response = components::search_input(ui, &mut state.search);
+        if std::mem::take(&mut self.focus_pending) {
+            search_response.inner.request_focus();
+        }
+        let loading = state
+            .catalog_thread
+            .as_ref()
+            .is_some_and(|t| !t.is_finished());
+        if loading {
+            ui.spinner();
+        } else if let Ok(data) = state.catalog_data.read() {
+            if let Some(error) = &data.error {
+                ui.colored_label(ui.visuals().error_fg_color, error);
+            }
+            if let Some(catalog) = &data.catalog {
+                if let Some(code) = components::catalog_list(
+                    ui,
+                    catalog,
+                    &state.search,
+                    &state.game_code,
+                    self.launcher_version,
+                ) {
+                    state.game_code = code;
+                    fetch = true;
+                }
+            }
+        }
 
-        ui.label("Enter game code:");
+        ui.add_space(10.0);
+        ui.label(t!("select-game-enter-code"));
         ui.horizontal(|ui| {
             let input = components::button_input(
                 ui,
                 &mut state.game_code,
-                "Fetch",
+                &t!("select-game-fetch"),
                 egui_phosphor::regular::DOWNLOAD_SIMPLE,
             );
-            if input.inner.accepted {
-                match fetch_game_config(&state.game_code) {
-                    Ok(config) => {
-                        self.configure_player_state.args = config
-                            .bot
-                            .template_args
-                            .values()
-                            .map(|arg| BotArg::default_from_template_arg(arg.clone()))
-                            .collect();
-                        self.game_config = Some(config);
-                        state.error = None;
-                    }
-                    Err(err) => {
-                        self.game_config = None;
-                        state.error = Some(err.to_string());
-                    }
-                }
-            }
+            fetch |= input.inner.accepted;
         });
+        if fetch {
+            self.fetch_game();
+        }
 
+        let state = &self.select_game_state;
         if let Some(error) = &state.error {
-            ui.colored_label(ui.visuals().error_fg_color, format!("Error: {error}"));
+            ui.colored_label(ui.visuals().error_fg_color, t!("error", error = error));
         } else if let Some(game_config) = &self.game_config {
             components::game_info_text(ui, game_config);
+            if let Some(source) = &state.game_source {
+                ui.label(t!("select-game-loaded-from", source = source.to_string()));
+            }
+            if let Some(verification) = &state.verification {
+                components::verification_text(ui, verification);
+            }
         }
-        ui.add_space(100.0);
+        ui.add_space(40.0);
         let theme = Theme::get_selected();
-        ui.label(
-            RichText::from("Your game master will provide the game code.")
-                .color(theme.base.fg_low_contrast.0),
+        ui.label(RichText::from(t!("select-game-code-hint")).color(theme.base.fg_low_contrast.0));
+
+        ui.add_space(20.0);
+        if ui
+            .add(components::icon_button(
+                &t!("select-game-check-installed"),
+                egui_phosphor::regular::WRENCH,
+            ))
+            .clicked()
+        {
+            if let Some(path) = rfd::FileDialog::new().pick_folder() {
+                self.doctor_state.project_path = path;
+                self.start_check(None);
+                self.screen = Screen::Doctor;
+            }
+        }
+    }
+
+    /// Fetch the config of the selected game and fill in the default bot args.
+    fn fetch_game(&mut self) {
+        let fetched = fetch_game_config(
+            &self.game_sources,
+            &self.trust_policy,
+            &self.select_game_state.game_code,
         );
+        self.set_fetched_game(fetched);
+    }
+
+    fn set_fetched_game(&mut self, fetched: Result<FetchedGame>) {
+        let state = &mut self.select_game_state;
+        match fetched {
+            Ok(fetched) => {
+                let config = fetched.config;
+                self.configure_player_state.args = default_bot_args(&config, &self.preferences);
+                self.game_config = Some(config);
+                state.game_source = Some(fetched.source);
+                state.verification = Some(fetched.verification);
+                state.error = None;
+            }
+            Err(err) => {
+                self.game_config = None;
+                state.game_source = None;
+                state.verification = None;
+                state.error = Some(format!("{err:#}"));
+            }
+        }
+    }
+
+    /// Fetch the game of `link` and continue with configuring the player.
+    ///
+    /// Games from unverified publishers show a warning on the player configuration.
+    fn open_install_link(&mut self, link: InstallLink) {
+        self.configure_player_state = ConfigurePlayerState::new(&self.preferences);
+        if let Some(name) = link.player_name {
+            self.configure_player_state.id = aigl_project::bot_id_from_name(&name);
+            self.configure_player_state.name = name;
+        }
+        self.select_game_state.game_code = link.game_code;
+        let fetched = fetch_game_config(
+            &self.game_sources,
+            &self.trust_policy,
+            &self.select_game_state.game_code,
+        );
+        self.screen = screen_after_link(&fetched);
+        self.set_fetched_game(fetched);
     }
 
     fn show_configure_player_central_panel(&mut self, ui: &mut egui::Ui) {
         let state = &mut self.configure_player_state;
 
-        ui.label("Give your bot a unique name:");
-        let name_response = components::text_input(ui, "Name", &mut state.name);
+        ui.label(t!("configure-name-prompt"));
+        let name_response = components::text_input(ui, &t!("configure-name"), &mut state.name);
+        if std::mem::take(&mut self.focus_pending) {
+            name_response.inner.request_focus();
+        }
         if name_response.inner.changed() && !state.custom_id {
-            state.id = default_bot_id(&state.name);
+            state.id = aigl_project::bot_id_from_name(&state.name);
         }
 
-        ui.label("Name your Python package (optional):");
-        let id_response = components::text_input(ui, "Package", &mut state.id);
+        ui.label(t!("configure-package-prompt"));
+        let id_response = components::text_input(ui, &t!("configure-package"), &mut state.id);
         if id_response.inner.changed() {
             state.custom_id = true;
         }
+        if let Err(error) = aigl_project::validate_bot_id(&state.id) {
+            ui.colored_label(ui.visuals().error_fg_color, messages::bot_id_error(&error));
+        }
 
         for arg in state.args.iter_mut() {
             components::bot_arg_input(ui, arg);
         }
+
+        // Links skip the game selection, so warn here before anything is installed.
+        if state.project_path.is_none() {
+            if let Some(verification) = self
+                .select_game_state
+                .verification
+                .as_ref()
+                .filter(|verification| !verification.is_verified())
+            {
+                ui.add_space(10.0);
+                components::verification_text(ui, verification);
+            }
+        }
     }
 
     fn show_select_location_central_panel(&mut self, ui: &mut egui::Ui) {
         let state = &mut self.select_location_state;
-        let path_label = ui.label("Select a folder to install into:");
+        let path_label = ui.label(t!("location-prompt"));
         let response = ui.horizontal(|ui| {
             let edit_response = ui
                 .text_edit_singleline(&mut state.install_location)
                 .labelled_by(path_label.id);
-            if ui.button("Browse").clicked() {
+            if std::mem::take(&mut self.focus_pending) {
+                edit_response.request_focus();
+            }
+            if ui.button(t!("browse")).clicked() {
                 if let Some(path) = rfd::FileDialog::new().pick_folder() {
                     state.install_location = path.display().to_string();
                 }
@@ -247,25 +884,61 @@ impl GameInstallApp {
     }
 
     fn show_overview_central_panel(&mut self, ui: &mut egui::Ui) {
-        ui.label(format!(
-            "About to install the game into {}",
-            self.select_location_state.install_location
-        ));
+        if self.planning() {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(t!("overview-preparing"));
+            });
+            return;
+        }
+        // Repeat where the game came from because links skip the game selection.
+        let state = &self.select_game_state;
+        if let Some(source) = &state.game_source {
+            ui.label(t!("select-game-loaded-from", source = source.to_string()));
+        }
+        if let Some(verification) = &state.verification {
+            components::verification_text(ui, verification);
+        }
+        if let Ok(data) = self.overview_state.thread_data.read() {
+            if let Some(error) = &data.error {
+                ui.colored_label(ui.visuals().error_fg_color, error);
+            }
+            if let Some(plan) = &data.plan {
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    components::install_plan(ui, plan);
+                });
+            }
+        }
+    }
+
+    fn planning(&self) -> bool {
+        self.overview_state
+            .thread
+            .as_ref()
+            .is_some_and(|thread| !thread.is_finished())
     }
 
     fn show_installing_central_panel(&mut self, ui: &mut egui::Ui) {
-        ui.label(format!(
-            "Installing into {}",
-            self.select_location_state.install_location
+        ui.label(t!(
+            "installing-into",
+            path = self.select_location_state.install_location
         ));
         if let Ok(data) = self.install_state.thread_data.read() {
-            if let Some(error) = &data.error {
-                ui.colored_label(ui.visuals().error_fg_color, format!("Error: {error}"));
+            if let Some(report) = &data.report {
+                if let Some(log_path) =
+                    components::error_panel(ui, report, data.log_path.as_deref())
+                {
+                    if let Err(err) = aigl_system::open::open_in_default_app(&log_path) {
+                        ui.colored_label(ui.visuals().error_fg_color, err.to_string());
+                    }
+                }
+            } else if let Some(error) = &data.error {
+                ui.colored_label(ui.visuals().error_fg_color, t!("error", error = error));
             }
         }
 
         let Some(thread) = &self.install_state.thread else {
-            ui.colored_label(ui.visuals().error_fg_color, "Install thread missing");
+            ui.colored_label(ui.visuals().error_fg_color, t!("installing-thread-missing"));
             return;
         };
         if thread.is_finished() {
@@ -285,61 +958,535 @@ impl GameInstallApp {
     fn show_finished_central_panel(&mut self, ui: &mut egui::Ui) {
         let theme = Theme::get_selected();
 
-        ui.label(format!(
-            "Finished installing {} into {}",
-            self.game_config.as_ref().unwrap().name,
-            self.select_location_state.install_location
+        ui.label(t!(
+            "finished-installed",
+            game = self
+                .game_config
+                .as_ref()
+                .unwrap()
+                .localized_name(&i18n::content_languages()),
+            path = self.select_location_state.install_location
         ));
 
         ui.add_space(10.0);
-        ui.colored_label(theme.highlight.fg_high_contrast.0, "Running the game");
-        ui.label("1. Open a terminal in the game folder");
-        ui.label("2. Activate the virtual environment:");
+        ui.colored_label(theme.highlight.fg_high_contrast.0, t!("finished-running"));
+        let play_response = ui.add(components::icon_button(
+            &t!("play"),
+            egui_phosphor::regular::PLAY,
+        ));
+        if std::mem::take(&mut self.focus_pending) {
+            play_response.request_focus();
+        }
+        if play_response.clicked() {
+            self.start_game(PathBuf::from(&self.select_location_state.install_location));
+            self.screen = Screen::Console;
+        }
+        ui.label(t!("finished-run-yourself"));
+        ui.label(t!("finished-step-terminal"));
+        ui.label(t!("finished-step-activate"));
         #[cfg(target_os = "windows")]
         ui.code(".venv\\Scripts\\activate");
         #[cfg(any(target_os = "linux", target_os = "macos"))]
         ui.code("source .venv/bin/activate");
-        ui.label("3. Run the game using:");
+        ui.label(t!("finished-step-run"));
         ui.code(format!(
             "{} config.toml",
             self.game_config.as_ref().unwrap().name.to_lowercase()
         ));
-        ui.label("4. Edit your AI");
+        ui.label(t!("finished-step-edit"));
 
         ui.add_space(10.0);
-        ui.colored_label(theme.highlight.fg_high_contrast.0, "Uninstalling");
-        ui.label("Simply remove the game folder at");
+        ui.colored_label(theme.highlight.fg_high_contrast.0, t!("finished-testing"));
+        ui.horizontal(|ui| {
+            if ui
+                .add(components::icon_button(
+                    &t!("run-tests"),
+                    egui_phosphor::regular::BUG,
+                ))
+                .clicked()
+            {
+                self.open_bot_tests(
+                    PathBuf::from(&self.select_location_state.install_location),
+                    self.configure_player_state.id.clone(),
+                );
+            }
+            if ui
+                .add(components::icon_button(
+                    &t!("check-code"),
+                    egui_phosphor::regular::CODE,
+                ))
+                .clicked()
+            {
+                self.open_lint(
+                    PathBuf::from(&self.select_location_state.install_location),
+                    self.configure_player_state.id.clone(),
+                    LintAction::Check,
+                );
+            }
+        });
+
+        ui.add_space(10.0);
+        ui.colored_label(
+            theme.highlight.fg_high_contrast.0,
+            t!("finished-uninstalling"),
+        );
+        ui.label(t!("finished-uninstall-hint"));
         ui.label(&self.select_location_state.install_location);
     }
 
+    fn show_doctor_central_panel(&mut self, ui: &mut egui::Ui) {
+        let state = &self.doctor_state;
+        ui.label(t!("doctor-checking", path = state.project_path.display()));
+
+        let running = state.thread.as_ref().is_some_and(|t| !t.is_finished());
+        if running {
+            ui.spinner();
+            return;
+        }
+
+        let mut requested_fix = None;
+        if let Ok(data) = state.thread_data.read() {
+            if let Some(error) = &data.error {
+                ui.colored_label(ui.visuals().error_fg_color, t!("error", error = error));
+            } else if data.findings.is_empty() {
+                ui.label(t!("no-problems"));
+            }
+            for finding in &data.findings {
+                ui.horizontal(|ui| {
+                    components::finding_text(ui, finding);
+                    if let Some(fix) = &finding.fix {
+                        if ui
+                            .add(components::icon_button(
+                                &t!("fix"),
+                                egui_phosphor::regular::WRENCH,
+                            ))
+                            .on_hover_text(messages::fix_description(fix))
+                            .clicked()
+                        {
+                            requested_fix = Some(fix.clone());
+                        }
+                    }
+                });
+            }
+        }
+
+        ui.add_space(10.0);
+        if ui
+            .add(components::icon_button(
+                &t!("doctor-check-again"),
+                egui_phosphor::regular::ARROWS_CLOCKWISE,
+            ))
+            .clicked()
+        {
+            self.start_check(None);
+        } else if let Some(fix) = requested_fix {
+            self.start_check(Some(fix));
+        }
+    }
+
+    /// Run all tests of a bot and show them, Back returns to the current screen.
+    fn open_bot_tests(&mut self, project_path: PathBuf, bot_id: String) {
+        self.bot_tests_state = BotTestsState {
+            project_path,
+            bot_id,
+            back_screen: self.screen,
+            ..Default::default()
+        };
+        self.start_bot_tests(TestSelection::All);
+        self.screen = Screen::BotTests;
+    }
+
+    fn show_bot_tests_central_panel(&mut self, ui: &mut egui::Ui) {
+        let state = &self.bot_tests_state;
+        ui.label(t!("bot-tests-title", bot = state.bot_id));
+
+        let running = state.thread.as_ref().is_some_and(|t| !t.is_finished());
+        let mut rerun = None;
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(
+                    !running,
+                    components::icon_button(&t!("bot-tests-run-all"), egui_phosphor::regular::PLAY),
+                )
+                .clicked()
+            {
+                rerun = Some(TestSelection::All);
+            }
+            let has_failures = state.thread_data.read().is_ok_and(|data| {
+                data.report
+                    .as_ref()
+                    .is_some_and(|report| report.cases.iter().any(|case| case.is_failure()))
+            });
+            if ui
+                .add_enabled(
+                    !running && has_failures,
+                    components::icon_button(
+                        &t!("bot-tests-rerun-failed"),
+                        egui_phosphor::regular::ARROWS_CLOCKWISE,
+                    ),
+                )
+                .clicked()
+            {
+                rerun = Some(TestSelection::Failed);
+            }
+            if running {
+                ui.spinner();
+            }
+        });
+
+        if let Ok(data) = state.thread_data.read() {
+            if let Some(error) = &data.error {
+                ui.colored_label(ui.visuals().error_fg_color, t!("error", error = error));
+            }
+            if let Some(report) = &data.report {
+                components::test_report(ui, report);
+            }
+        }
+
+        if let Some(selection) = rerun {
+            self.start_bot_tests(selection);
+        }
+    }
+
+    /// Run `action` on the code of a bot and show the result, Back returns to the current screen.
+    fn open_lint(&mut self, project_path: PathBuf, bot_id: String, action: LintAction) {
+        self.lint_state = LintState {
+            project_path,
+            bot_id,
+            back_screen: self.screen,
+            ..Default::default()
+        };
+        self.start_lint_action(action);
+        self.screen = Screen::Lint;
+    }
+
+    fn show_lint_central_panel(&mut self, ui: &mut egui::Ui) {
+        let state = &self.lint_state;
+        ui.label(t!("lint-title", bot = state.bot_id));
+
+        let running = state.thread.as_ref().is_some_and(|t| !t.is_finished());
+        let has_fixes = state.thread_data.read().is_ok_and(|data| {
+            data.diagnostics
+                .as_ref()
+                .is_some_and(|diagnostics| diagnostics.iter().any(|d| d.is_fixable()))
+        });
+        let mut action = None;
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(
+                    !running,
+                    components::icon_button(&t!("check-code"), egui_phosphor::regular::CODE),
+                )
+                .clicked()
+            {
+                action = Some(LintAction::Check);
+            }
+            if ui
+                .add_enabled(
+                    !running,
+                    components::icon_button(&t!("lint-format"), egui_phosphor::regular::SPARKLE),
+                )
+                .clicked()
+            {
+                action = Some(LintAction::Format);
+            }
+            if ui
+                .add_enabled(
+                    !running && has_fixes,
+                    components::icon_button(&t!("lint-fix-all"), egui_phosphor::regular::WRENCH),
+                )
+                .clicked()
+            {
+                action = Some(LintAction::Fix(None));
+            }
+            if running {
+                ui.spinner();
+            }
+        });
+
+        if let Ok(data) = state.thread_data.read() {
+            if let Some(error) = &data.error {
+                ui.colored_label(ui.visuals().error_fg_color, t!("error", error = error));
+            }
+            if let Some(summary) = &data.format_summary {
+                ui.label(summary);
+            }
+            if let Some(diagnostics) = &data.diagnostics {
+                let response = components::diagnostic_list(ui, diagnostics, &state.project_path);
+                if let Some((file, line)) = response.open {
+                    if let Err(err) = aigl_system::open::open_in_editor(&file, line) {
+                        ui.colored_label(ui.visuals().error_fg_color, err.to_string());
+                    }
+                }
+                if let Some(diagnostic) = response.fix.filter(|_| !running) {
+                    action = Some(LintAction::Fix(Some(diagnostic)));
+                }
+            }
+        }
+
+        if let Some(action) = action {
+            self.start_lint_action(action);
+        }
+    }
+
+    fn show_match_setup_central_panel(&mut self, ui: &mut egui::Ui) {
+        let state = &mut self.match_setup_state;
+        let running = state.thread.as_ref().is_some_and(|t| !t.is_finished());
+        if let Ok(mut data) = state.thread_data.write() {
+            if let Some(setup) = data.setup.take() {
+                state.players = setup.players;
+                state.bot_ids = setup.bot_ids;
+                state.limits = Some(setup.limits);
+            }
+            if let Some(error) = &data.error {
+                ui.colored_label(ui.visuals().error_fg_color, error);
+            }
+        }
+        let Some(limits) = &state.limits else {
+            if running {
+                ui.spinner();
+            }
+            return;
+        };
+
+        ui.label(t!("match-title", path = state.project_path.display()));
+        if let Players::FFA { n_min, n_max, .. } = limits {
+            ui.label(match n_max {
+                Some(n_max) => t!("match-needs-range", min = n_min, max = n_max),
+                None => t!("match-needs-at-least", min = n_min),
+            });
+        }
+        ui.add_space(10.0);
+        if components::match_editor(ui, &mut state.players, &state.bot_ids) {
+            if let Ok(mut data) = state.thread_data.write() {
+                data.saved = false;
+            }
+        }
+
+        ui.add_space(10.0);
+        let validation = validate_match(&state.players, limits, &state.bot_ids);
+        let mut save = false;
+        ui.horizontal(|ui| {
+            save = ui
+                .add_enabled(
+                    !running && validation.is_ok(),
+                    components::icon_button(&t!("save"), egui_phosphor::regular::CHECK),
+                )
+                .clicked();
+            if running {
+                ui.spinner();
+            } else if state.thread_data.read().is_ok_and(|data| data.saved) {
+                ui.label(t!("saved"));
+            }
+        });
+        if let Err(message) = validation {
+            ui.colored_label(ui.visuals().error_fg_color, message);
+        }
+        if save {
+            self.start_saving_match();
+        }
+    }
+
+    fn show_console_central_panel(&mut self, ui: &mut egui::Ui) {
+        let state = &mut self.game_run_state;
+        let running = state.thread.as_ref().is_some_and(|t| !t.is_finished());
+        if running {
+            ui.ctx()
+                .request_repaint_after(std::time::Duration::from_millis(100));
+        }
+
+        let mut restart = false;
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(
+                    running,
+                    components::icon_button(&t!("console-stop"), egui_phosphor::regular::STOP),
+                )
+                .clicked()
+            {
+                state.stop.store(true, Ordering::Relaxed);
+            }
+            restart = ui
+                .add(components::icon_button(
+                    &t!("console-restart"),
+                    egui_phosphor::regular::ARROW_CLOCKWISE,
+                ))
+                .clicked();
+            components::search_input(ui, &mut state.search);
+            if running {
+                ui.spinner();
+            }
+        });
+
+        let mut clicked = None;
+        if let Ok(data) = state.thread_data.read() {
+            if let Some(error) = &data.error {
+                ui.colored_label(ui.visuals().error_fg_color, error);
+            } else if let Some(status) = &data.exit_status {
+                ui.label(status);
+            }
+            clicked = components::console(ui, &data.lines, &state.search, &state.project_path);
+        }
+        if let Some(location) = clicked {
+            if let Err(err) =
+                aigl_system::open::open_in_editor(&location.file, location.line as usize)
+            {
+                ui.colored_label(ui.visuals().error_fg_color, err.to_string());
+            }
+        }
+        if restart {
+            self.start_game(self.game_run_state.project_path.clone());
+        }
+    }
+
     fn show_top_panel(&mut self, ui: &mut egui::Ui) {
         let heading = match self.screen {
-            Screen::SelectGame => "Select game",
-            Screen::ConfigurePlayer => "Configure player",
-            Screen::SelectLocation => "Install location",
-            Screen::Overview => "Overview",
-            Screen::Installing => "Installing",
-            Screen::Finished => "Installation finished",
+            Screen::Home => t!("screen-home"),
+            Screen::SelectGame => t!("screen-select-game"),
+            Screen::ConfigurePlayer if self.configure_player_state.project_path.is_some() => {
+                t!("screen-add-bot")
+            }
+            Screen::ConfigurePlayer => t!("screen-configure-player"),
+            Screen::SelectLocation => t!("screen-select-location"),
+            Screen::Overview => t!("screen-overview"),
+            Screen::Installing => t!("screen-installing"),
+            Screen::Finished => t!("screen-finished"),
+            Screen::Doctor => t!("screen-doctor"),
+            Screen::BotTests => t!("screen-bot-tests"),
+            Screen::Lint => t!("screen-lint"),
+            Screen::MatchSetup => t!("screen-match-setup"),
+            Screen::Console => t!("screen-console"),
         };
-        ui.heading(heading);
+        ui.horizontal(|ui| {
+            ui.heading(heading);
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if components::theme_picker(ui, &self.theme_library, &mut self.theme_choice) {
+                    self.remember_theme();
+                }
+                if components::language_picker(ui, &mut self.language_choice) {
+                    self.remember_language();
+                }
+                if let Some(zoom_factor) = components::zoom_control(ui, self.zoom_factor) {
+                    self.set_zoom_factor(ui.ctx(), zoom_factor);
+                }
+            });
+        });
     }
 
-    fn show_bottom_panel(&mut self, ui: &mut egui::Ui) {
-        let exit_button_spec = match self.screen {
-            Screen::Finished => components::NavExit::No,
-            Screen::Installing => components::NavExit::Cancel,
-            _ => components::NavExit::Exit,
-        };
-        let back_button_spec = match self.screen {
-            Screen::Finished | Screen::Installing | Screen::SelectGame => components::NavBack::No,
-            _ => components::NavBack::Back,
+    /// Select the chosen theme if the choice or the OS preference has changed.
+    fn update_theme(&mut self, ctx: &egui::Context) {
+        let wanted = (self.theme_choice.clone(), ctx.system_theme());
+        if self.applied_theme.as_ref() != Some(&wanted) {
+            Theme::select(self.theme_library.resolve(&wanted.0, wanted.1), ctx);
+            self.applied_theme = Some(wanted);
+        }
+    }
+
+    /// Show messages in the chosen language if the choice has changed.
+    fn update_language(&mut self, ctx: &egui::Context) {
+        if self.applied_language.as_ref() != Some(&self.language_choice) {
+            i18n::set_language(self.language_choice.resolve());
+            ctx.send_viewport_cmd(egui::ViewportCommand::Title(t!("app-title")));
+            self.applied_language = Some(self.language_choice.clone());
+        }
+    }
+
+    /// Remember the chosen theme for the next start.
+    fn remember_theme(&mut self) {
+        self.preferences.theme = match &self.theme_choice {
+            ThemeChoice::System => None,
+            ThemeChoice::Named(name) => Some(name.clone()),
         };
+        // Failing to save only means that the theme is not remembered.
+        let _ = self.preferences.save();
+    }
+
+    /// Remember the chosen language for the next start.
+    fn remember_language(&mut self) {
+        self.preferences.language = self.language_choice.code().map(str::to_owned);
+        // Failing to save only means that the language is not remembered.
+        let _ = self.preferences.save();
+    }
+
+    /// Zoom the user interface and remember the zoom factor for the next start.
+    fn set_zoom_factor(&mut self, ctx: &egui::Context, zoom_factor: f32) {
+        if zoom_factor == self.zoom_factor {
+            return;
+        }
+        self.zoom_factor = zoom_factor;
+        ctx.set_zoom_factor(zoom_factor);
+        self.preferences.zoom_factor = Some(zoom_factor);
+        // Failing to save only means that the zoom is not remembered.
+        let _ = self.preferences.save();
+    }
+
+    /// Zoom with Ctrl+Plus, Ctrl+Minus, and Ctrl+0 (Cmd on macOS).
+    fn handle_zoom_keys(&mut self, ctx: &egui::Context) {
+        use egui::gui_zoom::kb_shortcuts;
+        let zoom_factor = ctx.input_mut(|input| {
+            if input.consume_shortcut(&kb_shortcuts::ZOOM_RESET) {
+                Some(components::DEFAULT_ZOOM_FACTOR)
+            } else if input.consume_shortcut(&kb_shortcuts::ZOOM_IN)
+                || input.consume_shortcut(&kb_shortcuts::ZOOM_IN_SECONDARY)
+            {
+                Some(components::step_zoom_factor(self.zoom_factor, 1))
+            } else if input.consume_shortcut(&kb_shortcuts::ZOOM_OUT) {
+                Some(components::step_zoom_factor(self.zoom_factor, -1))
+            } else {
+                None
+            }
+        });
+        if let Some(zoom_factor) = zoom_factor {
+            self.set_zoom_factor(ctx, zoom_factor);
+        }
+    }
+
+    /// Enter moves to the next screen and Escape goes back, like the buttons in the navbar.
+    ///
+    /// Keys are left alone while a popup is open. Enter clicks a focused button and Escape only
+    /// takes the focus away from a widget, so only Enter in text fields moves to the next screen.
+    fn handle_navigation_keys(&mut self, ui: &mut egui::Ui) {
+        let ctx = ui.ctx().clone();
+        if egui::Popup::is_any_open(&ctx) {
+            return;
+        }
+        let next_enabled = matches!(
+            self.next_button_spec(),
+            components::NavNext::Next(true) | components::NavNext::Install(true)
+        );
+        let button_focused = self
+            .focused_widget
+            .is_some_and(|id| egui::TextEdit::load_state(&ctx, id).is_none());
+        if next_enabled
+            && !button_focused
+            && ctx.input_mut(|input| input.consume_key(egui::Modifiers::NONE, egui::Key::Enter))
+        {
+            self.next_screen(ui);
+            return;
+        }
+
+        if self.focused_widget.is_some() {
+            return;
+        }
+        let go_back: Option<fn(&mut Self, &mut egui::Ui)> =
+            match (self.back_button_spec(), self.exit_button_spec()) {
+                (components::NavBack::Back, _) => Some(Self::previous_screen),
+                (_, components::NavExit::Cancel) => Some(Self::exit),
+                _ => None,
+            };
+        if let Some(go_back) = go_back {
+            if ctx.input_mut(|input| input.consume_key(egui::Modifiers::NONE, egui::Key::Escape)) {
+                go_back(self, ui);
+            }
+        }
+    }
 
+    fn show_bottom_panel(&mut self, ui: &mut egui::Ui) {
         match components::navbar(
             ui,
             self.next_button_spec(),
-            back_button_spec,
-            exit_button_spec,
+            self.back_button_spec(),
+            self.exit_button_spec(),
         )
         .clicked()
         {
@@ -350,11 +1497,32 @@ impl GameInstallApp {
         }
     }
 
+    fn exit_button_spec(&self) -> components::NavExit {
+        match self.screen {
+            Screen::Finished => components::NavExit::No,
+            Screen::Installing => components::NavExit::Cancel,
+            _ => components::NavExit::Exit,
+        }
+    }
+
+    fn back_button_spec(&self) -> components::NavBack {
+        match self.screen {
+            Screen::Home | Screen::Finished | Screen::Installing => components::NavBack::No,
+            _ => components::NavBack::Back,
+        }
+    }
+
     fn next_button_spec(&self) -> components::NavNext {
         match self.screen {
+            Screen::Home => components::NavNext::No,
             Screen::SelectGame => components::NavNext::Next(self.game_config.is_some()),
             Screen::ConfigurePlayer => {
-                components::NavNext::Next(!self.configure_player_state.name.is_empty())
+                let state = &self.configure_player_state;
+                components::NavNext::Next(
+                    !state.name.is_empty()
+                        && aigl_project::validate_bot_id(&state.id).is_ok()
+                        && state.args.iter().all(|arg| arg.validate().is_ok()),
+                )
             }
             Screen::SelectLocation => {
                 let state = &self.select_location_state;
@@ -362,28 +1530,144 @@ impl GameInstallApp {
                     !state.install_location.is_empty() && state.error.is_none(),
                 )
             }
-            Screen::Overview => components::NavNext::Install(true),
+            Screen::Overview => components::NavNext::Install(
+                !self.planning()
+                    && self
+                        .overview_state
+                        .thread_data
+                        .read()
+                        .is_ok_and(|data| data.plan.is_some()),
+            ),
             Screen::Installing => components::NavNext::Install(false),
             Screen::Finished => components::NavNext::Finish,
+            Screen::Doctor
+            | Screen::BotTests
+            | Screen::Lint
+            | Screen::MatchSetup
+            | Screen::Console => components::NavNext::No,
         }
     }
 
+    fn start_loading_projects(&mut self) {
+        let data = self.home_state.thread_data.clone();
+        self.home_state.thread = Some(std::thread::spawn(move || load_projects(data)));
+    }
+
+    fn start_project_action(&mut self, project_path: PathBuf, action: ProjectAction) {
+        let data = self.home_state.action_data.clone();
+        let home_data = self.home_state.thread_data.clone();
+
+        self.home_state.action_thread = Some(std::thread::spawn(move || {
+            run_project_action(data, home_data, project_path, action);
+        }));
+    }
+
+    /// Run the game of a project, stopping the game that is currently running.
+    fn start_game(&mut self, project_path: PathBuf) {
+        self.stop_game();
+        self.game_run_state = GameRunState {
+            project_path: project_path.clone(),
+            search: std::mem::take(&mut self.game_run_state.search),
+            ..Default::default()
+        };
+        let data = self.game_run_state.thread_data.clone();
+        let stop = self.game_run_state.stop.clone();
+
+        self.game_run_state.thread = Some(std::thread::spawn(move || {
+            run_game(data, project_path, stop);
+        }));
+    }
+
+    /// Tell the thread of the running game to kill it without waiting for it,
+    /// the thread only writes to the data of the stopped game.
+    fn stop_game(&mut self) -> Option<std::thread::JoinHandle<()>> {
+        self.game_run_state.stop.store(true, Ordering::Relaxed);
+        self.game_run_state.thread.take()
+    }
+
+    fn start_loading_match(&mut self) {
+        let data = self.match_setup_state.thread_data.clone();
+        let project_path = self.match_setup_state.project_path.clone();
+
+        self.match_setup_state.thread = Some(std::thread::spawn(move || {
+            load_match(data, project_path);
+        }));
+    }
+
+    fn start_saving_match(&mut self) {
+        let data = self.match_setup_state.thread_data.clone();
+        let project_path = self.match_setup_state.project_path.clone();
+        let players = self.match_setup_state.players.clone();
+
+        self.match_setup_state.thread = Some(std::thread::spawn(move || {
+            save_match(data, project_path, players);
+        }));
+    }
+
+    fn start_check(&mut self, fix: Option<Fix>) {
+        let data = self.doctor_state.thread_data.clone();
+        let project_path = self.doctor_state.project_path.clone();
+
+        self.doctor_state.thread = Some(std::thread::spawn(move || {
+            check(data, project_path, fix);
+        }));
+    }
+
+    fn start_bot_tests(&mut self, selection: TestSelection) {
+        let data = self.bot_tests_state.thread_data.clone();
+        let project_path = self.bot_tests_state.project_path.clone();
+        let bot_id = self.bot_tests_state.bot_id.clone();
+
+        self.bot_tests_state.thread = Some(std::thread::spawn(move || {
+            run_tests(data, project_path, bot_id, selection);
+        }));
+    }
+
+    fn start_lint_action(&mut self, action: LintAction) {
+        let data = self.lint_state.thread_data.clone();
+        let project_path = self.lint_state.project_path.clone();
+        let bot_id = self.lint_state.bot_id.clone();
+
+        self.lint_state.thread = Some(std::thread::spawn(move || {
+            run_lint_action(data, project_path, bot_id, action);
+        }));
+    }
+
+    fn start_planning(&mut self) {
+        // A new allocation so that a stale plan from going back and forth cannot show up.
+        self.overview_state.thread_data = Default::default();
+        let data = self.overview_state.thread_data.clone();
+        let target_path = PathBuf::from(&self.select_location_state.install_location);
+        let config = self.game_config.as_ref().unwrap().clone();
+        let player_bot_id = self.configure_player_state.id.clone();
+        let player_bot_name = self.configure_player_state.name.clone();
+        let player_bot_args = self.configure_player_state.args.clone();
+
+        self.overview_state.thread = Some(std::thread::spawn(move || {
+            plan_install(
+                data,
+                target_path,
+                config,
+                player_bot_id,
+                player_bot_name,
+                player_bot_args,
+            );
+        }));
+    }
+
     fn start_installation(&mut self) {
         let data = self.install_state.thread_data.clone();
+        let launcher_version = self.launcher_version;
         let target_path = PathBuf::from(&self.select_location_state.install_location);
         let config = self.game_config.as_ref().unwrap().clone();
         let player_bot_id = self.configure_player_state.id.clone();
         let player_bot_name = self.configure_player_state.name.clone();
         let player_bot_args = self.configure_player_state.args.clone();
 
-        // Safety: This is single threaded code.
-        unsafe {
-            aigl_project::config::init_environment(&target_path);
-        }
-
         self.install_state.thread = Some(std::thread::spawn(move || {
             let _ = install(
                 data,
+                launcher_version,
                 target_path,
                 config,
                 player_bot_id,
@@ -396,13 +1680,63 @@ impl GameInstallApp {
 
 impl eframe::App for GameInstallApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        ctx.set_zoom_factor(1.5);
+        self.update_theme(ctx);
+        self.update_language(ctx);
+        self.handle_zoom_keys(ctx);
+        if self.shown_screen != Some(self.screen) {
+            self.shown_screen = Some(self.screen);
+            self.focus_pending = true;
+        }
         egui::TopBottomPanel::top("heading").show(ctx, |ui| self.show_top_panel(ui));
         egui::TopBottomPanel::bottom("nav_buttons").show(ctx, |ui| self.show_bottom_panel(ui));
-        egui::CentralPanel::default().show(ctx, |ui| self.show_central_panel(ui));
+        egui::CentralPanel::default().show(ctx, |ui| {
+            self.show_central_panel(ui);
+            self.handle_navigation_keys(ui);
+        });
+        self.focused_widget = ctx.memory(|memory| memory.focused());
     }
 }
 
-fn default_bot_id(name: &str) -> String {
-    format!("bot_{}", name.to_lowercase())
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// The directory that is published as the default game source.
+    fn published_games() -> GameSources {
+        GameSources(vec![GameSource::Dir(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../resources/games"),
+        )])
+    }
+
+    #[test]
+    fn link_to_default_source_opens_player_configuration() {
+        let link = InstallLink::parse("aigl://install/test_game?name=Ada", Path::new(".")).unwrap();
+        assert_eq!(link.game_source, None);
+        let trust = TrustPolicy {
+            require_signatures: false,
+            ..TrustPolicy::builtin_only()
+        };
+        let fetched = fetch_game_config(&published_games(), &trust, &link.game_code);
+        assert!(!fetched.as_ref().unwrap().verification.is_verified());
+        assert_eq!(screen_after_link(&fetched), Screen::ConfigurePlayer);
+    }
+
+    #[test]
+    fn link_to_missing_game_stays_on_game_selection() {
+        let trust = TrustPolicy::default();
+        let fetched = fetch_game_config(&published_games(), &trust, "no_such_game");
+        assert_eq!(screen_after_link(&fetched), Screen::SelectGame);
+    }
+
+    #[test]
+    fn link_to_unsigned_game_stays_on_game_selection_if_signatures_are_required() {
+        let fetched = fetch_game_config(
+            &published_games(),
+            &TrustPolicy::builtin_only(),
+            "test_game",
+        );
+        assert!(fetched.is_err());
+        assert_eq!(screen_after_link(&fetched), Screen::SelectGame);
+    }
 }
@@ -0,0 +1,41 @@
+use anyhow::Result;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+
+use crate::runtime::block_on;
+use aigl_project::{Project, TestReport, TestSelection};
+
+#[derive(Debug, Default)]
+pub(crate) struct BotTestsThreadData {
+    pub(crate) report: Option<TestReport>,
+    pub(crate) error: Option<String>,
+}
+
+type Data = Arc<RwLock<BotTestsThreadData>>;
+
+pub(crate) fn run_tests(
+    data: Data,
+    project_path: PathBuf,
+    bot_id: String,
+    selection: TestSelection,
+) {
+    let result = block_on(async_run_tests(project_path, bot_id, selection));
+    if let Ok(mut data) = data.write() {
+        match result {
+            Ok(report) => {
+                data.report = Some(report);
+                data.error = None;
+            }
+            Err(err) => data.error = Some(format!("Failed to run tests: {err}")),
+        }
+    }
+}
+
+async fn async_run_tests(
+    project_path: PathBuf,
+    bot_id: String,
+    selection: TestSelection,
+) -> Result<TestReport> {
+    let project = Project::open(project_path).await?;
+    project.run_bot_tests(&bot_id, selection).await
+}
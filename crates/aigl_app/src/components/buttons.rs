@@ -1,5 +1,27 @@
-use eframe::egui::{self, Button};
+use eframe::egui::{self, Button, Response, Widget, WidgetInfo, WidgetType};
 
-pub fn icon_button<'a>(text: &str, icon: &str) -> Button<'a> {
-    Button::new(egui::RichText::new(format!("{icon} {text}")))
+/// A button with an icon in front of its text, see [`icon_button`].
+pub struct IconButton<'a> {
+    button: Button<'a>,
+    text: String,
+}
+
+/// A button with `icon` in front of `text`.
+///
+/// Screen readers only announce the text, not the icon glyph.
+pub fn icon_button<'a>(text: &str, icon: &str) -> IconButton<'a> {
+    IconButton {
+        button: Button::new(egui::RichText::new(format!("{icon} {text}"))),
+        text: text.to_owned(),
+    }
+}
+
+impl Widget for IconButton<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> Response {
+        let response = self.button.ui(ui);
+        response.widget_info(|| {
+            WidgetInfo::labeled(WidgetType::Button, response.enabled(), &self.text)
+        });
+        response
+    }
 }
@@ -0,0 +1,71 @@
+use eframe::egui::{self, RichText};
+
+use crate::i18n::{self, t};
+use crate::theme::Theme;
+use aigl_project::catalog::{Catalog, CatalogEntry};
+
+/// Show the games in `catalog` that match `query`.
+///
+/// Games that need a newer launcher than `launcher_version` cannot be selected.
+/// Returns the code of the game that was clicked.
+pub fn catalog_list(
+    ui: &mut egui::Ui,
+    catalog: &Catalog,
+    query: &str,
+    selected_code: &str,
+    launcher_version: &str,
+) -> Option<String> {
+    let mut clicked = None;
+    let mut any = false;
+    egui::ScrollArea::vertical()
+        .max_height(200.0)
+        .show(ui, |ui| {
+            for game in catalog.search(query) {
+                any = true;
+                if catalog_entry(ui, game, game.code == selected_code, launcher_version).clicked() {
+                    clicked = Some(game.code.clone());
+                }
+            }
+        });
+    if !any {
+        ui.label(t!("catalog-no-matches"));
+    }
+    clicked
+}
+
+fn catalog_entry(
+    ui: &mut egui::Ui,
+    game: &CatalogEntry,
+    selected: bool,
+    launcher_version: &str,
+) -> egui::Response {
+    let theme = Theme::get_selected();
+    let supported = game.is_supported(launcher_version);
+    let languages = i18n::content_languages();
+    ui.add_enabled_ui(supported, |ui| {
+        let response = ui.selectable_label(
+            selected,
+            RichText::new(game.localized_name(&languages)).strong(),
+        );
+        ui.indent(&game.code, |ui| {
+            let description = game.localized_description(&languages);
+            if !description.is_empty() {
+                ui.label(RichText::new(description).color(theme.base.fg_low_contrast.0));
+            }
+            if !game.tags.is_empty() {
+                ui.label(
+                    RichText::new(game.tags.join(", "))
+                        .small()
+                        .color(theme.highlight.fg_low_contrast.0),
+                );
+            }
+            if !supported {
+                if let Some(version) = &game.min_launcher_version {
+                    ui.label(t!("catalog-requires-version", version = version));
+                }
+            }
+        });
+        response
+    })
+    .inner
+}
@@ -0,0 +1,110 @@
+use eframe::egui::{
+    self, Color32, FontId, TextStyle,
+    text::{LayoutJob, TextFormat},
+};
+use std::collections::VecDeque;
+use std::path::Path;
+
+use crate::ansi::AnsiStyle;
+use crate::game_run::{ConsoleLine, SourceLocation, Stream};
+use crate::i18n::t;
+use crate::theme::Theme;
+
+/// The standard terminal colors followed by their bright variants.
+const ANSI_COLORS: [Color32; 16] = [
+    Color32::from_rgb(0x4d, 0x4d, 0x4d),
+    Color32::from_rgb(0xcd, 0x31, 0x31),
+    Color32::from_rgb(0x0d, 0xbc, 0x79),
+    Color32::from_rgb(0xe5, 0xe5, 0x10),
+    Color32::from_rgb(0x24, 0x72, 0xc8),
+    Color32::from_rgb(0xbc, 0x3f, 0xbc),
+    Color32::from_rgb(0x11, 0xa8, 0xcd),
+    Color32::from_rgb(0xe5, 0xe5, 0xe5),
+    Color32::from_rgb(0x66, 0x66, 0x66),
+    Color32::from_rgb(0xf1, 0x4c, 0x4c),
+    Color32::from_rgb(0x23, 0xd1, 0x8b),
+    Color32::from_rgb(0xf5, 0xf5, 0x43),
+    Color32::from_rgb(0x3b, 0x8e, 0xea),
+    Color32::from_rgb(0xd6, 0x70, 0xd6),
+    Color32::from_rgb(0x29, 0xb8, 0xdb),
+    Color32::from_rgb(0xff, 0xff, 0xff),
+];
+
+/// Show the output of the game, only lines that contain `query` (case-insensitive).
+///
+/// Traceback lines that refer to files in `base` are links.
+/// Returns the location that was clicked.
+pub fn console(
+    ui: &mut egui::Ui,
+    lines: &VecDeque<ConsoleLine>,
+    query: &str,
+    base: &Path,
+) -> Option<SourceLocation> {
+    let query = query.to_lowercase();
+    let visible: Vec<_> = lines
+        .iter()
+        .filter(|line| query.is_empty() || line.text.to_lowercase().contains(&query))
+        .collect();
+    let font = TextStyle::Monospace.resolve(ui.style());
+    let row_height = ui.text_style_height(&TextStyle::Monospace);
+
+    let mut clicked = None;
+    egui::Frame::new()
+        .fill(ui.visuals().extreme_bg_color)
+        .inner_margin(4.0)
+        .show(ui, |ui| {
+            egui::ScrollArea::both()
+                .auto_shrink(false)
+                .stick_to_bottom(true)
+                .show_rows(ui, row_height, visible.len(), |ui, rows| {
+                    for line in &visible[rows] {
+                        let job = line_layout(ui, line, &font);
+                        match &line.location {
+                            Some(location) if location.file.starts_with(base) => {
+                                if ui
+                                    .link(job)
+                                    .on_hover_text(t!("console-open-file"))
+                                    .clicked()
+                                {
+                                    clicked = Some(location.clone());
+                                }
+                            }
+                            _ => {
+                                ui.label(job);
+                            }
+                        }
+                    }
+                });
+        });
+    clicked
+}
+
+fn line_layout(ui: &egui::Ui, line: &ConsoleLine, font: &FontId) -> LayoutJob {
+    let theme = Theme::get_selected();
+    let default_color = if line.traceback {
+        theme.error.fg_high_contrast.0
+    } else if line.stream == Stream::Stderr {
+        theme.warning.fg_low_contrast.0
+    } else {
+        ui.visuals().text_color()
+    };
+    let mut job = LayoutJob::default();
+    job.wrap.max_width = f32::INFINITY;
+    for span in &line.spans {
+        job.append(
+            &span.text,
+            0.0,
+            TextFormat::simple(font.clone(), span_color(span.style, default_color)),
+        );
+    }
+    job
+}
+
+fn span_color(style: AnsiStyle, default_color: Color32) -> Color32 {
+    match style.color {
+        // Bold text uses the bright colors like in most terminals.
+        Some(color) if style.bold && color < 8 => ANSI_COLORS[usize::from(color) + 8],
+        Some(color) => ANSI_COLORS[usize::from(color) % 16],
+        None => default_color,
+    }
+}
@@ -0,0 +1,67 @@
+use eframe::egui::{self, RichText};
+use std::path::{Path, PathBuf};
+
+use crate::i18n::t;
+use crate::theme::Theme;
+use aigl_project::Diagnostic;
+
+#[derive(Clone, Debug, Default)]
+pub struct DiagnosticListResponse {
+    /// A file and line that the user wants to open.
+    pub open: Option<(PathBuf, usize)>,
+    /// A diagnostic that the user wants to fix.
+    pub fix: Option<Diagnostic>,
+}
+
+/// Show lint diagnostics with paths relative to `base`.
+pub fn diagnostic_list(
+    ui: &mut egui::Ui,
+    diagnostics: &[Diagnostic],
+    base: &Path,
+) -> DiagnosticListResponse {
+    let theme = Theme::get_selected();
+    let mut response = DiagnosticListResponse::default();
+    if diagnostics.is_empty() {
+        ui.label(t!("no-problems"));
+        return response;
+    }
+
+    ui.label(t!("lint-problems", count = diagnostics.len()));
+    egui::ScrollArea::vertical().show(ui, |ui| {
+        for diagnostic in diagnostics {
+            ui.horizontal(|ui| {
+                let path = diagnostic
+                    .file
+                    .strip_prefix(base)
+                    .unwrap_or(&diagnostic.file);
+                let location = format!(
+                    "{}:{}:{}",
+                    path.display(),
+                    diagnostic.line,
+                    diagnostic.column
+                );
+                if ui.link(location).clicked() {
+                    response.open = Some((diagnostic.file.clone(), diagnostic.line));
+                }
+                if let Some(code) = &diagnostic.code {
+                    ui.label(RichText::new(code).color(theme.warning.fg_low_contrast.0));
+                }
+                ui.label(&diagnostic.message);
+                if let Some(fix) = &diagnostic.fix {
+                    // ruff cannot fix a single occurrence, see `Project::fix_bot_code`.
+                    let hint = match &diagnostic.code {
+                        Some(code) => format!(
+                            "{fix}\n{}",
+                            t!("lint-fix-scope", code = code, file = path.display())
+                        ),
+                        None => fix.clone(),
+                    };
+                    if ui.small_button(t!("fix")).on_hover_text(hint).clicked() {
+                        response.fix = Some(diagnostic.clone());
+                    }
+                }
+            });
+        }
+    });
+    response
+}
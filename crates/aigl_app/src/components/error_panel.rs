@@ -0,0 +1,75 @@
+use eframe::egui::{self, RichText, WidgetType};
+use std::path::{Path, PathBuf};
+
+use super::widget::accessible_name;
+use crate::i18n::t;
+use crate::theme::Theme;
+use aigl_project::ErrorReport;
+
+/// Show an error with its causes and context and a button to copy all details.
+///
+/// Returns the log file if the player wants to open it.
+pub fn error_panel(
+    ui: &mut egui::Ui,
+    report: &ErrorReport,
+    log_path: Option<&Path>,
+) -> Option<PathBuf> {
+    let theme = Theme::get_selected();
+    let mut open = None;
+    egui::Frame::group(ui.style()).show(ui, |ui| {
+        let summary = ui.colored_label(
+            theme.error.fg_high_contrast.0,
+            RichText::new(format!(
+                "{} {}",
+                egui_phosphor::regular::X_CIRCLE,
+                report.summary
+            ))
+            .strong(),
+        );
+        accessible_name(summary, WidgetType::Label, &report.summary);
+        ui.label(&report.step);
+
+        if !report.causes.is_empty() {
+            ui.add_space(5.0);
+            ui.label(t!("error-caused-by"));
+            for cause in &report.causes {
+                ui.label(RichText::new(cause).monospace());
+            }
+        }
+
+        ui.add_space(5.0);
+        egui::CollapsingHeader::new(t!("error-details"))
+            .id_salt("error_details")
+            .show(ui, |ui| {
+                egui::Grid::new("error_context").show(ui, |ui| {
+                    for (label, value) in &report.context {
+                        ui.label(RichText::new(label).color(theme.base.fg_low_contrast.0));
+                        ui.label(value);
+                        ui.end_row();
+                    }
+                });
+            });
+
+        ui.horizontal(|ui| {
+            if ui
+                .add(super::icon_button(
+                    &t!("error-copy-details"),
+                    egui_phosphor::regular::COPY,
+                ))
+                .clicked()
+            {
+                ui.ctx().copy_text(report.to_string());
+            }
+            if let Some(log_path) = log_path {
+                if ui
+                    .link(t!("error-saved-to", path = log_path.display()))
+                    .on_hover_text(t!("error-open-log"))
+                    .clicked()
+                {
+                    open = Some(log_path.to_path_buf());
+                }
+            }
+        });
+    });
+    open
+}
@@ -1,6 +1,9 @@
-use eframe::egui::{self, InnerResponse, Response};
+use eframe::egui::{self, InnerResponse, Response, WidgetType};
 
+use super::widget::accessible_name;
 use crate::components;
+use crate::i18n::t;
+use crate::messages;
 use aigl_project::{BotArg, BotArgValue};
 
 pub fn text_input(ui: &mut egui::Ui, label: &str, text: &mut String) -> InnerResponse<Response> {
@@ -10,6 +13,15 @@ pub fn text_input(ui: &mut egui::Ui, label: &str, text: &mut String) -> InnerRes
     })
 }
 
+/// A text field for a search query with a magnifying glass in front of it.
+pub fn search_input(ui: &mut egui::Ui, query: &mut String) -> InnerResponse<Response> {
+    ui.horizontal(|ui| {
+        ui.label(egui_phosphor::regular::MAGNIFYING_GLASS);
+        let edit = ui.add(egui::TextEdit::singleline(query).hint_text(t!("search")));
+        accessible_name(edit, WidgetType::TextEdit, &t!("search"))
+    })
+}
+
 pub fn color_input(ui: &mut egui::Ui, label: &str, color: &mut [u8; 3]) -> InnerResponse<Response> {
     ui.horizontal(|ui| {
         let label_response = ui.label(label);
@@ -22,7 +34,7 @@ pub fn path_input(ui: &mut egui::Ui, label: &str, path: &mut String) -> InnerRes
     ui.horizontal(|ui| {
         let label_response = ui.label(label);
         let edit = ui.text_edit_singleline(path).labelled_by(label_response.id);
-        if ui.button("Browse").clicked() {
+        if ui.button(t!("browse")).clicked() {
             if let Some(selected) = rfd::FileDialog::new().pick_folder() {
                 *path = selected.display().to_string();
             }
@@ -31,12 +43,82 @@ pub fn path_input(ui: &mut egui::Ui, label: &str, path: &mut String) -> InnerRes
     })
 }
 
+pub fn integer_input(
+    ui: &mut egui::Ui,
+    label: &str,
+    value: &mut i64,
+    min: Option<i64>,
+    max: Option<i64>,
+) -> InnerResponse<Response> {
+    ui.horizontal(|ui| {
+        let label_response = ui.label(label);
+        let range = min.unwrap_or(i64::MIN)..=max.unwrap_or(i64::MAX);
+        ui.add(egui::DragValue::new(value).range(range))
+            .labelled_by(label_response.id)
+    })
+}
+
+pub fn float_input(
+    ui: &mut egui::Ui,
+    label: &str,
+    value: &mut f64,
+    min: Option<f64>,
+    max: Option<f64>,
+) -> InnerResponse<Response> {
+    ui.horizontal(|ui| {
+        let label_response = ui.label(label);
+        let range = min.unwrap_or(f64::NEG_INFINITY)..=max.unwrap_or(f64::INFINITY);
+        ui.add(egui::DragValue::new(value).range(range).speed(0.01))
+            .labelled_by(label_response.id)
+    })
+}
+
+pub fn choice_input(
+    ui: &mut egui::Ui,
+    label: &str,
+    value: &mut String,
+    options: &[String],
+) -> InnerResponse<Response> {
+    ui.horizontal(|ui| {
+        let label_response = ui.label(label);
+        let mut response = egui::ComboBox::from_id_salt(label)
+            .selected_text(value.as_str())
+            .show_ui(ui, |ui| {
+                let mut changed = false;
+                for option in options {
+                    changed |= ui.selectable_value(value, option.clone(), option).changed();
+                }
+                changed
+            });
+        if response.inner == Some(true) {
+            response.response.mark_changed();
+        }
+        response.response.labelled_by(label_response.id)
+    })
+}
+
+/// Input for a bot arg that shows its description on hover and its validation error.
 pub fn bot_arg_input(ui: &mut egui::Ui, arg: &mut BotArg) -> InnerResponse<Response> {
-    match &mut arg.value {
-        BotArgValue::String(value) => text_input(ui, &arg.display, value),
-        BotArgValue::Color(value) => color_input(ui, &arg.display, value),
-        BotArgValue::Path(value) => path_input(ui, &arg.display, value),
+    let display = &arg.display;
+    let response = match &mut arg.value {
+        BotArgValue::String(value) => text_input(ui, display, value),
+        BotArgValue::Color(value) => color_input(ui, display, value),
+        BotArgValue::Path(value) => path_input(ui, display, value),
+        BotArgValue::Integer { value, min, max } => integer_input(ui, display, value, *min, *max),
+        BotArgValue::Float { value, min, max } => float_input(ui, display, value, *min, *max),
+        BotArgValue::Bool(value) => ui.horizontal(|ui| ui.checkbox(value, display.as_str())),
+        BotArgValue::Choice { value, options } => choice_input(ui, display, value, options),
+    };
+    let response = match &arg.description {
+        Some(description) => {
+            InnerResponse::new(response.inner.on_hover_text(description), response.response)
+        }
+        None => response,
+    };
+    if let Err(error) = arg.validate() {
+        ui.colored_label(ui.visuals().error_fg_color, messages::bot_arg_error(&error));
     }
+    response
 }
 
 pub fn button_input(
@@ -47,13 +129,16 @@ pub fn button_input(
 ) -> InnerResponse<ButtonInputResponse> {
     ui.horizontal(|ui| {
         let input = ui.text_edit_singleline(text);
+        let input = accessible_name(input, WidgetType::TextEdit, button_label);
         let button = ui.add_enabled(
             !text.is_empty(),
             components::icon_button(button_label, button_icon),
         );
+        // Consume the key so that Enter does not also move to the next screen.
+        let entered = input.lost_focus()
+            && ui.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::Enter));
         ButtonInputResponse {
-            // lost_focus happens when enter is pressed
-            accepted: input.lost_focus() || button.clicked(),
+            accepted: entered || button.clicked(),
         }
     })
 }
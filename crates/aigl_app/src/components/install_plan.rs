@@ -0,0 +1,102 @@
+use eframe::egui::{self, RichText};
+use std::path::Path;
+
+use super::widget::icon_label;
+use crate::i18n::t;
+use crate::messages;
+use crate::theme::Theme;
+use aigl_project::{InstallPlan, RepoPlan, RepoRef, format_size};
+
+/// Show what an installation will download and create and what could go wrong.
+pub fn install_plan(ui: &mut egui::Ui, plan: &InstallPlan) {
+    let theme = Theme::get_selected();
+
+    for warning in &plan.warnings {
+        icon_label(
+            ui,
+            egui_phosphor::regular::WARNING,
+            &messages::plan_warning(warning),
+            theme.warning.fg_high_contrast.0,
+        );
+    }
+    if !plan.warnings.is_empty() {
+        ui.add_space(5.0);
+    }
+
+    egui::Grid::new("install_plan")
+        .num_columns(2)
+        .striped(true)
+        .show(ui, |ui| {
+            let mut row = |label: String, add_value: &mut dyn FnMut(&mut egui::Ui)| {
+                ui.label(RichText::new(label).color(theme.base.fg_low_contrast.0));
+                ui.vertical(|ui| add_value(ui));
+                ui.end_row();
+            };
+
+            row(t!("plan-location"), &mut |ui| path_label(ui, &plan.root));
+            row(t!("plan-game"), &mut |ui| repo_label(ui, &plan.game));
+            row(t!("plan-bot-template"), &mut |ui| {
+                repo_label(ui, &plan.bot_template)
+            });
+            row(t!("plan-python"), &mut |ui| {
+                ui.label(t!("plan-python-download", version = plan.python_version));
+            });
+            row(t!("plan-player-bot"), &mut |ui| {
+                ui.label(format!("{} ({})", plan.player_bot.name, plan.player_bot.id));
+                path_label(ui, &plan.player_bot.path);
+                for (display, value) in &plan.player_bot.args {
+                    ui.label(format!("{display}: {value}"));
+                }
+            });
+            row(t!("plan-opponents"), &mut |ui| {
+                ui.label(t!("plan-opponents-generated", count = plan.n_opponents));
+                ui.label(t!("plan-players-selected-in"));
+                path_label(ui, &plan.player_config_path);
+            });
+            row(t!("plan-venv"), &mut |ui| {
+                path_label(ui, &plan.venv.path);
+                ui.label(t!("plan-venv-packages"));
+                for package in &plan.venv.packages {
+                    path_label(ui, package);
+                }
+            });
+            row(t!("plan-size"), &mut |ui| {
+                ui.label(t!(
+                    "plan-size-value",
+                    download = format_size(plan.download_size),
+                    disk = format_size(plan.disk_size)
+                ))
+                .on_hover_text(t!("plan-size-hint"));
+            });
+        });
+}
+
+fn repo_label(ui: &mut egui::Ui, repo: &RepoPlan) {
+    ui.label(&repo.url);
+    match &repo.reference {
+        RepoRef::Pinned(commit) => ui.label(t!("plan-commit-pinned", commit = commit)),
+        RepoRef::Latest {
+            branch: Some(branch),
+            commit,
+        } => ui.label(t!(
+            "plan-commit-latest-on",
+            branch = branch,
+            commit = short(commit)
+        )),
+        RepoRef::Latest {
+            branch: None,
+            commit,
+        } => ui.label(t!("plan-commit-latest", commit = short(commit))),
+        RepoRef::Unresolved(_) => ui.label(t!("plan-commit-unknown")),
+    };
+    path_label(ui, &repo.target);
+}
+
+fn path_label(ui: &mut egui::Ui, path: &Path) {
+    ui.label(RichText::new(path.display().to_string()).monospace());
+}
+
+/// Abbreviate a commit hash like git does.
+fn short(commit: &str) -> &str {
+    commit.get(..7).unwrap_or(commit)
+}
@@ -0,0 +1,40 @@
+use eframe::egui::{self, WidgetType};
+
+use super::widget::accessible_name;
+use crate::i18n::{LANGUAGES, LanguageChoice, find_language, t};
+
+/// Select one of the languages that the user interface is translated to.
+///
+/// Returns `true` if `choice` was changed.
+pub fn language_picker(ui: &mut egui::Ui, choice: &mut LanguageChoice) -> bool {
+    let selected_text = match choice {
+        LanguageChoice::System => t!("language-system"),
+        LanguageChoice::Language(code) => find_language(code)
+            .map_or(*code, |language| language.name)
+            .to_owned(),
+    };
+    let mut changed = false;
+    let response = egui::ComboBox::from_id_salt("language_picker")
+        .selected_text(format!(
+            "{} {selected_text}",
+            egui_phosphor::regular::TRANSLATE
+        ))
+        .show_ui(ui, |ui| {
+            changed |= ui
+                .selectable_value(choice, LanguageChoice::System, t!("language-system"))
+                .on_hover_text(t!("language-system-hint"))
+                .changed();
+            for language in &LANGUAGES {
+                changed |= ui
+                    .selectable_value(
+                        choice,
+                        LanguageChoice::Language(language.code),
+                        language.name,
+                    )
+                    .changed();
+            }
+        })
+        .response;
+    accessible_name(response, WidgetType::ComboBox, &t!("language")).on_hover_text(t!("language"));
+    changed
+}
@@ -0,0 +1,135 @@
+use eframe::egui::{self, WidgetType};
+
+use super::widget::accessible_name;
+use crate::i18n::t;
+use aigl_project::{BotArgValue, MatchPlayer};
+
+/// Edit which bots play in a match, how many copies of each, and their overrides.
+///
+/// Returns `true` if `players` was changed.
+pub fn match_editor(ui: &mut egui::Ui, players: &mut Vec<MatchPlayer>, bot_ids: &[String]) -> bool {
+    let mut changed = false;
+
+    ui.label(t!("match-copies"));
+    egui::Grid::new("match_bot_counts").show(ui, |ui| {
+        for bot_id in bot_ids {
+            let label = ui.label(bot_id);
+            let count = players.iter().filter(|p| &p.package == bot_id).count();
+            let mut new_count = count;
+            ui.add(egui::DragValue::new(&mut new_count).range(0..=99))
+                .labelled_by(label.id);
+            ui.end_row();
+            if new_count != count {
+                set_count(players, bot_id, new_count);
+                changed = true;
+            }
+        }
+    });
+
+    ui.add_space(10.0);
+    ui.label(t!("match-players"));
+    let mut remove = None;
+    egui::ScrollArea::vertical()
+        .max_height(250.0)
+        .show(ui, |ui| {
+            egui::Grid::new("match_players").show(ui, |ui| {
+                for (i, player) in players.iter_mut().enumerate() {
+                    ui.push_id(i, |ui| {
+                        ui.label(&player.package);
+                    });
+                    ui.push_id(i, |ui| changed |= name_override(ui, &mut player.name));
+                    ui.push_id(i, |ui| changed |= color_override(ui, &mut player.color));
+                    let remove_button = accessible_name(
+                        ui.small_button(egui_phosphor::regular::TRASH),
+                        WidgetType::Button,
+                        &t!("match-remove-player"),
+                    );
+                    if remove_button
+                        .on_hover_text(t!("match-remove-player"))
+                        .clicked()
+                    {
+                        remove = Some(i);
+                    }
+                    ui.end_row();
+                }
+            });
+        });
+    if let Some(i) = remove {
+        players.remove(i);
+        changed = true;
+    }
+    changed
+}
+
+/// Add or remove players of `bot_id` at the end of the list.
+fn set_count(players: &mut Vec<MatchPlayer>, bot_id: &str, count: usize) {
+    let mut current = players.iter().filter(|p| p.package == bot_id).count();
+    while current < count {
+        let player = if current == 0 {
+            MatchPlayer {
+                package: bot_id.to_owned(),
+                ..Default::default()
+            }
+        } else {
+            MatchPlayer::with_unique_overrides(bot_id, players)
+        };
+        players.push(player);
+        current += 1;
+    }
+    while current > count {
+        if let Some(i) = players.iter().rposition(|p| p.package == bot_id) {
+            players.remove(i);
+        }
+        current -= 1;
+    }
+}
+
+fn name_override(ui: &mut egui::Ui, name: &mut Option<String>) -> bool {
+    let mut custom = name.is_some();
+    let checkbox = ui
+        .checkbox(&mut custom, t!("match-name"))
+        .on_hover_text(t!("match-name-hint"));
+    let mut changed = checkbox.changed();
+    if changed {
+        *name = custom.then(String::new);
+    }
+    ui.add_enabled_ui(custom, |ui| {
+        let mut text = name.clone().unwrap_or_default();
+        if ui
+            .text_edit_singleline(&mut text)
+            .labelled_by(checkbox.id)
+            .changed()
+        {
+            *name = Some(text);
+            changed = true;
+        }
+    });
+    changed
+}
+
+fn color_override(ui: &mut egui::Ui, color: &mut Option<String>) -> bool {
+    let mut custom = color.is_some();
+    let checkbox = ui
+        .checkbox(&mut custom, t!("match-color"))
+        .on_hover_text(t!("match-color-hint"));
+    let mut changed = checkbox.changed();
+    if changed {
+        *color = custom.then(|| "#000000".to_owned());
+    }
+    ui.add_enabled_ui(custom, |ui| {
+        let BotArgValue::Color(mut rgb) =
+            BotArgValue::color_from_string(color.as_deref().unwrap_or_default())
+        else {
+            return;
+        };
+        if ui
+            .color_edit_button_srgb(&mut rgb)
+            .labelled_by(checkbox.id)
+            .changed()
+        {
+            *color = Some(BotArgValue::Color(rgb).render_value());
+            changed = true;
+        }
+    });
+    changed
+}
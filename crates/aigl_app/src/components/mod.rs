@@ -1,10 +1,32 @@
 mod buttons;
+mod catalog;
+mod console;
+mod diagnostics;
+mod error_panel;
 mod input;
+mod install_plan;
+mod language_picker;
+mod match_editor;
 mod navbar;
+mod project_list;
+mod test_report;
 mod text;
+mod theme_picker;
 mod widget;
+mod zoom_control;
 
 pub use buttons::icon_button;
-pub use input::{bot_arg_input, button_input, text_input};
+pub use catalog::catalog_list;
+pub use console::console;
+pub use diagnostics::diagnostic_list;
+pub use error_panel::error_panel;
+pub use input::{bot_arg_input, button_input, search_input, text_input};
+pub use install_plan::install_plan;
+pub use language_picker::language_picker;
+pub use match_editor::match_editor;
 pub use navbar::{NavBack, NavClicked, NavExit, NavNext, navbar};
-pub use text::game_info_text;
+pub use project_list::{ProjectClicked, project_list};
+pub use test_report::test_report;
+pub use text::{finding_text, game_info_text, verification_text};
+pub use theme_picker::theme_picker;
+pub use zoom_control::{DEFAULT_ZOOM_FACTOR, step_zoom_factor, zoom_control};
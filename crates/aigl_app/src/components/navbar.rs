@@ -1,5 +1,6 @@
 use super::icon_button;
 use super::widget::add_enabled_with_colors;
+use crate::i18n::t;
 use crate::theme::Theme;
 use eframe::egui;
 
@@ -14,29 +15,22 @@ pub fn navbar(ui: &mut egui::Ui, next: NavNext, back: NavBack, exit: NavExit) ->
             clicked = NavClicked::Exit;
         }
 
-        let mut response = ui
+        let response = ui
             .with_layout(inner_layout, |ui| {
                 let next_response = add_next_button(ui, next);
                 let back_response = add_back_button(ui, back);
-                if next_response.clicked() {
+                if next_response.as_ref().is_some_and(|r| r.clicked()) {
                     clicked = NavClicked::Next;
                 }
                 if back_response.as_ref().is_some_and(|r| r.clicked()) {
                     clicked = NavClicked::Back;
                 }
 
-                let mut response = next_response;
-                if let Some(r) = back_response {
-                    response = response.union(r);
-                }
-                response
+                union_responses(next_response, back_response)
             })
             .inner;
 
-        if let Some(r) = exit_response {
-            response = response.union(r);
-        }
-        response
+        union_responses(response, exit_response).unwrap_or_else(|| ui.response())
     });
 
     NavResponse {
@@ -51,6 +45,7 @@ pub enum NavNext {
     Next(bool),
     Install(bool),
     Finish,
+    No,
 }
 
 #[derive(Copy, Clone)]
@@ -88,42 +83,57 @@ pub enum NavClicked {
     None,
 }
 
-fn add_next_button(ui: &mut egui::Ui, spec: NavNext) -> egui::Response {
+fn union_responses(a: Option<egui::Response>, b: Option<egui::Response>) -> Option<egui::Response> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(a.union(b)),
+        (a, b) => a.or(b),
+    }
+}
+
+fn add_next_button(ui: &mut egui::Ui, spec: NavNext) -> Option<egui::Response> {
     let theme = Theme::get_selected();
-    match spec {
+    let response = match spec {
         NavNext::Next(enabled) => add_enabled_with_colors(
             ui,
             enabled,
             &theme.highlight_widget_visuals,
-            icon_button("Next", egui_phosphor::regular::CARET_RIGHT),
+            icon_button(&t!("nav-next"), egui_phosphor::regular::CARET_RIGHT),
         ),
         NavNext::Install(enabled) => add_enabled_with_colors(
             ui,
             enabled,
             &theme.highlight_widget_visuals,
-            icon_button("Install", egui_phosphor::regular::CHECK),
+            icon_button(&t!("nav-install"), egui_phosphor::regular::CHECK),
         ),
         NavNext::Finish => add_enabled_with_colors(
             ui,
             true,
             &theme.highlight_widget_visuals,
-            icon_button("Finish", egui_phosphor::regular::SIGN_OUT),
+            icon_button(&t!("nav-finish"), egui_phosphor::regular::SIGN_OUT),
         ),
-    }
+        NavNext::No => return None,
+    };
+    Some(response)
 }
 
 fn add_back_button(ui: &mut egui::Ui, spec: NavBack) -> Option<egui::Response> {
     match spec {
-        NavBack::Back => Some(ui.add(icon_button("Back", egui_phosphor::regular::CARET_LEFT))),
+        NavBack::Back => Some(ui.add(icon_button(
+            &t!("nav-back"),
+            egui_phosphor::regular::CARET_LEFT,
+        ))),
         NavBack::No => None,
     }
 }
 
 fn add_exit_button(ui: &mut egui::Ui, spec: NavExit) -> Option<egui::Response> {
     match spec {
-        NavExit::Exit => Some(ui.add(icon_button("Exit", egui_phosphor::regular::SIGN_OUT))),
+        NavExit::Exit => Some(ui.add(icon_button(
+            &t!("nav-exit"),
+            egui_phosphor::regular::SIGN_OUT,
+        ))),
         NavExit::Cancel => Some(ui.add(icon_button(
-            "Cancel",
+            &t!("cancel"),
             egui_phosphor::regular::PROHIBIT_INSET,
         ))),
         NavExit::No => None,
@@ -0,0 +1,238 @@
+use eframe::egui::{self, RichText};
+use std::path::PathBuf;
+
+use super::widget::icon_label;
+use crate::home::ProjectSummary;
+use crate::i18n::{self, t};
+use crate::theme::Theme;
+use aigl_project::Severity;
+
+/// A button of a project in [`project_list`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProjectClicked {
+    Play,
+    AddBot,
+    RunTests { bot: String },
+    CheckCode { bot: String },
+    FormatCode { bot: String },
+    SetUpMatch,
+    UpdateGame,
+    UpdateBotTemplate,
+    OpenFolder,
+    Check,
+    Uninstall,
+    Forget,
+}
+
+/// Show the installed projects with their bots, health, and actions.
+///
+/// Buttons that modify projects are disabled while `busy`.
+/// Returns the path of the project whose button was clicked.
+pub fn project_list(
+    ui: &mut egui::Ui,
+    projects: &[ProjectSummary],
+    busy: bool,
+) -> Option<(PathBuf, ProjectClicked)> {
+    let mut clicked = None;
+    egui::ScrollArea::vertical()
+        .max_height(300.0)
+        .show(ui, |ui| {
+            for project in projects {
+                if let Some(button) = project_entry(ui, project, busy) {
+                    clicked = Some((project.path.clone(), button));
+                }
+                ui.separator();
+            }
+        });
+    if projects.is_empty() {
+        ui.label(t!("projects-empty"));
+    }
+    clicked
+}
+
+fn project_entry(
+    ui: &mut egui::Ui,
+    project: &ProjectSummary,
+    busy: bool,
+) -> Option<ProjectClicked> {
+    let theme = Theme::get_selected();
+    let mut clicked = None;
+    let game_name = project
+        .game_config
+        .as_ref()
+        .map_or(&project.game_name[..], |config| {
+            config.localized_name(&i18n::content_languages())
+        });
+    ui.label(RichText::new(game_name).strong());
+    ui.indent(&project.path, |ui| {
+        ui.label(
+            RichText::new(project.path.display().to_string()).color(theme.base.fg_low_contrast.0),
+        );
+        health_text(ui, project);
+
+        let available = project.game_config.is_some();
+        if available && !project.bot_ids.is_empty() {
+            ui.label(t!("projects-bots"));
+            for bot in &project.bot_ids {
+                if let Some(action) = bot_entry(ui, bot, busy) {
+                    clicked = Some(action);
+                }
+            }
+        }
+        ui.horizontal_wrapped(|ui| {
+            let mut button = |ui: &mut egui::Ui, enabled: bool, text: String, icon, action| {
+                if ui
+                    .add_enabled(enabled, super::icon_button(&text, icon))
+                    .clicked()
+                {
+                    clicked = Some(action);
+                }
+            };
+            if available {
+                let modifiable = !busy;
+                use egui_phosphor::regular as icons;
+                button(ui, true, t!("play"), icons::PLAY, ProjectClicked::Play);
+                button(
+                    ui,
+                    modifiable,
+                    t!("projects-add-bot"),
+                    icons::PLUS,
+                    ProjectClicked::AddBot,
+                );
+                button(
+                    ui,
+                    modifiable,
+                    t!("projects-set-up-match"),
+                    icons::LIST,
+                    ProjectClicked::SetUpMatch,
+                );
+                button(
+                    ui,
+                    modifiable,
+                    t!("projects-update-game"),
+                    icons::ARROW_CLOCKWISE,
+                    ProjectClicked::UpdateGame,
+                );
+                button(
+                    ui,
+                    modifiable,
+                    t!("projects-update-template"),
+                    icons::ARROWS_CLOCKWISE,
+                    ProjectClicked::UpdateBotTemplate,
+                );
+                button(
+                    ui,
+                    true,
+                    t!("projects-open-folder"),
+                    icons::FOLDER_OPEN,
+                    ProjectClicked::OpenFolder,
+                );
+                button(
+                    ui,
+                    modifiable,
+                    t!("projects-check"),
+                    icons::WRENCH,
+                    ProjectClicked::Check,
+                );
+                button(
+                    ui,
+                    modifiable,
+                    t!("uninstall"),
+                    icons::TRASH,
+                    ProjectClicked::Uninstall,
+                );
+            } else {
+                button(
+                    ui,
+                    !busy,
+                    t!("projects-forget"),
+                    egui_phosphor::regular::X,
+                    ProjectClicked::Forget,
+                );
+            }
+        });
+    });
+    clicked
+}
+
+/// A bot of a project with the tools that work on its code.
+///
+/// Formatting modifies the bot's files, so it is disabled while `busy`.
+fn bot_entry(ui: &mut egui::Ui, bot: &str, busy: bool) -> Option<ProjectClicked> {
+    let mut clicked = None;
+    ui.horizontal(|ui| {
+        ui.label(RichText::new(bot).monospace());
+        let bot = bot.to_string();
+        let mut button = |ui: &mut egui::Ui, enabled: bool, text: String, icon, action| {
+            if ui
+                .add_enabled(enabled, super::icon_button(&text, icon))
+                .clicked()
+            {
+                clicked = Some(action);
+            }
+        };
+        use egui_phosphor::regular as icons;
+        button(
+            ui,
+            true,
+            t!("run-tests"),
+            icons::BUG,
+            ProjectClicked::RunTests { bot: bot.clone() },
+        );
+        button(
+            ui,
+            true,
+            t!("check-code"),
+            icons::CODE,
+            ProjectClicked::CheckCode { bot: bot.clone() },
+        );
+        button(
+            ui,
+            !busy,
+            t!("lint-format"),
+            icons::SPARKLE,
+            ProjectClicked::FormatCode { bot },
+        );
+    });
+    clicked
+}
+
+fn health_text(ui: &mut egui::Ui, project: &ProjectSummary) {
+    let theme = Theme::get_selected();
+    match &project.health {
+        Err(error) => {
+            ui.colored_label(ui.visuals().error_fg_color, error);
+        }
+        Ok(findings) if findings.is_empty() => {
+            icon_label(
+                ui,
+                egui_phosphor::regular::CHECK_CIRCLE,
+                &t!("no-problems"),
+                theme.highlight.fg_high_contrast.0,
+            );
+        }
+        Ok(findings) => {
+            let n_errors = findings
+                .iter()
+                .filter(|finding| finding.severity == Severity::Error)
+                .count();
+            let (icon, color) = if n_errors > 0 {
+                (
+                    egui_phosphor::regular::X_CIRCLE,
+                    theme.error.fg_high_contrast.0,
+                )
+            } else {
+                (
+                    egui_phosphor::regular::WARNING,
+                    theme.warning.fg_high_contrast.0,
+                )
+            };
+            icon_label(
+                ui,
+                icon,
+                &t!("projects-problems", count = findings.len()),
+                color,
+            );
+        }
+    }
+}
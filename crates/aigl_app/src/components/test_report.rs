@@ -0,0 +1,96 @@
+use eframe::egui::{self, RichText, WidgetType};
+use std::collections::BTreeMap;
+
+use super::widget::accessible_name;
+use crate::i18n::t;
+use crate::theme::Theme;
+use aigl_project::{TestCase, TestOutcome, TestReport};
+
+/// Show test results as a tree grouped by module and class.
+pub fn test_report(ui: &mut egui::Ui, report: &TestReport) {
+    let theme = Theme::get_selected();
+    ui.label(t!(
+        "tests-summary",
+        passed = report.n_passed(),
+        failed = report.n_failed(),
+        errors = report.n_errors(),
+        skipped = report.n_skipped()
+    ));
+
+    let mut groups = BTreeMap::<&str, Vec<&TestCase>>::new();
+    for case in &report.cases {
+        groups.entry(&case.class_name).or_default().push(case);
+    }
+
+    egui::ScrollArea::vertical().show(ui, |ui| {
+        for (class_name, cases) in groups {
+            let failed = cases.iter().any(|case| case.is_failure());
+            let color = if failed {
+                theme.error.fg_high_contrast.0
+            } else {
+                theme.base.fg_high_contrast.0
+            };
+            egui::CollapsingHeader::new(RichText::new(class_name).color(color))
+                .default_open(failed)
+                .show(ui, |ui| {
+                    for case in cases {
+                        test_case(ui, case);
+                    }
+                });
+        }
+    });
+}
+
+fn test_case(ui: &mut egui::Ui, case: &TestCase) {
+    let theme = Theme::get_selected();
+    let (icon, color, accessible_title) = match &case.outcome {
+        TestOutcome::Passed => (
+            egui_phosphor::regular::CHECK_CIRCLE,
+            theme.highlight.fg_low_contrast.0,
+            t!("tests-passed", name = case.name),
+        ),
+        TestOutcome::Failed { .. } | TestOutcome::Error { .. } => (
+            egui_phosphor::regular::X_CIRCLE,
+            theme.error.fg_high_contrast.0,
+            t!("tests-failed", name = case.name),
+        ),
+        TestOutcome::Skipped { .. } => (
+            egui_phosphor::regular::MINUS,
+            theme.base.fg_low_contrast.0,
+            t!("tests-skipped", name = case.name),
+        ),
+    };
+    let title = RichText::new(format!("{icon} {}", case.name)).color(color);
+
+    let (message, details) = match &case.outcome {
+        TestOutcome::Passed => (None, None),
+        TestOutcome::Failed { message, details } | TestOutcome::Error { message, details } => {
+            (Some(message), Some(details))
+        }
+        TestOutcome::Skipped { message } => (Some(message), None),
+    };
+    if message.is_none() && case.output.is_empty() {
+        accessible_name(ui.label(title), WidgetType::Label, &accessible_title);
+        return;
+    }
+
+    let header = egui::CollapsingHeader::new(title)
+        .id_salt((&case.class_name, &case.name))
+        .show(ui, |ui| {
+            if let (Some(file), Some(line)) = (&case.file, case.line) {
+                ui.label(format!("{file}:{line}"));
+            }
+            if let Some(message) = message.filter(|m| !m.is_empty()) {
+                ui.label(message);
+            }
+            if let Some(details) = details.filter(|d| !d.is_empty()) {
+                ui.code(details);
+            }
+            if !case.output.is_empty() {
+                ui.label(t!("tests-captured-output"));
+                ui.code(&case.output);
+            }
+        })
+        .header_response;
+    accessible_name(header, WidgetType::CollapsingHeader, &accessible_title);
+}
@@ -3,15 +3,19 @@ use eframe::egui::{
     text::{LayoutJob, TextFormat},
 };
 
+use super::widget::icon_label;
+use crate::i18n::{self, t};
+use crate::messages;
 use crate::theme::Theme;
-use aigl_project::config::game::GameConfig;
+use aigl_project::signing::Verification;
+use aigl_project::{Finding, Severity, config::game::GameConfig};
 
 pub fn game_info_text(ui: &mut egui::Ui, config: &GameConfig) -> Response {
     let theme = Theme::get_selected();
     let mut job = LayoutJob::default();
-    job.append("Game name: ", 0.0, TextFormat::default());
+    job.append(&format!("{} ", t!("game-name")), 0.0, TextFormat::default());
     job.append(
-        &config.name,
+        config.localized_name(&i18n::content_languages()),
         0.0,
         TextFormat {
             color: theme.highlight.fg_low_contrast.0,
@@ -20,3 +24,40 @@ pub fn game_info_text(ui: &mut egui::Ui, config: &GameConfig) -> Response {
     );
     ui.label(job)
 }
+
+pub fn finding_text(ui: &mut egui::Ui, finding: &Finding) -> Response {
+    let theme = Theme::get_selected();
+    let (icon, color) = match finding.severity {
+        Severity::Warning => (
+            egui_phosphor::regular::WARNING,
+            theme.warning.fg_high_contrast.0,
+        ),
+        Severity::Error => (
+            egui_phosphor::regular::X_CIRCLE,
+            theme.error.fg_high_contrast.0,
+        ),
+    };
+    icon_label(ui, icon, &finding.message, color)
+}
+
+pub fn verification_text(ui: &mut egui::Ui, verification: &Verification) -> Response {
+    let theme = Theme::get_selected();
+    match verification {
+        Verification::Verified { publisher } => icon_label(
+            ui,
+            egui_phosphor::regular::SHIELD_CHECK,
+            &t!("verification-published", publisher = publisher),
+            theme.highlight.fg_high_contrast.0,
+        ),
+        _ => icon_label(
+            ui,
+            egui_phosphor::regular::SHIELD_WARNING,
+            &format!(
+                "{} {}",
+                messages::verification_warning(verification).unwrap_or_default(),
+                t!("verification-untrusted")
+            ),
+            theme.warning.fg_high_contrast.0,
+        ),
+    }
+}
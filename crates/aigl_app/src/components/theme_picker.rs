@@ -0,0 +1,50 @@
+use eframe::egui::{self, WidgetType};
+
+use super::widget::accessible_name;
+use crate::i18n::t;
+use crate::theme::{ThemeChoice, ThemeLibrary};
+
+/// Select one of the themes in `library`.
+///
+/// Returns `true` if `choice` was changed.
+pub fn theme_picker(ui: &mut egui::Ui, library: &ThemeLibrary, choice: &mut ThemeChoice) -> bool {
+    let selected_text = match choice {
+        ThemeChoice::System => t!("theme-system"),
+        ThemeChoice::Named(name) => name.clone(),
+    };
+    let mut changed = false;
+    let response = egui::ComboBox::from_id_salt("theme_picker")
+        .selected_text(format!(
+            "{} {selected_text}",
+            egui_phosphor::regular::PALETTE
+        ))
+        .show_ui(ui, |ui| {
+            changed |= ui
+                .selectable_value(choice, ThemeChoice::System, t!("theme-system"))
+                .on_hover_text(t!("theme-system-hint"))
+                .changed();
+            for entry in library.entries() {
+                match &entry.theme {
+                    Ok(_) => {
+                        let response = ui.selectable_value(
+                            choice,
+                            ThemeChoice::Named(entry.name.clone()),
+                            &entry.name,
+                        );
+                        changed |= match &entry.path {
+                            Some(path) => response.on_hover_text(path.display().to_string()),
+                            None => response,
+                        }
+                        .changed();
+                    }
+                    Err(err) => {
+                        ui.add_enabled(false, egui::Button::selectable(false, &entry.name))
+                            .on_disabled_hover_text(err);
+                    }
+                }
+            }
+        })
+        .response;
+    accessible_name(response, WidgetType::ComboBox, &t!("theme")).on_hover_text(t!("theme"));
+    changed
+}
@@ -16,3 +16,26 @@ pub fn add_enabled_with_colors(
         ui.add_enabled(false, widget)
     }
 }
+
+/// Set the name that screen readers announce for `response`.
+///
+/// Use this for widgets whose visible text is only an icon or has no label next to it.
+pub fn accessible_name(
+    response: egui::Response,
+    typ: egui::WidgetType,
+    name: &str,
+) -> egui::Response {
+    response.widget_info(|| egui::WidgetInfo::labeled(typ, response.enabled(), name));
+    response
+}
+
+/// A colored label with `icon` in front of `text` whose accessible name is only `text`.
+pub fn icon_label(
+    ui: &mut egui::Ui,
+    icon: &str,
+    text: &str,
+    color: egui::Color32,
+) -> egui::Response {
+    let response = ui.colored_label(color, format!("{icon} {text}"));
+    accessible_name(response, egui::WidgetType::Label, text)
+}
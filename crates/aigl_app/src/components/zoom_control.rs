@@ -0,0 +1,77 @@
+use eframe::egui::{self, WidgetType};
+use egui::gui_zoom::kb_shortcuts;
+
+use super::widget::accessible_name;
+use crate::i18n::t;
+
+/// Zoom factor of the user interface until the player changes it.
+pub const DEFAULT_ZOOM_FACTOR: f32 = 1.5;
+const MIN_ZOOM_FACTOR: f32 = 0.5;
+const MAX_ZOOM_FACTOR: f32 = 3.0;
+/// Zooming in or out changes the zoom factor by one step, i.e., 10 %.
+const ZOOM_STEPS_PER_UNIT: f32 = 10.0;
+
+/// Buttons to zoom out, reset the zoom, and zoom in.
+///
+/// Returns the new zoom factor if one of them was clicked.
+pub fn zoom_control(ui: &mut egui::Ui, zoom_factor: f32) -> Option<f32> {
+    let mut buttons = [
+        (
+            egui_phosphor::regular::MINUS.to_owned(),
+            t!("zoom-out"),
+            kb_shortcuts::ZOOM_OUT,
+            step_zoom_factor(zoom_factor, -1),
+        ),
+        (
+            format!("{:.0} %", zoom_factor * 100.0),
+            t!("zoom-reset"),
+            kb_shortcuts::ZOOM_RESET,
+            DEFAULT_ZOOM_FACTOR,
+        ),
+        (
+            egui_phosphor::regular::PLUS.to_owned(),
+            t!("zoom-in"),
+            kb_shortcuts::ZOOM_IN,
+            step_zoom_factor(zoom_factor, 1),
+        ),
+    ];
+    let mut clicked = None;
+    ui.horizontal(|ui| {
+        // Keep zooming out on the left when placed in a right-to-left layout.
+        if ui.layout().prefer_right_to_left() {
+            buttons.reverse();
+        }
+        for (text, name, shortcut, new_zoom_factor) in buttons {
+            let hint = format!("{name} ({})", ui.ctx().format_shortcut(&shortcut));
+            let response = accessible_name(ui.small_button(text), WidgetType::Button, &name);
+            if response.on_hover_text(hint).clicked() {
+                clicked = Some(new_zoom_factor);
+            }
+        }
+    });
+    clicked
+}
+
+/// Zoom in or out by `steps` and keep the zoom factor in the supported range.
+///
+/// Rounds to whole steps so that zoom factors stay readable in the preferences.
+pub fn step_zoom_factor(zoom_factor: f32, steps: i32) -> f32 {
+    let current = (zoom_factor * ZOOM_STEPS_PER_UNIT).round();
+    ((current + steps as f32) / ZOOM_STEPS_PER_UNIT).clamp(MIN_ZOOM_FACTOR, MAX_ZOOM_FACTOR)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn zoom_factor_steps_are_rounded_and_clamped() {
+        assert_eq!(step_zoom_factor(DEFAULT_ZOOM_FACTOR, 1), 1.6);
+        assert_eq!(step_zoom_factor(DEFAULT_ZOOM_FACTOR, -2), 1.3);
+        assert_eq!(step_zoom_factor(1.234, 0), 1.2);
+        assert_eq!(step_zoom_factor(MIN_ZOOM_FACTOR, -1), MIN_ZOOM_FACTOR);
+        assert_eq!(step_zoom_factor(MAX_ZOOM_FACTOR, 1), MAX_ZOOM_FACTOR);
+        assert_eq!(step_zoom_factor(f32::MAX, 0), MAX_ZOOM_FACTOR);
+    }
+}
@@ -0,0 +1,36 @@
+use anyhow::Result;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+
+use crate::runtime::block_on;
+use aigl_project::{Finding, Fix, Project};
+
+#[derive(Debug, Default)]
+pub(crate) struct DoctorThreadData {
+    pub(crate) findings: Vec<Finding>,
+    pub(crate) error: Option<String>,
+}
+
+type Data = Arc<RwLock<DoctorThreadData>>;
+
+/// Check the project at `project_path` after applying `fix` if given.
+pub(crate) fn check(data: Data, project_path: PathBuf, fix: Option<Fix>) {
+    let result = block_on(async_check(project_path, fix));
+    if let Ok(mut data) = data.write() {
+        match result {
+            Ok(findings) => {
+                data.findings = findings;
+                data.error = None;
+            }
+            Err(err) => data.error = Some(format!("Failed to check the game: {err}")),
+        }
+    }
+}
+
+async fn async_check(project_path: PathBuf, fix: Option<Fix>) -> Result<Vec<Finding>> {
+    let mut project = Project::open(project_path).await?;
+    if let Some(fix) = fix {
+        project.fix(&fix).await?;
+    }
+    Ok(project.check().await)
+}
@@ -1,14 +1,151 @@
-use anyhow::{Result, bail};
+use anyhow::{Context, Result, bail};
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
 
+use crate::i18n::t;
+use crate::messages;
+use aigl_project::catalog::{CATALOG_FILE_NAME, Catalog, CatalogEntry};
 use aigl_project::config::game::GameConfig;
+use aigl_project::signing::{SIGNATURE_EXTENSION, TrustPolicy, Verification};
+use aigl_project::sources::{GameSource, GameSources};
 
-const BASE_URL: &str = "https://jl-wynen.github.io/aigl/resources/games";
+#[derive(Debug)]
+pub(crate) struct FetchedGame {
+    pub(crate) config: GameConfig,
+    /// The source that provided the config.
+    pub(crate) source: GameSource,
+    pub(crate) verification: Verification,
+}
 
-pub(crate) fn fetch_game_config(game_code: &str) -> Result<GameConfig> {
-    let url = format!("{BASE_URL}/{game_code}.toml");
-    let config_toml = reqwest::blocking::get(url)?.text()?;
-    if config_toml.starts_with("<!DOCTYPE html>") {
+/// Fetch a game config from the first source that has it and check its signature.
+///
+/// Fails if the config is not verified and `trust` requires signatures.
+pub(crate) fn fetch_game_config(
+    sources: &GameSources,
+    trust: &TrustPolicy,
+    game_code: &str,
+) -> Result<FetchedGame> {
+    let file_name = format!("{game_code}.toml");
+    let mut errors = Vec::new();
+    for source in &sources.0 {
+        match read_from_source(source, &file_name) {
+            Ok(Some(config_toml)) => {
+                let signature =
+                    read_from_source(source, &format!("{file_name}.{SIGNATURE_EXTENSION}"))?;
+                let verification = trust.verify(config_toml.as_bytes(), signature.as_deref())?;
+                if trust.require_signatures {
+                    if let Some(warning) = messages::verification_warning(&verification) {
+                        bail!("{warning} {}", t!("verification-refused"));
+                    }
+                }
+                let config = GameConfig::validate_toml_str(&config_toml)
+                    .with_context(|| format!("Invalid game config in {source}"))?;
+                return Ok(FetchedGame {
+                    config,
+                    source: source.clone(),
+                    verification,
+                });
+            }
+            Ok(None) => {}
+            Err(err) => errors.push(format!("{source}: {err}")),
+        }
+    }
+    if errors.is_empty() {
         bail!("Game '{game_code}' not found");
     }
-    GameConfig::load_toml_str(&config_toml)
+    bail!(
+        "Game '{game_code}' not found, some sources failed:\n{}",
+        errors.join("\n")
+    );
+}
+
+/// Read `file_name` from a source, returns `None` if the source does not have it.
+fn read_from_source(source: &GameSource, file_name: &str) -> Result<Option<String>> {
+    match source {
+        GameSource::Url(base_url) => {
+            let response = reqwest::blocking::get(format!("{base_url}/{file_name}"))?;
+            if response.status() == reqwest::StatusCode::NOT_FOUND {
+                return Ok(None);
+            }
+            let content = response.error_for_status()?.text()?;
+            // GitHub pages serves an HTML page for missing files.
+            if content.starts_with("<!DOCTYPE html>") {
+                return Ok(None);
+            }
+            Ok(Some(content))
+        }
+        GameSource::Dir(dir) => {
+            let path = dir.join(file_name);
+            if !path.exists() {
+                return Ok(None);
+            }
+            Ok(Some(std::fs::read_to_string(path)?))
+        }
+        GameSource::File(path) => {
+            // Also serves companion files like signatures, e.g., `game.toml.sig` for `game.toml`.
+            // `.aigl` files are served as `.toml` files with the same stem.
+            let Some(suffix) = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| file_name.strip_prefix(&format!("{stem}.toml")))
+            else {
+                return Ok(None);
+            };
+            let mut path = path.as_os_str().to_owned();
+            path.push(suffix);
+            let path = PathBuf::from(path);
+            if !suffix.is_empty() && !path.exists() {
+                return Ok(None);
+            }
+            Ok(Some(std::fs::read_to_string(path)?))
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct CatalogThreadData {
+    pub(crate) catalog: Option<Catalog>,
+    pub(crate) error: Option<String>,
+}
+
+/// Combine the catalogs of all sources, earlier sources take precedence.
+pub(crate) fn load_catalog(data: Arc<RwLock<CatalogThreadData>>, sources: GameSources) {
+    let mut catalog = Catalog::default();
+    let mut errors = Vec::new();
+    for source in &sources.0 {
+        match fetch_catalog(source) {
+            Ok(Some(source_catalog)) => catalog.merge(source_catalog),
+            Ok(None) => {}
+            Err(err) => errors.push(format!("{source}: {err}")),
+        }
+    }
+    if let Ok(mut data) = data.write() {
+        data.catalog = Some(catalog);
+        if !errors.is_empty() {
+            data.error = Some(format!(
+                "Failed to load the game list from some sources:\n{}",
+                errors.join("\n")
+            ));
+        }
+    }
+}
+
+fn fetch_catalog(source: &GameSource) -> Result<Option<Catalog>> {
+    match source {
+        GameSource::Dir(dir) if !dir.join(CATALOG_FILE_NAME).exists() => {
+            Ok(Some(Catalog::from_game_dir(dir)?))
+        }
+        GameSource::File(path) => {
+            let Some(code) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                return Ok(None);
+            };
+            let config = GameConfig::validate_toml_str(&std::fs::read_to_string(path)?)?;
+            Ok(Some(Catalog {
+                games: vec![CatalogEntry::from_game_config(code, config)],
+            }))
+        }
+        _ => read_from_source(source, CATALOG_FILE_NAME)?
+            .map(|content| Catalog::from_toml_str(&content))
+            .transpose(),
+    }
 }
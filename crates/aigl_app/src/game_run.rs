@@ -0,0 +1,242 @@
+use anyhow::Result;
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Read};
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use crate::ansi::{AnsiParser, AnsiSpan};
+use crate::runtime::block_on;
+use aigl_project::Project;
+use aigl_system::process::{kill_process_tree, spawn_in_own_group};
+
+/// Older lines are dropped to keep the memory usage bounded.
+const MAX_CONSOLE_LINES: usize = 10_000;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Stream {
+    Stdout,
+    Stderr,
+}
+
+/// A line of output of the game.
+#[derive(Clone, Debug)]
+pub(crate) struct ConsoleLine {
+    pub(crate) stream: Stream,
+    pub(crate) spans: Vec<AnsiSpan>,
+    /// The text without escape sequences, used for searching.
+    pub(crate) text: String,
+    /// Whether the line is part of a Python traceback.
+    pub(crate) traceback: bool,
+    /// The source location that a traceback line refers to.
+    pub(crate) location: Option<SourceLocation>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct SourceLocation {
+    pub(crate) file: PathBuf,
+    pub(crate) line: u32,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct GameRunThreadData {
+    pub(crate) lines: VecDeque<ConsoleLine>,
+    /// How the game exited, `None` while it is running.
+    pub(crate) exit_status: Option<String>,
+    pub(crate) error: Option<String>,
+}
+
+type Data = Arc<RwLock<GameRunThreadData>>;
+
+/// Run the game of the project at `project_path` and collect its output in `data`.
+///
+/// The game and the processes it has started are killed when `stop` is set.
+pub(crate) fn run_game(data: Data, project_path: PathBuf, stop: Arc<AtomicBool>) {
+    let result = run_and_wait(&data, project_path, &stop);
+    if let Ok(mut data) = data.write() {
+        match result {
+            Ok(status) => data.exit_status = Some(status),
+            Err(err) => {
+                data.exit_status = Some("The game did not start".into());
+                data.error = Some(format!("Failed to run the game: {err:#}"));
+            }
+        }
+    }
+}
+
+fn run_and_wait(data: &Data, project_path: PathBuf, stop: &AtomicBool) -> Result<String> {
+    let mut command = block_on(async { Project::open(project_path).await?.game_command() })?;
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        // Otherwise, Python buffers the output when it is not written to a terminal.
+        .env("PYTHONUNBUFFERED", "1");
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        // Release builds have no console, do not open a new one for the game.
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let mut child = spawn_in_own_group(&mut command)?;
+    let readers = [
+        spawn_reader(child.stdout.take(), Stream::Stdout, data.clone()),
+        spawn_reader(child.stderr.take(), Stream::Stderr, data.clone()),
+    ];
+    let (status, stopped) = loop {
+        if let Some(status) = child.try_wait()? {
+            break (status, false);
+        }
+        if stop.load(Ordering::Relaxed) {
+            // Fails if the game has exited in the meantime which is fine.
+            let _ = kill_process_tree(&mut child);
+            break (child.wait()?, true);
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    };
+    for reader in readers {
+        let _ = reader.join();
+    }
+
+    Ok(if stopped {
+        "Stopped the game".into()
+    } else if status.success() {
+        "The game has finished".into()
+    } else {
+        format!("The game has failed ({status})")
+    })
+}
+
+fn spawn_reader(
+    pipe: Option<impl Read + Send + 'static>,
+    stream: Stream,
+    data: Data,
+) -> std::thread::JoinHandle<()> {
+    std::thread::spawn(move || {
+        let Some(pipe) = pipe else {
+            return;
+        };
+        let mut parser = LineParser::default();
+        // Read raw bytes because the output is not necessarily valid UTF-8.
+        for line in BufReader::new(pipe).split(b'\n') {
+            let Ok(line) = line else {
+                break;
+            };
+            let line = String::from_utf8_lossy(&line);
+            let line = parser.parse(stream, line.trim_end_matches('\r'));
+            if let Ok(mut data) = data.write() {
+                if data.lines.len() >= MAX_CONSOLE_LINES {
+                    data.lines.pop_front();
+                }
+                data.lines.push_back(line);
+            }
+        }
+    })
+}
+
+/// Turns the lines of one output stream into [`ConsoleLine`]s.
+#[derive(Debug, Default)]
+struct LineParser {
+    ansi: AnsiParser,
+    in_traceback: bool,
+}
+
+impl LineParser {
+    fn parse(&mut self, stream: Stream, line: &str) -> ConsoleLine {
+        let spans = self.ansi.parse_line(line);
+        let text: String = spans.iter().map(|span| span.text.as_str()).collect();
+
+        let traceback = if text.starts_with("Traceback (most recent call last):") {
+            self.in_traceback = true;
+            true
+        } else if self.in_traceback {
+            // The traceback ends with the first unindented line, the exception message.
+            self.in_traceback = text.starts_with([' ', '\t']);
+            true
+        } else {
+            false
+        };
+        let location = traceback.then(|| parse_location(&text)).flatten();
+
+        ConsoleLine {
+            stream,
+            spans,
+            text,
+            traceback,
+            location,
+        }
+    }
+}
+
+/// Parse a traceback line like `  File "bot.py", line 3, in step`.
+fn parse_location(text: &str) -> Option<SourceLocation> {
+    let rest = text.trim_start().strip_prefix("File \"")?;
+    let (file, rest) = rest.split_once("\", line ")?;
+    let line = rest
+        .split(|c: char| !c.is_ascii_digit())
+        .next()?
+        .parse()
+        .ok()?;
+    Some(SourceLocation {
+        file: file.into(),
+        line,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const TRACEBACK: &str = r#"turn 1
+Traceback (most recent call last):
+  File "/game/bot_me/src/bot_me/bot.py", line 12, in step
+    return self.move()
+           ^^^^^^^^^^^
+ZeroDivisionError: division by zero
+turn 2"#;
+
+    #[test]
+    fn traceback_lines_are_marked() {
+        let mut parser = LineParser::default();
+        let lines: Vec<_> = TRACEBACK
+            .lines()
+            .map(|line| parser.parse(Stream::Stderr, line))
+            .collect();
+        assert_eq!(
+            lines.iter().map(|line| line.traceback).collect::<Vec<_>>(),
+            vec![false, true, true, true, true, true, false]
+        );
+        assert_eq!(
+            lines[2].location,
+            Some(SourceLocation {
+                file: "/game/bot_me/src/bot_me/bot.py".into(),
+                line: 12
+            })
+        );
+        assert!(lines.iter().filter(|line| line.location.is_some()).count() == 1);
+    }
+
+    #[test]
+    fn parse_location_handles_windows_paths() {
+        assert_eq!(
+            parse_location(r#"  File "C:\games\bot_me\bot.py", line 7, in <module>"#),
+            Some(SourceLocation {
+                file: r"C:\games\bot_me\bot.py".into(),
+                line: 7
+            })
+        );
+        assert_eq!(parse_location("File not found"), None);
+    }
+
+    #[test]
+    fn colored_output_is_searchable_as_plain_text() {
+        let line = LineParser::default().parse(Stream::Stdout, "\x1b[32mready\x1b[0m");
+        assert_eq!(line.text, "ready");
+        assert!(!line.traceback);
+    }
+}
@@ -0,0 +1,168 @@
+use anyhow::Result;
+use async_lock::Mutex;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use crate::i18n::{self, t};
+use crate::runtime::block_on;
+use aigl_project::config::game::GameConfig;
+use aigl_project::registry::ProjectRegistry;
+use aigl_project::user_file::UserFile;
+use aigl_project::{BotArg, Finding, Project};
+
+/// What the home screen shows about an installed project.
+#[derive(Debug)]
+pub(crate) struct ProjectSummary {
+    pub(crate) path: PathBuf,
+    pub(crate) game_name: String,
+    /// `None` if the project could not be opened.
+    pub(crate) game_config: Option<GameConfig>,
+    pub(crate) bot_ids: Vec<String>,
+    pub(crate) health: Result<Vec<Finding>, String>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct HomeThreadData {
+    pub(crate) projects: Vec<ProjectSummary>,
+    pub(crate) error: Option<String>,
+}
+
+/// Something the player can do with an installed project.
+#[derive(Clone, Debug)]
+pub(crate) enum ProjectAction {
+    AddBot {
+        bot_id: String,
+        bot_name: String,
+        args: Vec<BotArg>,
+    },
+    UpdateGame,
+    UpdateBotTemplate,
+    Uninstall,
+    /// Remove the project from the list without deleting it.
+    Forget,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct ProjectActionThreadData {
+    pub(crate) message: Option<String>,
+    pub(crate) error: Option<String>,
+}
+
+/// Open all registered projects and check their health.
+pub(crate) fn load_projects(data: Arc<RwLock<HomeThreadData>>) {
+    let result = ProjectRegistry::load().map(|registry| {
+        registry
+            .projects
+            .into_iter()
+            .map(|project| {
+                block_on(summarize(&project.path)).unwrap_or_else(|err| ProjectSummary {
+                    path: project.path,
+                    game_name: project.game_name,
+                    game_config: None,
+                    bot_ids: Vec::new(),
+                    health: Err(format!("{err:#}")),
+                })
+            })
+            .collect()
+    });
+    if let Ok(mut data) = data.write() {
+        match result {
+            Ok(projects) => {
+                data.projects = projects;
+                data.error = None;
+            }
+            Err(err) => data.error = Some(format!("Failed to load your games: {err:#}")),
+        }
+    }
+}
+
+async fn summarize(path: &Path) -> Result<ProjectSummary> {
+    let project = Project::open(path.to_path_buf()).await?;
+    Ok(ProjectSummary {
+        path: path.to_path_buf(),
+        game_name: project.cfg().game_config.name.clone(),
+        game_config: Some(project.cfg().game_config.clone()),
+        bot_ids: project.bot_ids(),
+        health: Ok(project.check().await),
+    })
+}
+
+/// Remember a project on the home screen, fails if `path` is not a project.
+pub(crate) fn register_project(path: PathBuf) -> Result<()> {
+    let project = block_on(Project::open(path))?;
+    let game_name = project.cfg().game_config.name.clone();
+    ProjectRegistry::update(|registry| registry.add(project.root().to_path_buf(), game_name))
+}
+
+/// Run `action` on the project at `path` and reload the project list afterwards.
+pub(crate) fn run_project_action(
+    data: Arc<RwLock<ProjectActionThreadData>>,
+    home_data: Arc<RwLock<HomeThreadData>>,
+    path: PathBuf,
+    action: ProjectAction,
+) {
+    let result = block_on(async_run_project_action(path, action));
+    if let Ok(mut data) = data.write() {
+        match result {
+            Ok(message) => {
+                data.message = Some(message);
+                data.error = None;
+            }
+            Err(err) => {
+                data.message = None;
+                data.error = Some(format!("{err:#}"));
+            }
+        }
+    }
+    load_projects(home_data);
+}
+
+async fn async_run_project_action(path: PathBuf, action: ProjectAction) -> Result<String> {
+    if let ProjectAction::Forget = action {
+        ProjectRegistry::update(|registry| registry.remove(&path))?;
+        return Ok(t!("home-removed", path = path.display()));
+    }
+
+    let project = Project::open(path.clone()).await?;
+    let game_name = project
+        .cfg()
+        .game_config
+        .localized_name(&i18n::content_languages())
+        .to_owned();
+    match action {
+        ProjectAction::AddBot {
+            bot_id,
+            bot_name,
+            args,
+        } => {
+            Project::add_bot(
+                Arc::new(Mutex::new(project)),
+                bot_id.clone(),
+                bot_name,
+                args,
+            )
+            .await?;
+            Ok(t!("home-added-bot", bot = bot_id, game = game_name))
+        }
+        ProjectAction::UpdateGame => Ok(if project.update_game().await? {
+            t!("home-updated-game", game = game_name)
+        } else {
+            t!("home-game-up-to-date", game = game_name)
+        }),
+        ProjectAction::UpdateBotTemplate => Ok(if project.update_bot_template().await? {
+            t!("home-updated-template")
+        } else {
+            t!("home-template-up-to-date")
+        }),
+        ProjectAction::Uninstall => {
+            project.uninstall().await?;
+            ProjectRegistry::update(|registry| registry.remove(&path))?;
+            Ok(t!(
+                "home-uninstalled",
+                game = game_name,
+                path = path.display()
+            ))
+        }
+        ProjectAction::Forget => unreachable!("handled above"),
+    }
+}
@@ -0,0 +1,435 @@
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use std::collections::HashMap;
+use std::sync::{LazyLock, RwLock};
+
+pub use fluent_bundle::FluentValue;
+
+/// The language that the player wants to use.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum LanguageChoice {
+    /// The first supported language in the preferences of the OS.
+    #[default]
+    System,
+    /// A language by its code in [`LANGUAGES`].
+    Language(&'static str),
+}
+
+impl LanguageChoice {
+    /// The choice of a language by its code, falls back to the system language for unknown codes.
+    pub fn from_code(code: Option<&str>) -> Self {
+        code.and_then(find_language)
+            .map_or(Self::System, |language| Self::Language(language.code))
+    }
+
+    /// The code of the chosen language, `None` for the system language.
+    pub fn code(&self) -> Option<&'static str> {
+        match self {
+            Self::System => None,
+            Self::Language(code) => Some(code),
+        }
+    }
+
+    /// The code of the chosen language.
+    pub fn resolve(&self) -> &'static str {
+        match self {
+            Self::System => system_language(),
+            Self::Language(code) => code,
+        }
+    }
+}
+
+/// A language that the user interface is translated to.
+#[derive(Debug)]
+pub struct Language {
+    /// BCP 47 code, e.g., "de" or "pt-BR".
+    pub code: &'static str,
+    /// Name of the language in the language itself.
+    pub name: &'static str,
+    source: &'static str,
+}
+
+/// The bundled message catalogs, the first one is the fallback for missing messages.
+pub const LANGUAGES: [Language; 2] = [
+    Language {
+        code: "en",
+        name: "English",
+        source: include_str!("../../../resources/locales/en.ftl"),
+    },
+    Language {
+        code: "de",
+        name: "Deutsch",
+        source: include_str!("../../../resources/locales/de.ftl"),
+    },
+];
+
+type Bundle = FluentBundle<FluentResource>;
+
+static BUNDLES: LazyLock<HashMap<&'static str, Bundle>> = LazyLock::new(|| {
+    LANGUAGES
+        .iter()
+        .map(|language| {
+            let bundle = language
+                .bundle()
+                .unwrap_or_else(|err| panic!("Invalid catalog for '{}': {err}", language.code));
+            (language.code, bundle)
+        })
+        .collect()
+});
+
+static CURRENT_LANGUAGE: RwLock<&str> = RwLock::new(LANGUAGES[0].code);
+
+impl Language {
+    /// Parse the messages of this language, see [`parse_bundle`].
+    fn bundle(&self) -> Result<Bundle, String> {
+        parse_bundle(self.code, self.source)
+    }
+}
+
+/// Parse a [Fluent](https://projectfluent.org) catalog.
+///
+/// Plural forms are selected with the rules of the language given by `code`.
+fn parse_bundle(code: &str, source: &str) -> Result<Bundle, String> {
+    let locale = code.parse().map_err(|err| format!("{err}"))?;
+    let resource =
+        FluentResource::try_new(source.to_owned()).map_err(|(_, errors)| format!("{errors:?}"))?;
+    let mut bundle = Bundle::new_concurrent(vec![locale]);
+    // egui does not support the Unicode isolation marks around arguments.
+    bundle.set_use_isolating(false);
+    bundle
+        .add_resource(resource)
+        .map_err(|errors| format!("{errors:?}"))?;
+    Ok(bundle)
+}
+
+/// Look up the message `id` in the current language and fill in `args`.
+///
+/// Falls back to English and then to the id itself if the message is missing.
+/// Use the [`t!`] macro instead of calling this directly.
+pub fn translate(id: &str, args: &[(&str, FluentValue<'_>)]) -> String {
+    [current_language(), LANGUAGES[0].code]
+        .into_iter()
+        .find_map(|code| format_message(&BUNDLES[code], id, args))
+        .unwrap_or_else(|| id.to_owned())
+}
+
+fn format_message(bundle: &Bundle, id: &str, args: &[(&str, FluentValue<'_>)]) -> Option<String> {
+    let pattern = bundle.get_message(id)?.value()?;
+    let args: FluentArgs = args
+        .iter()
+        .map(|(name, value)| (*name, value.clone()))
+        .collect();
+    // Missing arguments are shown as `{$name}` instead of hiding the problem.
+    let mut errors = Vec::new();
+    Some(
+        bundle
+            .format_pattern(pattern, Some(&args), &mut errors)
+            .into_owned(),
+    )
+}
+
+/// A value that can be passed to [`t!`].
+pub trait MessageArg {
+    fn to_fluent(&self) -> FluentValue<'static>;
+}
+
+impl<T: MessageArg + ?Sized> MessageArg for &T {
+    fn to_fluent(&self) -> FluentValue<'static> {
+        (**self).to_fluent()
+    }
+}
+
+macro_rules! impl_message_arg {
+    (text: $($text:ty),+; number: $($number:ty),+) => {
+        $(impl MessageArg for $text {
+            fn to_fluent(&self) -> FluentValue<'static> {
+                FluentValue::from(self.to_string())
+            }
+        })+
+        // Numbers stay numbers so that they select plural forms.
+        $(impl MessageArg for $number {
+            fn to_fluent(&self) -> FluentValue<'static> {
+                FluentValue::from(*self)
+            }
+        })+
+    };
+}
+
+impl_message_arg!(
+    text: str, String, std::path::Display<'_>;
+    number: usize, u32, u64, i32, i64, f32, f64
+);
+
+/// Translate a message, e.g., `t!("installing-into", path = location)`.
+macro_rules! t {
+    ($id:literal) => {
+        $crate::i18n::translate($id, &[])
+    };
+    ($id:literal, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::translate($id, &[$((stringify!($name), $crate::i18n::MessageArg::to_fluent(&$value))),+])
+    };
+}
+pub(crate) use t;
+
+/// The code of the language that messages are shown in.
+pub fn current_language() -> &'static str {
+    *CURRENT_LANGUAGE.read().unwrap()
+}
+
+/// Show messages in the language given by `tag` or English if it is not supported.
+pub fn set_language(tag: &str) {
+    let code = find_language(tag).map_or(LANGUAGES[0].code, |language| language.code);
+    *CURRENT_LANGUAGE.write().unwrap() = code;
+}
+
+/// The first of the operating system's preferred languages that is supported.
+pub fn system_language() -> &'static str {
+    sys_locale::get_locales()
+        .find_map(|tag| find_language(&tag))
+        .map_or(LANGUAGES[0].code, |language| language.code)
+}
+
+/// The operating system's preferred languages as BCP 47 tags, most preferred first.
+static SYSTEM_LOCALES: LazyLock<Vec<String>> = LazyLock::new(|| {
+    sys_locale::get_locales()
+        .map(|tag| normalize_tag(&tag))
+        .collect()
+});
+
+/// Languages to show texts of games in, most preferred first.
+///
+/// Games can be translated to languages that the launcher is not translated to,
+/// so these are the operating system's preferred languages followed by [`current_language`].
+pub fn content_languages() -> Vec<String> {
+    let mut languages = SYSTEM_LOCALES.clone();
+    languages.push(current_language().to_owned());
+    languages
+}
+
+/// Turn a tag like "de_AT.UTF-8" into a BCP 47 tag like "de-AT".
+fn normalize_tag(tag: &str) -> String {
+    tag.split(['.', '@'])
+        .next()
+        .unwrap_or_default()
+        .replace('_', "-")
+}
+
+/// Find a supported language for a tag like "de-AT" or "de_AT.UTF-8".
+///
+/// Falls back to the primary language, e.g., "de" for "de-AT".
+pub fn find_language(tag: &str) -> Option<&'static Language> {
+    let tag = normalize_tag(tag);
+    let primary = tag.split('-').next().unwrap_or_default();
+    LANGUAGES
+        .iter()
+        .find(|language| language.code.eq_ignore_ascii_case(&tag))
+        .or_else(|| {
+            LANGUAGES
+                .iter()
+                .find(|language| language.code.eq_ignore_ascii_case(primary))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fluent_syntax::ast;
+    use pretty_assertions::assert_eq;
+    use std::collections::BTreeSet;
+    use std::path::Path;
+
+    /// The ids and variables of all messages in a catalog.
+    fn messages(language: &Language) -> HashMap<String, BTreeSet<String>> {
+        let resource = FluentResource::try_new(language.source.to_owned()).unwrap();
+        resource
+            .entries()
+            .filter_map(|entry| match entry {
+                ast::Entry::Message(message) => {
+                    let mut variables = BTreeSet::new();
+                    if let Some(pattern) = &message.value {
+                        collect_variables(pattern, &mut variables);
+                    }
+                    Some((message.id.name.to_owned(), variables))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn collect_variables(pattern: &ast::Pattern<&str>, variables: &mut BTreeSet<String>) {
+        for element in &pattern.elements {
+            if let ast::PatternElement::Placeable { expression } = element {
+                collect_expression_variables(expression, variables);
+            }
+        }
+    }
+
+    fn collect_expression_variables(
+        expression: &ast::Expression<&str>,
+        variables: &mut BTreeSet<String>,
+    ) {
+        match expression {
+            ast::Expression::Select { selector, variants } => {
+                collect_inline_variables(selector, variables);
+                for variant in variants {
+                    collect_variables(&variant.value, variables);
+                }
+            }
+            ast::Expression::Inline(inline) => collect_inline_variables(inline, variables),
+        }
+    }
+
+    fn collect_inline_variables(
+        inline: &ast::InlineExpression<&str>,
+        variables: &mut BTreeSet<String>,
+    ) {
+        match inline {
+            ast::InlineExpression::VariableReference { id } => {
+                variables.insert(id.name.to_owned());
+            }
+            ast::InlineExpression::Placeable { expression } => {
+                collect_expression_variables(expression, variables)
+            }
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn catalogs_parse() {
+        for language in &LANGUAGES {
+            if let Err(err) = language.bundle() {
+                panic!("{}: {err}", language.code);
+            }
+        }
+    }
+
+    #[test]
+    fn translations_match_english_messages() {
+        let english = messages(&LANGUAGES[0]);
+        for language in &LANGUAGES[1..] {
+            let translated = messages(language);
+            let mut missing: Vec<_> = english
+                .keys()
+                .filter(|id| !translated.contains_key(*id))
+                .collect();
+            missing.sort();
+            assert_eq!(
+                missing,
+                Vec::<&String>::new(),
+                "missing in {}",
+                language.code
+            );
+            for (id, variables) in &translated {
+                let Some(english_variables) = english.get(id) else {
+                    panic!("unknown message '{id}' in {}", language.code);
+                };
+                assert_eq!(
+                    variables, english_variables,
+                    "variables of '{id}' in {}",
+                    language.code
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn all_used_messages_exist() {
+        fn visit(dir: &Path, english: &Bundle) {
+            for entry in std::fs::read_dir(dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    visit(&path, english);
+                    continue;
+                }
+                let source = std::fs::read_to_string(&path).unwrap();
+                // Split up so that this test does not find itself.
+                let pattern = concat!("t", "!(");
+                for (i, _) in source.match_indices(pattern) {
+                    // Skip other macros like `format!(`.
+                    if source[..i]
+                        .chars()
+                        .next_back()
+                        .is_some_and(|c| c.is_alphanumeric() || c == '_')
+                    {
+                        continue;
+                    }
+                    let Some(rest) = source[i + pattern.len()..].trim_start().strip_prefix('"')
+                    else {
+                        continue;
+                    };
+                    let id = &rest[..rest.find('"').unwrap()];
+                    assert!(
+                        english.has_message(id),
+                        "unknown message '{id}' in {}",
+                        path.display()
+                    );
+                }
+            }
+        }
+        visit(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+            &BUNDLES[LANGUAGES[0].code],
+        );
+    }
+
+    #[test]
+    fn messages_are_formatted_with_arguments() {
+        let bundle = parse_bundle(
+            "en",
+            "# A comment\n\
+             greeting = Hello { $name }!\n\
+             braces = { \"{\" }config{ \"}\" }\n\
+             long = First line\n    second line\n",
+        )
+        .unwrap();
+        let format = |id, args: &[(&str, FluentValue)]| format_message(&bundle, id, args);
+        assert_eq!(
+            format("greeting", &[("name", "Ada".into())]),
+            Some("Hello Ada!".into())
+        );
+        assert_eq!(format("greeting", &[]), Some("Hello {$name}!".into()));
+        assert_eq!(format("braces", &[]), Some("{config}".into()));
+        assert_eq!(format("long", &[]), Some("First line\nsecond line".into()));
+        assert_eq!(format("missing", &[]), None);
+    }
+
+    #[test]
+    fn plural_forms_follow_the_language() {
+        let count = |code: &str, n: usize| {
+            format_message(&BUNDLES[code], "lint-problems", &[("count", n.into())]).unwrap()
+        };
+        assert_eq!(count("en", 1), "1 problem found");
+        assert_eq!(count("en", 2), "2 problems found");
+        assert_eq!(count("de", 1), "1 Problem gefunden");
+        assert_eq!(count("de", 0), "0 Probleme gefunden");
+    }
+
+    #[test]
+    fn invalid_catalogs_are_rejected() {
+        assert!(parse_bundle("en", "no equals sign").is_err());
+        assert!(parse_bundle("en", "a = { $name").is_err());
+        assert!(parse_bundle("en", "a = x\na = y").is_err());
+        assert!(parse_bundle("not a language", "a = x").is_err());
+    }
+
+    #[test]
+    fn languages_are_found_by_tag() {
+        let code = |tag| find_language(tag).map(|language| language.code);
+        assert_eq!(code("de"), Some("de"));
+        assert_eq!(code("de-AT"), Some("de"));
+        assert_eq!(code("de_CH.UTF-8"), Some("de"));
+        assert_eq!(code("EN-us"), Some("en"));
+        assert_eq!(code("fr-FR"), None);
+    }
+
+    #[test]
+    fn saved_language_codes_are_restored() {
+        for choice in [LanguageChoice::System, LanguageChoice::Language("de")] {
+            assert_eq!(LanguageChoice::from_code(choice.code()), choice);
+        }
+        assert_eq!(
+            LanguageChoice::from_code(Some("xx")),
+            LanguageChoice::System
+        );
+    }
+}
@@ -1,19 +1,26 @@
 use anyhow::Result;
 use async_lock::Mutex;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
 
-use aigl_project::{BotArg, Project, config::game::GameConfig};
+use aigl_project::registry::ProjectRegistry;
+use aigl_project::user_file::UserFile;
+use aigl_project::{BotArg, ErrorReport, Project, config::game::GameConfig};
 
 #[derive(Debug, Default)]
 pub(crate) struct InstallThreadData {
     pub(crate) error: Option<String>,
+    /// Details of `error` if the installation itself failed.
+    pub(crate) report: Option<ErrorReport>,
+    /// Where `report` was written to.
+    pub(crate) log_path: Option<PathBuf>,
 }
 
 type Data = Arc<RwLock<InstallThreadData>>;
 
 pub(crate) fn install(
     data: Data,
+    launcher_version: &str,
     target_path: PathBuf,
     config: GameConfig,
     player_bot_id: String,
@@ -23,8 +30,8 @@ pub(crate) fn install(
     let runtime = start_tokio_runtime(&data)?;
 
     let result = runtime.block_on(Box::pin(async_install(
-        target_path,
-        config,
+        target_path.clone(),
+        config.clone(),
         player_bot_id,
         player_bot_name,
         player_bot_args,
@@ -37,11 +44,26 @@ pub(crate) fn install(
     runtime.shutdown_background();
 
     if let Err(err) = result {
-        show_error(&data, format!("Failed to install: {err}"));
+        let report = install_error_report(&err, launcher_version, &target_path, &config);
+        show_report(&data, report, &target_path);
     }
     Some(())
 }
 
+fn install_error_report(
+    err: &anyhow::Error,
+    launcher_version: &str,
+    target_path: &Path,
+    config: &GameConfig,
+) -> ErrorReport {
+    ErrorReport::new("Failed to install", launcher_version, err)
+        .with_context("Game", &config.name)
+        .with_context("Game repository", &config.game.url)
+        .with_context("Bot template repository", &config.bot.template_url)
+        .with_context("Python version", &config.python.version)
+        .with_context("Install location", target_path.display())
+}
+
 async fn async_install(
     target_path: PathBuf,
     config: GameConfig,
@@ -49,14 +71,22 @@ async fn async_install(
     player_bot_name: String,
     player_bot_args: Vec<BotArg>,
 ) -> Result<Arc<Mutex<Project>>> {
-    Project::init(
+    let game_name = config.name.clone();
+    let project = Project::init(
         target_path,
         config,
         player_bot_id,
         player_bot_name,
         player_bot_args,
     )
-    .await
+    .await?;
+
+    let root = project.lock().await.root().to_path_buf();
+    // The game is usable without the registry, it just won't show up on the home screen.
+    if let Err(err) = ProjectRegistry::update(|registry| registry.add(root, game_name)) {
+        eprintln!("Failed to remember the installed game: {err:#}");
+    }
+    Ok(project)
 }
 
 fn start_tokio_runtime(data: &Data) -> Option<tokio::runtime::Runtime> {
@@ -72,6 +102,18 @@ fn start_tokio_runtime(data: &Data) -> Option<tokio::runtime::Runtime> {
     }
 }
 
+/// Show the report and write it to a log file.
+fn show_report(data: &Data, report: ErrorReport, project_root: &Path) {
+    let log_path = report.write_log(Some(project_root));
+    if let Ok(mut data) = data.write() {
+        data.error = Some(format!("{}: {}", report.summary, report.step));
+        data.log_path = log_path.ok();
+        data.report = Some(report);
+    } else {
+        eprintln!("Failed to write to error data: {report}");
+    }
+}
+
 fn show_error(data: &Data, error: String) {
     if let Ok(mut data) = data.write() {
         data.error = Some(error);
@@ -1,7 +1,19 @@
+mod ansi;
 mod app;
+mod bot_tests;
 mod components;
+mod doctor;
 mod game_config;
+mod game_run;
+mod home;
+mod i18n;
 mod install;
+mod lint;
+mod match_setup;
+mod messages;
+mod plan;
+mod runtime;
 mod theme;
 
 pub use app::GameInstallApp;
+pub use theme::generate_theme_ron;
@@ -0,0 +1,60 @@
+use anyhow::Result;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+
+use crate::runtime::block_on;
+use aigl_project::{Diagnostic, Project};
+
+#[derive(Debug, Default)]
+pub(crate) struct LintThreadData {
+    pub(crate) diagnostics: Option<Vec<Diagnostic>>,
+    pub(crate) format_summary: Option<String>,
+    pub(crate) error: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) enum LintAction {
+    Check,
+    Fix(Option<Diagnostic>),
+    Format,
+}
+
+type Data = Arc<RwLock<LintThreadData>>;
+
+pub(crate) fn run_lint_action(
+    data: Data,
+    project_path: PathBuf,
+    bot_id: String,
+    action: LintAction,
+) {
+    let result = block_on(async_run_lint_action(project_path, bot_id, action));
+    if let Ok(mut data) = data.write() {
+        match result {
+            Ok((diagnostics, format_summary)) => {
+                data.diagnostics = Some(diagnostics);
+                data.format_summary = format_summary;
+                data.error = None;
+            }
+            Err(err) => data.error = Some(err.to_string()),
+        }
+    }
+}
+
+async fn async_run_lint_action(
+    project_path: PathBuf,
+    bot_id: String,
+    action: LintAction,
+) -> Result<(Vec<Diagnostic>, Option<String>)> {
+    let project = Project::open(project_path).await?;
+    match action {
+        LintAction::Check => Ok((project.check_bot_code(&bot_id).await?, None)),
+        LintAction::Fix(diagnostic) => Ok((
+            project.fix_bot_code(&bot_id, diagnostic.as_ref()).await?,
+            None,
+        )),
+        LintAction::Format => {
+            let summary = project.format_bot_code(&bot_id).await?;
+            Ok((project.check_bot_code(&bot_id).await?, Some(summary)))
+        }
+    }
+}
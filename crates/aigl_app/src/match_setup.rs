@@ -0,0 +1,60 @@
+use anyhow::Result;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+
+use crate::runtime::block_on;
+use aigl_project::config::game::Players;
+use aigl_project::{MatchPlayer, Project};
+
+/// The current match of a project and what can be changed about it.
+#[derive(Debug)]
+pub(crate) struct MatchSetup {
+    pub(crate) players: Vec<MatchPlayer>,
+    pub(crate) bot_ids: Vec<String>,
+    pub(crate) limits: Players,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct MatchSetupThreadData {
+    /// Taken by the GUI once it is loaded.
+    pub(crate) setup: Option<MatchSetup>,
+    pub(crate) saved: bool,
+    pub(crate) error: Option<String>,
+}
+
+type Data = Arc<RwLock<MatchSetupThreadData>>;
+
+pub(crate) fn load_match(data: Data, project_path: PathBuf) {
+    let result = block_on(async_load_match(project_path));
+    if let Ok(mut data) = data.write() {
+        match result {
+            Ok(setup) => {
+                data.setup = Some(setup);
+                data.error = None;
+            }
+            Err(err) => data.error = Some(format!("Failed to load the match: {err:#}")),
+        }
+    }
+}
+
+async fn async_load_match(project_path: PathBuf) -> Result<MatchSetup> {
+    let project = Project::open(project_path).await?;
+    Ok(MatchSetup {
+        players: project.load_match().await?,
+        bot_ids: project.bot_ids(),
+        limits: project.cfg().game_config.players.clone(),
+    })
+}
+
+pub(crate) fn save_match(data: Data, project_path: PathBuf, players: Vec<MatchPlayer>) {
+    let result = block_on(async {
+        let project = Project::open(project_path).await?;
+        project.save_match(&players).await
+    });
+    if let Ok(mut data) = data.write() {
+        data.saved = result.is_ok();
+        data.error = result
+            .err()
+            .map(|err| format!("Failed to save the match: {err:#}"));
+    }
+}
@@ -0,0 +1,123 @@
+//! Translations of the problems that `aigl_project` reports as values.
+
+use crate::i18n::t;
+use aigl_project::signing::Verification;
+use aigl_project::{BotArgError, BotIdError, Fix, PlanWarning, format_size};
+
+/// Explain why a game is not verified, `None` if it is.
+pub fn verification_warning(verification: &Verification) -> Option<String> {
+    match verification {
+        Verification::Verified { .. } => None,
+        Verification::Unsigned => Some(t!("verification-unsigned")),
+        Verification::UnknownSigner => Some(t!("verification-unknown-signer")),
+    }
+}
+
+pub fn bot_id_error(error: &BotIdError) -> String {
+    match error {
+        BotIdError::Empty => t!("bot-id-empty"),
+        BotIdError::InvalidStart => t!("bot-id-invalid-start"),
+        BotIdError::InvalidCharacter(c) => {
+            t!("bot-id-invalid-character", character = c.to_string())
+        }
+        BotIdError::PythonKeyword(id) => t!("bot-id-python-keyword", id = id),
+        BotIdError::StdlibModule(id) => t!("bot-id-stdlib-module", id = id),
+        BotIdError::Reserved(id) => t!("bot-id-reserved", id = id),
+    }
+}
+
+pub fn bot_arg_error(error: &BotArgError) -> String {
+    match error {
+        BotArgError::Required(arg) => t!("bot-arg-required", arg = arg),
+        BotArgError::TooSmall(min) => t!("bot-arg-too-small", min = min),
+        BotArgError::TooLarge(max) => t!("bot-arg-too-large", max = max),
+        BotArgError::NotAnOption(options) => {
+            t!("bot-arg-not-an-option", options = options.join(", "))
+        }
+    }
+}
+
+pub fn fix_description(fix: &Fix) -> String {
+    match fix {
+        Fix::RecreateVenv => t!("fix-recreate-venv"),
+        Fix::ReinstallPackages => t!("fix-reinstall-packages"),
+        Fix::RelocatePaths => t!("fix-relocate-paths"),
+        Fix::RemoveUnknownPlayers(packages) => {
+            t!("fix-remove-unknown-players", packages = packages.join(", "))
+        }
+    }
+}
+
+pub fn plan_warning(warning: &PlanWarning) -> String {
+    match warning {
+        PlanWarning::Unreachable { url, error } => {
+            t!("plan-warning-unreachable", url = url, error = error)
+        }
+        PlanWarning::NetworkDrive(path) => t!("plan-warning-network-drive", path = path.display()),
+        PlanWarning::LowDiskSpace { available, needed } => t!(
+            "plan-warning-low-space",
+            available = format_size(*available),
+            needed = format_size(*needed)
+        ),
+        PlanWarning::UnknownDiskSpace(error) => t!("plan-warning-unknown-space", error = error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::path::PathBuf;
+
+    // The tests run in English, the language of the messages of `aigl_project`.
+
+    #[test]
+    fn english_messages_match_the_project_messages() {
+        for error in [
+            BotIdError::Empty,
+            BotIdError::InvalidStart,
+            BotIdError::InvalidCharacter('-'),
+            BotIdError::PythonKeyword("lambda".into()),
+            BotIdError::StdlibModule("random".into()),
+            BotIdError::Reserved("template_bot".into()),
+        ] {
+            assert_eq!(bot_id_error(&error), error.to_string());
+        }
+        for error in [
+            BotArgError::Required("Color".into()),
+            BotArgError::TooSmall("0".into()),
+            BotArgError::TooLarge("1.5".into()),
+            BotArgError::NotAnOption(vec!["easy".into(), "hard".into()]),
+        ] {
+            assert_eq!(bot_arg_error(&error), error.to_string());
+        }
+        for fix in [
+            Fix::RecreateVenv,
+            Fix::ReinstallPackages,
+            Fix::RelocatePaths,
+            Fix::RemoveUnknownPlayers(vec!["bot_a".into(), "bot_b".into()]),
+        ] {
+            assert_eq!(fix_description(&fix), fix.description());
+        }
+        for warning in [
+            PlanWarning::Unreachable {
+                url: "https://example.com/game.git".into(),
+                error: "timeout".into(),
+            },
+            PlanWarning::NetworkDrive(PathBuf::from("/mnt/share")),
+            PlanWarning::LowDiskSpace {
+                available: 1024,
+                needed: 2048,
+            },
+            PlanWarning::UnknownDiskSpace("no such device".into()),
+        ] {
+            assert_eq!(plan_warning(&warning), warning.to_string());
+        }
+        for verification in [Verification::Unsigned, Verification::UnknownSigner] {
+            assert_eq!(
+                verification_warning(&verification).as_deref(),
+                verification.warning()
+            );
+        }
+    }
+}
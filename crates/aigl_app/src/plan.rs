@@ -0,0 +1,42 @@
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+
+use aigl_project::config::game::GameConfig;
+use aigl_project::{BotArg, InstallPlan, Project};
+
+#[derive(Debug, Default)]
+pub(crate) struct PlanThreadData {
+    pub(crate) plan: Option<InstallPlan>,
+    pub(crate) error: Option<String>,
+}
+
+type Data = Arc<RwLock<PlanThreadData>>;
+
+pub(crate) fn plan_install(
+    data: Data,
+    target_path: PathBuf,
+    config: GameConfig,
+    player_bot_id: String,
+    player_bot_name: String,
+    player_bot_args: Vec<BotArg>,
+) {
+    let result = Project::plan(
+        &target_path,
+        &config,
+        &player_bot_id,
+        &player_bot_name,
+        &player_bot_args,
+    );
+    if let Ok(mut data) = data.write() {
+        match result {
+            Ok(plan) => {
+                data.plan = Some(plan);
+                data.error = None;
+            }
+            Err(err) => {
+                data.plan = None;
+                data.error = Some(format!("Cannot install the game: {err:#}"));
+            }
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use anyhow::{Context, Result};
+
+/// Run a future to completion on a new single-threaded tokio runtime.
+pub(crate) fn block_on<T>(future: impl Future<Output = Result<T>>) -> Result<T> {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .context("Failed to create tokio runtime")?;
+    let result = runtime.block_on(Box::pin(future));
+    // Avoid waiting for pending tasks to complete, see `install::install`.
+    runtime.shutdown_background();
+    result
+}
@@ -1,8 +1,6 @@
-use eframe::egui::{Color32, ecolor::ParseHexColorError};
-#[cfg(feature = "load-theme")]
+use eframe::egui::{Color32, Rgba, ecolor::ParseHexColorError};
 use std::fmt::Formatter;
 
-#[cfg(feature = "load-theme")]
 use serde::{
     Deserialize, Deserializer,
     de::{self, Visitor},
@@ -13,7 +11,6 @@ use serde::{
 pub struct Color(pub Color32);
 
 impl Color {
-    #[allow(dead_code)]
     pub fn from_hex(hex: &str) -> Result<Self, ParseHexColorError> {
         Color32::from_hex(hex).map(Self)
     }
@@ -22,11 +19,22 @@ impl Color {
     pub const fn from_rgb(r: u8, g: u8, b: u8) -> Self {
         Self(Color32::from_rgb(r, g, b))
     }
+
+    /// The [WCAG contrast ratio](https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio) between two
+    /// opaque colors, from 1 for equal colors to 21 for black on white.
+    pub fn contrast_ratio(self, other: Self) -> f32 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    fn relative_luminance(self) -> f32 {
+        let linear = Rgba::from(self.0);
+        0.2126 * linear.r() + 0.7152 * linear.g() + 0.0722 * linear.b()
+    }
 }
 
 // Custom deserializer for Color / Color32 because the implementation in
 // egui cannot parse hex strings directly.
-#[cfg(feature = "load-theme")]
 impl<'de> Deserialize<'de> for Color {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -36,10 +44,8 @@ impl<'de> Deserialize<'de> for Color {
     }
 }
 
-#[cfg(feature = "load-theme")]
 struct ColorVisitor;
 
-#[cfg(feature = "load-theme")]
 impl Visitor<'_> for ColorVisitor {
     type Value = Color;
 
@@ -55,13 +61,11 @@ impl Visitor<'_> for ColorVisitor {
     }
 }
 
-#[cfg(feature = "load-theme")]
 #[cfg(test)]
-mod load_tests {
+mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
 
-    #[cfg(feature = "load-theme")]
     #[test]
     fn can_deserialize_color_from_ron() {
         let color_str = "\"#ff0000\"";
@@ -76,7 +80,22 @@ mod load_tests {
         );
     }
 
-    #[cfg(feature = "load-theme")]
+    #[test]
+    fn contrast_ratio_follows_wcag() {
+        let black = Color::from_rgb(0, 0, 0);
+        let white = Color::from_rgb(255, 255, 255);
+        let gray = Color::from_rgb(118, 118, 118);
+        for (a, b, expected) in [
+            (black, white, 21.0),
+            (white, black, 21.0),
+            (white, white, 1.0),
+            (gray, white, 4.54),
+        ] {
+            let ratio = a.contrast_ratio(b);
+            assert!((ratio - expected).abs() < 0.01, "{ratio} != {expected}");
+        }
+    }
+
     #[test]
     fn deserialize_color_requires_hash() {
         let color_str = "\"00ff00\"";
@@ -0,0 +1,328 @@
+use anyhow::{Result, anyhow};
+use eframe::egui::Color32;
+use std::fmt::Write;
+
+use super::{Color, Scale};
+
+/// Seeds for the warning and error scales of generated themes, Radix amber and red.
+const WARNING_SEED: Color = Color::from_rgb(255, 197, 61);
+const ERROR_SEED: Color = Color::from_rgb(229, 72, 77);
+
+/// What a generated scale is used for.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ScaleKind {
+    /// A colorful scale, the seed is used as is for `bg_solid`.
+    Accent,
+    /// A scale of tinted grays for the base colors.
+    Gray,
+}
+
+/// OKLCH lightness and chroma of the twelve steps of a scale, in the order of the Radix steps.
+///
+/// Chroma is relative to the seed's chroma.
+/// The values are taken from the Radix indigo and slate scales.
+struct Steps {
+    lightness: [f32; 12],
+    chroma: [f32; 12],
+}
+
+const ACCENT_DARK: Steps = Steps {
+    lightness: [
+        0.19, 0.21, 0.26, 0.30, 0.35, 0.39, 0.45, 0.52, 0.0, 0.0, 0.78, 0.91,
+    ],
+    chroma: [
+        0.10, 0.14, 0.35, 0.50, 0.55, 0.58, 0.63, 0.74, 1.0, 0.92, 0.63, 0.25,
+    ],
+};
+const ACCENT_LIGHT: Steps = Steps {
+    lightness: [
+        0.993, 0.982, 0.958, 0.93, 0.897, 0.86, 0.807, 0.74, 0.0, 0.0, 0.53, 0.33,
+    ],
+    chroma: [
+        0.02, 0.04, 0.10, 0.19, 0.27, 0.37, 0.46, 0.59, 1.0, 1.0, 0.95, 0.5,
+    ],
+};
+const GRAY_DARK: Steps = Steps {
+    lightness: [
+        0.179, 0.213, 0.252, 0.283, 0.312, 0.347, 0.399, 0.489, 0.537, 0.583, 0.769, 0.949,
+    ],
+    chroma: [
+        0.27, 0.28, 0.38, 0.46, 0.54, 0.67, 0.79, 1.0, 1.0, 0.95, 0.63, 0.19,
+    ],
+};
+const GRAY_LIGHT: Steps = Steps {
+    lightness: [
+        0.991, 0.983, 0.956, 0.932, 0.91, 0.887, 0.853, 0.794, 0.645, 0.611, 0.502, 0.241,
+    ],
+    chroma: [
+        0.08, 0.16, 0.24, 0.33, 0.42, 0.58, 0.67, 0.95, 1.0, 0.94, 0.83, 0.59,
+    ],
+};
+/// Gray seeds with more chroma would produce colorful backgrounds.
+const MAX_GRAY_CHROMA: f32 = 0.04;
+
+/// Derive all colors of a scale from a single seed color.
+pub fn generate_scale(seed: Color, kind: ScaleKind, dark_mode: bool) -> Scale {
+    let seed_lch = Oklch::from_color(seed);
+    let (steps, chroma) = match (kind, dark_mode) {
+        (ScaleKind::Accent, true) => (&ACCENT_DARK, seed_lch.c),
+        (ScaleKind::Accent, false) => (&ACCENT_LIGHT, seed_lch.c),
+        (ScaleKind::Gray, true) => (&GRAY_DARK, seed_lch.c.min(MAX_GRAY_CHROMA)),
+        (ScaleKind::Gray, false) => (&GRAY_LIGHT, seed_lch.c.min(MAX_GRAY_CHROMA)),
+    };
+    let step = |i: usize| -> Color {
+        let lightness = match (kind, i) {
+            (ScaleKind::Accent, 8) => return seed,
+            // The hovered solid background is slightly more prominent than the seed.
+            (ScaleKind::Accent, 9) if dark_mode => seed_lch.l + 0.045,
+            (ScaleKind::Accent, 9) => seed_lch.l - 0.03,
+            _ => steps.lightness[i],
+        };
+        Oklch {
+            l: lightness.clamp(0.0, 1.0),
+            c: chroma * steps.chroma[i],
+            h: seed_lch.h,
+        }
+        .to_color()
+    };
+    Scale {
+        bg: step(0),
+        bg_subtle: step(1),
+        bg_element: step(2),
+        bg_element_hovered: step(3),
+        bg_element_active: step(4),
+        border: step(5),
+        border_element: step(6),
+        border_element_hovered: step(7),
+        bg_solid: step(8),
+        bg_solid_hovered: step(9),
+        fg_low_contrast: step(10),
+        fg_high_contrast: step(11),
+    }
+}
+
+/// Generate a complete theme in RON format from an accent and a gray color like `#e5484d`.
+pub fn generate_theme_ron(accent: &str, gray: &str, dark_mode: bool) -> Result<String> {
+    let parse =
+        |hex: &str| Color::from_hex(hex).map_err(|err| anyhow!("Invalid color '{hex}': {err:?}"));
+    let scales = [
+        (
+            "base",
+            generate_scale(parse(gray)?, ScaleKind::Gray, dark_mode),
+        ),
+        (
+            "highlight",
+            generate_scale(parse(accent)?, ScaleKind::Accent, dark_mode),
+        ),
+        (
+            "warning",
+            generate_scale(WARNING_SEED, ScaleKind::Accent, dark_mode),
+        ),
+        (
+            "error",
+            generate_scale(ERROR_SEED, ScaleKind::Accent, dark_mode),
+        ),
+    ];
+
+    let mut ron = format!("Theme(\n    dark_mode: {dark_mode},\n");
+    for (name, scale) in scales {
+        writeln!(ron, "    {name}: Scale(")?;
+        for (field, color) in scale.fields() {
+            writeln!(ron, "        {field}: \"{}\",", hex(color))?;
+        }
+        writeln!(ron, "    ),")?;
+    }
+    ron.push_str(
+        "    stroke_width: 1.0,\n    element_border_width: 1.0,\n    corner_radius: 1,\n)\n",
+    );
+    Ok(ron)
+}
+
+fn hex(color: Color) -> String {
+    let [r, g, b, _] = color.0.to_array();
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+impl Scale {
+    /// The colors with their names in the order of theme files.
+    fn fields(&self) -> [(&'static str, Color); 12] {
+        [
+            ("bg", self.bg),
+            ("bg_subtle", self.bg_subtle),
+            ("bg_element", self.bg_element),
+            ("bg_element_hovered", self.bg_element_hovered),
+            ("bg_element_active", self.bg_element_active),
+            ("bg_solid", self.bg_solid),
+            ("bg_solid_hovered", self.bg_solid_hovered),
+            ("border", self.border),
+            ("border_element", self.border_element),
+            ("border_element_hovered", self.border_element_hovered),
+            ("fg_low_contrast", self.fg_low_contrast),
+            ("fg_high_contrast", self.fg_high_contrast),
+        ]
+    }
+}
+
+/// A color in the perceptual OKLCH color space.
+#[derive(Clone, Copy, Debug)]
+struct Oklch {
+    l: f32,
+    c: f32,
+    /// Hue in radians.
+    h: f32,
+}
+
+impl Oklch {
+    fn from_color(color: Color) -> Self {
+        let [r, g, b, _] = color.0.to_array();
+        let [r, g, b] = [r, g, b].map(|c| srgb_to_linear(f32::from(c) / 255.0));
+        let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+        let lightness = 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s;
+        let a = 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s;
+        let b = 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s;
+        Self {
+            l: lightness,
+            c: a.hypot(b),
+            h: b.atan2(a),
+        }
+    }
+
+    /// The closest sRGB color with the same lightness and hue.
+    fn to_color(self) -> Color {
+        let rgb = match self.linear_rgb() {
+            Some(rgb) => rgb,
+            None => {
+                // Reduce the chroma until the color fits into sRGB.
+                let (mut low, mut high) = (0.0, self.c);
+                for _ in 0..20 {
+                    let mid = (low + high) / 2.0;
+                    let candidate = Self { c: mid, ..self };
+                    if candidate.linear_rgb().is_some() {
+                        low = mid;
+                    } else {
+                        high = mid;
+                    }
+                }
+                Self { c: low, ..self }
+                    .linear_rgb()
+                    // Grays are always in the gamut, this is only a safeguard.
+                    .unwrap_or([self.l.powi(3); 3])
+            }
+        };
+        let [r, g, b] = rgb.map(|c| (linear_to_srgb(c.clamp(0.0, 1.0)) * 255.0).round() as u8);
+        Color(Color32::from_rgb(r, g, b))
+    }
+
+    /// Linear sRGB components or `None` if the color is outside of the sRGB gamut.
+    fn linear_rgb(self) -> Option<[f32; 3]> {
+        let (a, b) = (self.c * self.h.cos(), self.c * self.h.sin());
+        let l = (self.l + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+        let m = (self.l - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+        let s = (self.l - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+        let rgb = [
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        ];
+        const EPSILON: f32 = 1e-4;
+        rgb.iter()
+            .all(|c| (-EPSILON..=1.0 + EPSILON).contains(c))
+            .then_some(rgb)
+    }
+}
+
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::Theme;
+    use pretty_assertions::assert_eq;
+
+    fn lightness(color: Color) -> f32 {
+        Oklch::from_color(color).l
+    }
+
+    #[test]
+    fn oklch_round_trips() {
+        for hex in ["#e5484d", "#3e63dd", "#8b8d98", "#000000", "#ffffff"] {
+            let color = Color::from_hex(hex).unwrap();
+            assert_eq!(Oklch::from_color(color).to_color(), color);
+        }
+    }
+
+    #[test]
+    fn accent_scale_keeps_seed_as_solid_background() {
+        let seed = Color::from_hex("#e5484d").unwrap();
+        for dark_mode in [true, false] {
+            let scale = generate_scale(seed, ScaleKind::Accent, dark_mode);
+            assert_eq!(scale.bg_solid, seed);
+        }
+    }
+
+    #[test]
+    fn steps_are_ordered_by_lightness() {
+        let seed = Color::from_hex("#3e63dd").unwrap();
+        let dark = generate_scale(seed, ScaleKind::Accent, true);
+        assert!(lightness(dark.bg) < lightness(dark.bg_element));
+        assert!(lightness(dark.bg_element) < lightness(dark.border_element));
+        assert!(lightness(dark.fg_low_contrast) < lightness(dark.fg_high_contrast));
+        let light = generate_scale(seed, ScaleKind::Accent, false);
+        assert!(lightness(light.bg) > lightness(light.bg_element));
+        assert!(lightness(light.fg_low_contrast) > lightness(light.fg_high_contrast));
+    }
+
+    #[test]
+    fn gray_scale_has_little_chroma() {
+        let scale = generate_scale(Color::from_hex("#ff0000").unwrap(), ScaleKind::Gray, true);
+        for (_, color) in scale.fields() {
+            assert!(Oklch::from_color(color).c <= MAX_GRAY_CHROMA + 0.01);
+        }
+    }
+
+    #[test]
+    fn generated_theme_can_be_loaded() {
+        let ron = generate_theme_ron("#e5484d", "#8b8d98", false).unwrap();
+        let theme = Theme::from_ron(&ron).unwrap();
+        assert!(!theme.dark_mode);
+        assert_eq!(hex(theme.highlight.bg_solid), "#e5484d");
+    }
+
+    #[test]
+    fn theme_files_can_use_seeds() {
+        let ron = r##"Theme(
+    dark_mode: true,
+    base: Gray("#8b8d98"),
+    highlight: Accent("#e5484d"),
+    warning: Accent("#ffc53d"),
+    error: Accent("#e5484d"),
+    stroke_width: 1.0,
+    element_border_width: 1.0,
+    corner_radius: 1,
+)"##;
+        let theme = Theme::from_ron(ron).unwrap();
+        assert_eq!(hex(theme.highlight.bg_solid), "#e5484d");
+        assert!(lightness(theme.base.bg) < 0.2);
+    }
+
+    #[test]
+    fn generate_theme_ron_rejects_invalid_colors() {
+        assert!(generate_theme_ron("red", "#8b8d98", true).is_err());
+    }
+}
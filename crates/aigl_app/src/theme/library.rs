@@ -0,0 +1,198 @@
+use eframe::egui;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use super::{BUNDLED_THEMES, Theme};
+
+/// The theme that the player wants to use.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum ThemeChoice {
+    /// The bundled dark or light theme depending on the preference of the OS.
+    #[default]
+    System,
+    /// A theme by its name in the [`ThemeLibrary`].
+    Named(String),
+}
+
+#[derive(Debug)]
+pub struct ThemeEntry {
+    pub name: String,
+    /// The file of a user theme, `None` for bundled themes.
+    pub path: Option<PathBuf>,
+    /// An error message if the theme cannot be used.
+    pub theme: Result<Arc<Theme>, String>,
+}
+
+/// All themes that can be selected.
+#[derive(Debug)]
+pub struct ThemeLibrary {
+    entries: Vec<ThemeEntry>,
+}
+
+impl ThemeLibrary {
+    /// Load the bundled themes and the `.ron` files in the user's theme directory.
+    pub fn load() -> Self {
+        let mut library = Self::bundled();
+        if let Some(dir) = aigl_project::config::user_themes_dir() {
+            library.add_user_themes(&dir);
+        }
+        library
+    }
+
+    fn bundled() -> Self {
+        let entries = BUNDLED_THEMES
+            .iter()
+            .map(|(name, ron)| ThemeEntry {
+                name: (*name).to_owned(),
+                path: None,
+                theme: Theme::from_ron(ron)
+                    .map(Arc::new)
+                    .map_err(|err| format!("{err:#}")),
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// Add all themes in `dir`, named after their files.
+    ///
+    /// Themes that cannot be read are added with an error so that players can see what is wrong.
+    fn add_user_themes(&mut self, dir: &Path) {
+        let Ok(read_dir) = std::fs::read_dir(dir) else {
+            return;
+        };
+        let mut paths: Vec<_> = read_dir
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "ron"))
+            .collect();
+        paths.sort();
+        for path in paths {
+            let Some(name) = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+            else {
+                continue;
+            };
+            let theme = if self.entries.iter().any(|entry| entry.name == name) {
+                Err(format!("Another theme is already called '{name}'"))
+            } else {
+                std::fs::read_to_string(&path)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|ron| Theme::from_ron(&ron))
+                    .map(Arc::new)
+                    .map_err(|err| format!("Invalid theme {}: {err:#}", path.display()))
+            };
+            self.entries.push(ThemeEntry {
+                name,
+                path: Some(path),
+                theme,
+            });
+        }
+    }
+
+    pub fn entries(&self) -> &[ThemeEntry] {
+        &self.entries
+    }
+
+    /// The theme for `choice` given the OS preference `system`.
+    ///
+    /// Falls back to the default theme if the chosen one is not available.
+    pub fn resolve(&self, choice: &ThemeChoice, system: Option<egui::Theme>) -> Arc<Theme> {
+        let name = match choice {
+            ThemeChoice::System => match system {
+                Some(egui::Theme::Light) => "Light",
+                _ => "Dark",
+            },
+            ThemeChoice::Named(name) => name,
+        };
+        self.get(name)
+            .or_else(|| self.get(BUNDLED_THEMES[0].0))
+            .unwrap_or_else(Theme::get_selected)
+    }
+
+    fn get(&self, name: &str) -> Option<Arc<Theme>> {
+        self.entries
+            .iter()
+            .find(|entry| entry.name == name)
+            .and_then(|entry| entry.theme.clone().ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::Color;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn bundled_themes_are_valid() {
+        let library = ThemeLibrary::bundled();
+        for entry in library.entries() {
+            assert!(entry.theme.is_ok(), "{}: {:?}", entry.name, entry.theme);
+        }
+    }
+
+    #[test]
+    fn focus_outlines_are_visible_in_bundled_themes() {
+        for entry in ThemeLibrary::bundled().entries() {
+            let theme = entry.theme.as_ref().unwrap();
+            let focus = Color(theme.focus_stroke.color);
+            assert!(
+                focus.contrast_ratio(theme.base.bg) >= 3.0,
+                "{}: focus outline contrast is too low",
+                entry.name
+            );
+            assert!(theme.focus_stroke.width >= 2.0, "{}", entry.name);
+            assert_eq!(
+                theme.base_widget_visuals.active.bg_stroke,
+                theme.focus_stroke
+            );
+        }
+    }
+
+    #[test]
+    fn system_choice_follows_os_preference() {
+        let library = ThemeLibrary::bundled();
+        assert!(
+            !library
+                .resolve(&ThemeChoice::System, Some(egui::Theme::Light))
+                .dark_mode
+        );
+        assert!(
+            library
+                .resolve(&ThemeChoice::System, Some(egui::Theme::Dark))
+                .dark_mode
+        );
+        assert!(library.resolve(&ThemeChoice::System, None).dark_mode);
+    }
+
+    #[test]
+    fn user_themes_are_loaded_with_errors() {
+        let dir = std::env::temp_dir().join(format!("aigl-test-themes-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let (_, light) = BUNDLED_THEMES[1];
+        std::fs::write(dir.join("mine.ron"), light).unwrap();
+        std::fs::write(dir.join("broken.ron"), "Theme(").unwrap();
+        std::fs::write(dir.join("notes.txt"), "not a theme").unwrap();
+
+        let mut library = ThemeLibrary::bundled();
+        library.add_user_themes(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let user: Vec<_> = library.entries()[BUNDLED_THEMES.len()..]
+            .iter()
+            .map(|entry| (entry.name.as_str(), entry.theme.is_ok()))
+            .collect();
+        assert_eq!(user, vec![("broken", false), ("mine", true)]);
+        assert!(
+            !library
+                .resolve(&ThemeChoice::Named("mine".into()), None)
+                .dark_mode
+        );
+        // Unusable themes fall back to the default.
+        assert!(
+            library
+                .resolve(&ThemeChoice::Named("broken".into()), None)
+                .dark_mode
+        );
+    }
+}
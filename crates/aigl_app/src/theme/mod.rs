@@ -1,9 +1,27 @@
 mod color;
-#[cfg(not(feature = "load-theme"))]
-mod radix;
+mod generate;
+mod library;
 mod theme_struct;
 
 #[allow(unused_imports)]
 pub use color::Color;
+pub use generate::generate_theme_ron;
+pub use library::{ThemeChoice, ThemeLibrary};
 #[allow(unused_imports)]
 pub use theme_struct::{Scale, Theme};
+
+/// Themes that are compiled into the app, the first one is the default.
+const BUNDLED_THEMES: [(&str, &str); 3] = [
+    (
+        "Dark",
+        include_str!("../../../../resources/themes/dark.ron"),
+    ),
+    (
+        "Light",
+        include_str!("../../../../resources/themes/light.ron"),
+    ),
+    (
+        "High contrast",
+        include_str!("../../../../resources/themes/high_contrast.ron"),
+    ),
+];
@@ -1,67 +0,0 @@
-use super::theme_struct::ThemeConfig;
-use super::{Color, Scale, Theme};
-
-#[allow(dead_code)]
-pub const RADIX_THEME: Theme = ThemeConfig {
-    dark_mode: true,
-    base: Scale {
-        bg: Color::from_rgb(17, 17, 19),
-        bg_subtle: Color::from_rgb(24, 25, 27),
-        bg_element: Color::from_rgb(33, 34, 37),
-        bg_element_hovered: Color::from_rgb(39, 42, 45),
-        bg_element_active: Color::from_rgb(46, 49, 53),
-        bg_solid: Color::from_rgb(105, 110, 119),
-        bg_solid_hovered: Color::from_rgb(119, 123, 132),
-        border: Color::from_rgb(54, 58, 63),
-        border_element: Color::from_rgb(67, 72, 78),
-        border_element_hovered: Color::from_rgb(90, 97, 105),
-        fg_low_contrast: Color::from_rgb(176, 180, 186),
-        fg_high_contrast: Color::from_rgb(237, 238, 240),
-    },
-    highlight: Scale {
-        bg: Color::from_rgb(17, 19, 31),
-        bg_subtle: Color::from_rgb(20, 23, 38),
-        bg_element: Color::from_rgb(24, 36, 73),
-        bg_element_hovered: Color::from_rgb(29, 46, 98),
-        bg_element_active: Color::from_rgb(37, 57, 116),
-        bg_solid: Color::from_rgb(62, 99, 221),
-        bg_solid_hovered: Color::from_rgb(84, 114, 228),
-        border: Color::from_rgb(48, 67, 132),
-        border_element: Color::from_rgb(58, 79, 151),
-        border_element_hovered: Color::from_rgb(67, 93, 177),
-        fg_low_contrast: Color::from_rgb(158, 177, 255),
-        fg_high_contrast: Color::from_rgb(214, 225, 255),
-    },
-    warning: Scale {
-        bg: Color::from_rgb(22, 18, 12),
-        bg_subtle: Color::from_rgb(29, 24, 15),
-        bg_element: Color::from_rgb(48, 32, 8),
-        bg_element_hovered: Color::from_rgb(63, 39, 0),
-        bg_element_active: Color::from_rgb(77, 48, 0),
-        bg_solid: Color::from_rgb(255, 197, 61),
-        bg_solid_hovered: Color::from_rgb(255, 214, 10),
-        border: Color::from_rgb(92, 61, 5),
-        border_element: Color::from_rgb(113, 79, 25),
-        border_element_hovered: Color::from_rgb(143, 100, 36),
-        fg_low_contrast: Color::from_rgb(255, 202, 22),
-        fg_high_contrast: Color::from_rgb(255, 231, 179),
-    },
-    error: Scale {
-        bg: Color::from_rgb(25, 17, 17),
-        bg_subtle: Color::from_rgb(32, 19, 20),
-        bg_element: Color::from_rgb(59, 18, 25),
-        bg_element_hovered: Color::from_rgb(80, 15, 28),
-        bg_element_active: Color::from_rgb(97, 22, 35),
-        bg_solid: Color::from_rgb(229, 72, 77),
-        bg_solid_hovered: Color::from_rgb(236, 93, 94),
-        border: Color::from_rgb(114, 35, 45),
-        border_element: Color::from_rgb(140, 51, 58),
-        border_element_hovered: Color::from_rgb(181, 69, 72),
-        fg_low_contrast: Color::from_rgb(255, 149, 146),
-        fg_high_contrast: Color::from_rgb(255, 209, 217),
-    },
-    stroke_width: 1.0,
-    element_border_width: 1.0,
-    corner_radius: 1,
-}
-.build();
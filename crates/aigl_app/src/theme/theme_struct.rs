@@ -1,25 +1,33 @@
 use super::color::Color;
+use super::generate::{ScaleKind, generate_scale};
+use anyhow::Result;
 use eframe::{
     egui::{self, CornerRadius, Stroke, style},
     epaint,
 };
+use std::sync::{Arc, LazyLock, RwLock};
 
-#[cfg(feature = "load-theme")]
-use anyhow::Result;
+/// The theme that is used when no other theme has been selected.
+static DEFAULT_THEME: LazyLock<Arc<Theme>> = LazyLock::new(|| {
+    let (_, ron) = super::BUNDLED_THEMES[0];
+    Arc::new(Theme::from_ron(ron).expect("Bundled themes are valid"))
+});
 
-#[cfg_attr(
-    feature = "load-theme",
-    derive(serde::Deserialize),
-    serde(rename = "Theme")
-)]
-#[allow(dead_code)]
-#[derive(Debug)]
+/// Minimum contrast ratio of the focus outline against the background.
+const MIN_FOCUS_CONTRAST: f32 = 3.0;
+/// Minimum width of the focus outline in points.
+const MIN_FOCUS_WIDTH: f32 = 2.0;
+
+static SELECTED_THEME: RwLock<Option<Arc<Theme>>> = RwLock::new(None);
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename = "Theme")]
 pub struct ThemeConfig {
     pub dark_mode: bool,
-    pub base: Scale,
-    pub highlight: Scale,
-    pub warning: Scale,
-    pub error: Scale,
+    pub base: ScaleConfig,
+    pub highlight: ScaleConfig,
+    pub warning: ScaleConfig,
+    pub error: ScaleConfig,
 
     pub stroke_width: f32,
     pub element_border_width: f32,
@@ -38,6 +46,8 @@ pub struct Theme {
     pub stroke_width: f32,
     pub element_border_width: f32,
     pub corner_radius: u8,
+    /// Outline of the widget that has keyboard focus.
+    pub focus_stroke: Stroke,
 
     pub base_widget_visuals: style::Widgets,
     pub highlight_widget_visuals: style::Widgets,
@@ -45,9 +55,16 @@ pub struct Theme {
     pub error_widget_visuals: style::Widgets,
 }
 
-#[cfg_attr(feature = "load-theme", derive(serde::Deserialize))]
+/// A scale in a theme file, either with all colors or generated from a seed color.
+#[derive(Debug, serde::Deserialize)]
+pub enum ScaleConfig {
+    Scale(Scale),
+    Accent(Color),
+    Gray(Color),
+}
+
 #[allow(dead_code)]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, serde::Deserialize)]
 pub struct Scale {
     pub bg: Color,
     pub bg_subtle: Color,
@@ -63,21 +80,37 @@ pub struct Scale {
     pub fg_high_contrast: Color,
 }
 
+impl ScaleConfig {
+    fn build(&self, dark_mode: bool) -> Scale {
+        match self {
+            Self::Scale(scale) => scale.clone(),
+            Self::Accent(seed) => generate_scale(*seed, ScaleKind::Accent, dark_mode),
+            Self::Gray(seed) => generate_scale(*seed, ScaleKind::Gray, dark_mode),
+        }
+    }
+}
+
 impl ThemeConfig {
-    pub const fn build(self) -> Theme {
-        let base_widget_visuals = self.make_widgets(&self.base);
-        let highlight_widget_visuals = self.make_widgets(&self.highlight);
-        let warning_widget_visuals = self.make_widgets(&self.warning);
-        let error_widget_visuals = self.make_widgets(&self.error);
+    pub fn build(self) -> Theme {
+        let base = self.base.build(self.dark_mode);
+        let highlight = self.highlight.build(self.dark_mode);
+        let warning = self.warning.build(self.dark_mode);
+        let error = self.error.build(self.dark_mode);
+        let focus_stroke = self.focus_stroke(&base, &highlight);
+        let base_widget_visuals = self.make_widgets(&base, focus_stroke);
+        let highlight_widget_visuals = self.make_widgets(&highlight, focus_stroke);
+        let warning_widget_visuals = self.make_widgets(&warning, focus_stroke);
+        let error_widget_visuals = self.make_widgets(&error, focus_stroke);
         Theme {
             dark_mode: self.dark_mode,
-            base: self.base,
-            highlight: self.highlight,
-            warning: self.warning,
-            error: self.error,
+            base,
+            highlight,
+            warning,
+            error,
             stroke_width: self.stroke_width,
             element_border_width: self.element_border_width,
             corner_radius: self.corner_radius,
+            focus_stroke,
             base_widget_visuals,
             highlight_widget_visuals,
             warning_widget_visuals,
@@ -85,7 +118,25 @@ impl ThemeConfig {
         }
     }
 
-    const fn make_widgets(&self, scale: &Scale) -> style::Widgets {
+    /// A focus outline in the accent color, or in the text color if the accent is too faint.
+    ///
+    /// Focus indicators need a contrast of at least 3:1 against the background to be visible.
+    fn focus_stroke(&self, base: &Scale, highlight: &Scale) -> Stroke {
+        let color = if highlight.bg_solid.contrast_ratio(base.bg) >= MIN_FOCUS_CONTRAST {
+            highlight.bg_solid
+        } else {
+            base.fg_high_contrast
+        };
+        Stroke {
+            color: color.0,
+            width: self.element_border_width.max(MIN_FOCUS_WIDTH),
+        }
+    }
+
+    /// Widget visuals in the colors of `scale`.
+    ///
+    /// egui uses the active visuals for focused widgets, so they get the `focus` outline.
+    const fn make_widgets(&self, scale: &Scale, focus: Stroke) -> style::Widgets {
         let base_visuals = style::WidgetVisuals {
             bg_fill: scale.bg_element.0,
             weak_bg_fill: scale.bg_element.0,
@@ -112,10 +163,7 @@ impl ThemeConfig {
         let active = style::WidgetVisuals {
             bg_fill: scale.bg_element_active.0,
             weak_bg_fill: scale.bg_element_active.0,
-            bg_stroke: Stroke {
-                color: scale.border_element_hovered.0,
-                ..base_visuals.bg_stroke
-            },
+            bg_stroke: focus,
             ..base_visuals
         };
         style::Widgets {
@@ -129,26 +177,38 @@ impl ThemeConfig {
 }
 
 impl Theme {
-    #[cfg(feature = "load-theme")]
-    pub fn get_selected() -> Self {
-        lazy_static::lazy_static! {
-            static ref THEME_STRING: String = read_string(
-                &std::path::Path::new(env!("CARGO_WORKSPACE_DIR"))
-                    .join("resources")
-                    .join("themes")
-                    .join("radix.ron"))
-            .expect("Failed to load theme");
-        }
-        let config: ThemeConfig = ron::from_str(&THEME_STRING).expect("Failed to parse theme");
-        config.build()
+    /// Parse a theme from the contents of a `.ron` file.
+    pub fn from_ron(ron: &str) -> Result<Self> {
+        // Allows writing `Scale(bg: ...)` instead of `Scale((bg: ...))` for explicit scales.
+        let options = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::UNWRAP_VARIANT_NEWTYPES);
+        let config: ThemeConfig = options.from_str(ron)?;
+        Ok(config.build())
     }
 
-    #[cfg(not(feature = "load-theme"))]
-    pub const fn get_selected() -> &'static Self {
-        &super::radix::RADIX_THEME
+    pub fn get_selected() -> Arc<Self> {
+        SELECTED_THEME
+            .read()
+            .ok()
+            .and_then(|selected| selected.clone())
+            .unwrap_or_else(|| DEFAULT_THEME.clone())
+    }
+
+    /// Make `theme` the one returned by [`Theme::get_selected`] and use it for `ctx`.
+    pub fn select(theme: Arc<Self>, ctx: &egui::Context) {
+        theme.apply(ctx);
+        if let Ok(mut selected) = SELECTED_THEME.write() {
+            *selected = Some(theme);
+        }
     }
 
     pub fn apply(&self, ctx: &egui::Context) {
+        // Otherwise, egui switches to its own visuals when the OS theme changes.
+        ctx.set_theme(if self.dark_mode {
+            egui::Theme::Dark
+        } else {
+            egui::Theme::Light
+        });
         let old = ctx.style().visuals.clone();
         ctx.set_visuals(egui::Visuals {
             dark_mode: self.dark_mode,
@@ -180,23 +240,11 @@ impl Theme {
             },
             selection: style::Selection {
                 bg_fill: self.highlight.bg_solid.0,
-                stroke: Stroke {
-                    color: self.highlight.border_element.0,
-                    ..old.selection.stroke
-                },
+                // Outlines focused text fields.
+                stroke: self.focus_stroke,
             },
             widgets: self.base_widget_visuals.clone(),
             ..old
         });
     }
 }
-
-#[cfg(feature = "load-theme")]
-fn read_string(path: &std::path::Path) -> Result<String> {
-    use std::io::Read;
-
-    let mut file = std::fs::File::open(path)?;
-    let mut string = String::new();
-    file.read_to_string(&mut string)?;
-    Ok(string)
-}
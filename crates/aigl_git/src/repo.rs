@@ -5,25 +5,21 @@ pub struct Repository {
     repo: git2::Repository,
 }
 
+/// The commit that a remote repository's default branch points to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RemoteHead {
+    /// `None` if the server does not report the name of the branch.
+    pub branch: Option<String>,
+    pub commit: String,
+}
+
 impl Repository {
     /// Clone a remote repository to the specified path.
     pub fn clone(url: &str, target: &Path, shallow: bool) -> Result<Self> {
         if target.exists() {
             bail!("Output directory {} already exists", target.display());
         }
-        let git_config = git2::Config::open_default()?;
-
-        let mut callbacks = git2::RemoteCallbacks::new();
-        let mut cred_handler = git2_credentials::CredentialHandler::new(git_config);
-        callbacks.credentials(move |url, username_from_url, _allowed_types| {
-            cred_handler.try_next_credential(url, username_from_url, _allowed_types)
-        });
-
-        let mut fetch_options = git2::FetchOptions::new();
-        fetch_options.remote_callbacks(callbacks);
-        if shallow {
-            fetch_options.depth(1);
-        }
+        let fetch_options = fetch_options(shallow)?;
 
         let repo = git2::build::RepoBuilder::new()
             .fetch_options(fetch_options)
@@ -31,6 +27,64 @@ impl Repository {
         Ok(Self { repo })
     }
 
+    /// Clone a remote repository and check out `commit` with a detached HEAD.
+    ///
+    /// `commit` must be a full hash. Only this commit is fetched,
+    /// so it does not have to be the tip of a branch.
+    pub fn clone_at_commit(url: &str, target: &Path, commit: &str, shallow: bool) -> Result<Self> {
+        if target.exists() {
+            bail!("Output directory {} already exists", target.display());
+        }
+        let oid = git2::Oid::from_str(commit)?;
+        let repo = git2::Repository::init(target)?;
+        repo.remote("origin", url)?
+            .fetch(&[commit], Some(&mut fetch_options(shallow)?), None)?;
+        {
+            // Objects are addressed by their hash, so finding it verifies the content.
+            let commit = repo.find_commit(oid)?;
+            repo.checkout_tree(
+                commit.as_object(),
+                Some(git2::build::CheckoutBuilder::new().force()),
+            )?;
+        }
+        repo.set_head_detached(oid)?;
+        Ok(Self { repo })
+    }
+
+    /// Look up the default branch of a remote repository without cloning it.
+    pub fn remote_head(url: &str) -> Result<RemoteHead> {
+        let mut remote = git2::Remote::create_detached(url)?;
+        let connection =
+            remote.connect_auth(git2::Direction::Fetch, Some(remote_callbacks()?), None)?;
+        let Some(head) = connection.list()?.iter().find(|head| head.name() == "HEAD") else {
+            bail!("Remote {url} has no HEAD");
+        };
+        Ok(RemoteHead {
+            branch: head
+                .symref_target()
+                .map(|target| target.trim_start_matches("refs/heads/").to_owned()),
+            commit: head.oid().to_string(),
+        })
+    }
+
+    /// Look up the size of a remote repository in bytes without cloning it.
+    ///
+    /// The git protocol does not report sizes, so this asks the hosting service.
+    /// Returns `None` for hosts other than GitHub.
+    pub fn remote_size(url: &str) -> Result<Option<u64>> {
+        let Some(path) = github_repo_path(url) else {
+            return Ok(None);
+        };
+        let info: serde_json::Value = reqwest::blocking::Client::new()
+            .get(format!("https://api.github.com/repos/{path}"))
+            .header(reqwest::header::USER_AGENT, "aigl")
+            .send()?
+            .error_for_status()?
+            .json()?;
+        // GitHub reports the size in KiB.
+        Ok(info["size"].as_u64().map(|size| size * 1024))
+    }
+
     /// Create a new repository at the specified path.
     pub fn init(path: &Path) -> Result<Self> {
         let repo = git2::Repository::init(path)?;
@@ -43,6 +97,47 @@ impl Repository {
         Ok(Self { repo })
     }
 
+    /// The full hash of the commit that HEAD points to.
+    pub fn head_commit_id(&self) -> Result<String> {
+        Ok(self.repo.head()?.peel_to_commit()?.id().to_string())
+    }
+
+    /// Fetch the current branch from `origin` and fast-forward to it.
+    ///
+    /// Returns `false` if the repository was already up to date.
+    /// Fails if the branch has diverged or local changes would be overwritten.
+    pub fn pull_fast_forward(&self) -> Result<bool> {
+        let head = self.repo.head()?;
+        let (Some(branch), Some(ref_name)) = (head.shorthand(), head.name()) else {
+            bail!("HEAD is not on a branch");
+        };
+        let mut remote = self.repo.find_remote("origin")?;
+        remote.fetch(
+            &[branch],
+            Some(&mut fetch_options(self.repo.is_shallow())?),
+            None,
+        )?;
+
+        let fetch_head = self.repo.find_reference("FETCH_HEAD")?;
+        let fetch_commit = self.repo.reference_to_annotated_commit(&fetch_head)?;
+        let (analysis, _) = self.repo.merge_analysis(&[&fetch_commit])?;
+        if analysis.is_up_to_date() {
+            return Ok(false);
+        }
+        if !analysis.is_fast_forward() {
+            bail!("Branch {branch} has diverged from origin, update it manually");
+        }
+
+        // Check out first so that a conflict with local changes leaves HEAD untouched.
+        let target = self.repo.find_object(fetch_commit.id(), None)?;
+        self.repo
+            .checkout_tree(&target, Some(git2::build::CheckoutBuilder::new().safe()))?;
+        self.repo
+            .find_reference(ref_name)?
+            .set_target(fetch_commit.id(), "Fast-forward")?;
+        Ok(true)
+    }
+
     /// Commit all changes to the repository and advance HEAD.
     pub fn commit_all(&self, message: &str) -> Result<()> {
         let mut index = self.add_all()?;
@@ -83,3 +178,35 @@ impl Repository {
         })
     }
 }
+
+/// Fetch options that authenticate with [`remote_callbacks`].
+fn fetch_options(shallow: bool) -> Result<git2::FetchOptions<'static>> {
+    let mut fetch_options = git2::FetchOptions::new();
+    fetch_options.remote_callbacks(remote_callbacks()?);
+    if shallow {
+        fetch_options.depth(1);
+    }
+    Ok(fetch_options)
+}
+
+/// Callbacks that use the credentials from the user's git config.
+fn remote_callbacks() -> Result<git2::RemoteCallbacks<'static>> {
+    let git_config = git2::Config::open_default()?;
+
+    let mut callbacks = git2::RemoteCallbacks::new();
+    let mut cred_handler = git2_credentials::CredentialHandler::new(git_config);
+    callbacks.credentials(move |url, username_from_url, _allowed_types| {
+        cred_handler.try_next_credential(url, username_from_url, _allowed_types)
+    });
+    Ok(callbacks)
+}
+
+/// The `owner/repo` part of a GitHub repository URL.
+fn github_repo_path(url: &str) -> Option<String> {
+    let path = url
+        .strip_prefix("https://github.com/")?
+        .trim_end_matches('/');
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    let (owner, repo) = path.split_once('/')?;
+    (!owner.is_empty() && !repo.is_empty() && !repo.contains('/')).then(|| path.to_owned())
+}
@@ -0,0 +1,36 @@
+use aigl_git::Repository;
+use std::fs;
+use std::path::PathBuf;
+
+fn test_dir() -> PathBuf {
+    let dir = PathBuf::from("tests").join("test_git_clone_at_commit");
+    if dir.exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir_all(dir.join("origin")).unwrap();
+    dir
+}
+
+#[test]
+fn can_clone_a_commit_that_is_not_the_head() {
+    let dir = test_dir();
+    let origin_path = dir.join("origin");
+    let origin = Repository::init(&origin_path).unwrap();
+    fs::write(origin_path.join("version.txt"), "1").unwrap();
+    origin.commit_all("First").unwrap();
+    let pinned = origin.head_commit_id().unwrap();
+    fs::write(origin_path.join("version.txt"), "2").unwrap();
+    origin.commit_all("Second").unwrap();
+
+    let url = fs::canonicalize(&origin_path).unwrap();
+    let clone_path = dir.join("clone");
+    let clone =
+        Repository::clone_at_commit(url.to_str().unwrap(), &clone_path, &pinned, false).unwrap();
+    assert_eq!(clone.head_commit_id().unwrap(), pinned);
+    assert_eq!(
+        fs::read_to_string(clone_path.join("version.txt")).unwrap(),
+        "1"
+    );
+
+    fs::remove_dir_all(dir).unwrap();
+}
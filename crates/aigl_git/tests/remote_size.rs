@@ -0,0 +1,21 @@
+use aigl_git::Repository;
+use std::fs;
+use std::path::PathBuf;
+
+#[test]
+fn size_of_local_remote_is_unknown() {
+    let dir = PathBuf::from("tests").join("test_git_remote_size");
+    if dir.exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    fs::create_dir_all(&dir).unwrap();
+    Repository::init(&dir).unwrap();
+
+    let url = fs::canonicalize(&dir).unwrap();
+    assert_eq!(
+        Repository::remote_size(url.to_str().unwrap()).unwrap(),
+        None
+    );
+
+    fs::remove_dir_all(dir).unwrap();
+}
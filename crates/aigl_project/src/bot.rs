@@ -1,25 +1,156 @@
 use async_lock::Mutex;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use crate::Project;
-use crate::config::game::{BotTemplateArg, BotTemplateArgType};
-use aigl_system::fs::copy_dir_recursive;
+use crate::config;
+use crate::config::game::{
+    BotTemplateArg, BotTemplateArgDefault, BotTemplateArgType, RenderConfig,
+};
+use crate::config::template::TemplateManifest;
+use crate::template::{BotTemplate, TemplateVars, render_template_dir};
+use aigl_system::fs::{CopyOptions, copy_dir};
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum BotArgValue {
     String(String),
     Color([u8; 3]),
     Path(String),
+    Integer {
+        value: i64,
+        min: Option<i64>,
+        max: Option<i64>,
+    },
+    Float {
+        value: f64,
+        min: Option<f64>,
+        max: Option<f64>,
+    },
+    Bool(bool),
+    Choice {
+        value: String,
+        options: Vec<String>,
+    },
 }
 
 impl BotArgValue {
-    pub fn serialize_value(&self) -> String {
+    /// The value as it is written to the bot's `config.toml`.
+    pub fn serialize_value(&self) -> toml_edit::Value {
+        match self {
+            Self::String(s) => s.into(),
+            Self::Color(c) => format!("#{:02x}{:02x}{:02x}", c[0], c[1], c[2]).into(),
+            Self::Path(p) => p.into(),
+            Self::Integer { value, .. } => (*value).into(),
+            Self::Float { value, .. } => (*value).into(),
+            Self::Bool(value) => (*value).into(),
+            Self::Choice { value, .. } => value.into(),
+        }
+    }
+
+    /// The value as it is inserted into template placeholders.
+    pub fn render_value(&self) -> String {
+        match self.serialize_value() {
+            toml_edit::Value::String(value) => value.into_value(),
+            value => value.to_string().trim().to_owned(),
+        }
+    }
+
+    /// Whether the value enables conditional template files.
+    pub(crate) fn is_truthy(&self) -> bool {
+        match self {
+            Self::Bool(value) => *value,
+            Self::Integer { value, .. } => *value != 0,
+            Self::Float { value, .. } => *value != 0.0,
+            Self::Color(_) => true,
+            Self::String(_) | Self::Path(_) | Self::Choice { .. } => !self.is_empty(),
+        }
+    }
+
+    /// The initial value of a template arg.
+    ///
+    /// Fails if the default value does not match the type of the arg.
+    pub fn from_template_arg(arg: &BotTemplateArg) -> Result<Self, String> {
+        let mismatch = || format!("The default value does not match type {:?}", arg.ty);
+        let value = match (&arg.ty, &arg.default) {
+            (BotTemplateArgType::String, None) => Self::String(String::new()),
+            (BotTemplateArgType::String, Some(BotTemplateArgDefault::String(value))) => {
+                Self::String(value.clone())
+            }
+            (BotTemplateArgType::Color, None) => Self::Color([0, 0, 0]),
+            (BotTemplateArgType::Color, Some(BotTemplateArgDefault::String(value))) => {
+                Self::color_from_string(value)
+            }
+            (BotTemplateArgType::Path, None) => Self::Path(String::new()),
+            (BotTemplateArgType::Path, Some(BotTemplateArgDefault::String(value))) => {
+                Self::Path(value.clone())
+            }
+            (BotTemplateArgType::Integer, default) => {
+                let min = arg.min.map(|min| min.ceil() as i64);
+                let max = arg.max.map(|max| max.floor() as i64);
+                let value = match default {
+                    None => zero_within(min, max),
+                    Some(BotTemplateArgDefault::Integer(value)) => *value,
+                    Some(_) => return Err(mismatch()),
+                };
+                Self::Integer { value, min, max }
+            }
+            (BotTemplateArgType::Float, default) => {
+                let value = match default {
+                    None => zero_within(arg.min, arg.max),
+                    Some(BotTemplateArgDefault::Float(value)) => *value,
+                    Some(BotTemplateArgDefault::Integer(value)) => *value as f64,
+                    Some(_) => return Err(mismatch()),
+                };
+                Self::Float {
+                    value,
+                    min: arg.min,
+                    max: arg.max,
+                }
+            }
+            (BotTemplateArgType::Bool, None) => Self::Bool(false),
+            (BotTemplateArgType::Bool, Some(BotTemplateArgDefault::Bool(value))) => {
+                Self::Bool(*value)
+            }
+            (BotTemplateArgType::Choice, default) => {
+                let value = match default {
+                    None => arg.options.first().cloned().unwrap_or_default(),
+                    Some(BotTemplateArgDefault::String(value)) => value.clone(),
+                    Some(_) => return Err(mismatch()),
+                };
+                Self::Choice {
+                    value,
+                    options: arg.options.clone(),
+                }
+            }
+            _ => return Err(mismatch()),
+        };
+        Ok(value)
+    }
+
+    /// Check that the value is within its bounds or one of its options.
+    pub fn validate(&self) -> Result<(), BotArgError> {
         match self {
-            Self::String(s) => s.clone(),
-            Self::Color(c) => format!("#{:02x}{:02x}{:02x}", c[0], c[1], c[2]),
-            Self::Path(p) => p.clone(),
+            Self::Integer { value, min, max } => check_range(*value, *min, *max),
+            Self::Float { value, min, max } => check_range(*value, *min, *max),
+            Self::Choice { value, options } => {
+                if options.contains(value) {
+                    Ok(())
+                } else {
+                    Err(BotArgError::NotAnOption(options.clone()))
+                }
+            }
+            Self::String(_) | Self::Color(_) | Self::Path(_) | Self::Bool(_) => Ok(()),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            Self::String(value) | Self::Path(value) | Self::Choice { value, .. } => {
+                value.trim().is_empty()
+            }
+            _ => false,
         }
     }
 
@@ -34,33 +165,125 @@ impl BotArgValue {
             let s = c.encode_utf8(&mut str_buf);
             // Silently handle invalid hex characters to keep error handling simple.
             let byte = u8::from_str_radix(s, 16).unwrap_or(0);
-            color[i / 2] += byte * (16 * (i % 2) as u8);
+            color[i / 2] += byte * if i % 2 == 0 { 16 } else { 1 };
             i += 1;
         });
         Self::Color(color)
     }
 }
 
+/// Zero or the closest bound if zero is out of range.
+fn zero_within<T: PartialOrd + Default>(min: Option<T>, max: Option<T>) -> T {
+    match (min, max) {
+        (Some(min), _) if min > T::default() => min,
+        (_, Some(max)) if max < T::default() => max,
+        _ => T::default(),
+    }
+}
+
+fn check_range<T: PartialOrd + std::fmt::Display>(
+    value: T,
+    min: Option<T>,
+    max: Option<T>,
+) -> Result<(), BotArgError> {
+    if let Some(min) = min {
+        if value < min {
+            return Err(BotArgError::TooSmall(min.to_string()));
+        }
+    }
+    if let Some(max) = max {
+        if value > max {
+            return Err(BotArgError::TooLarge(max.to_string()));
+        }
+    }
+    Ok(())
+}
+
+/// Why the value of a bot arg is invalid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BotArgError {
+    /// A required arg is empty, holds the display name of the arg.
+    Required(String),
+    /// The value is less than the minimum, formatted like the value.
+    TooSmall(String),
+    /// The value is greater than the maximum, formatted like the value.
+    TooLarge(String),
+    /// The value is not one of these options.
+    NotAnOption(Vec<String>),
+}
+
+impl fmt::Display for BotArgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Required(display) => write!(f, "{display} is required"),
+            Self::TooSmall(min) => write!(f, "Must be at least {min}"),
+            Self::TooLarge(max) => write!(f, "Must be at most {max}"),
+            Self::NotAnOption(options) => write!(f, "Must be one of {}", options.join(", ")),
+        }
+    }
+}
+
+impl std::error::Error for BotArgError {}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BotArg {
     pub var: String,
     pub display: String,
+    pub description: Option<String>,
+    pub required: bool,
     pub value: BotArgValue,
 }
 
 impl BotArg {
     pub fn default_from_template_arg(template_arg: BotTemplateArg) -> Self {
-        let value = match template_arg.ty {
-            BotTemplateArgType::String => BotArgValue::String(String::new()),
-            BotTemplateArgType::Color => BotArgValue::Color([0, 0, 0]),
-            BotTemplateArgType::Path => BotArgValue::Path(String::new()),
-        };
+        // Game configs are validated when loaded, so this only falls back for invalid defaults.
+        let value = BotArgValue::from_template_arg(&template_arg).unwrap_or_else(|_| {
+            BotArgValue::from_template_arg(&BotTemplateArg {
+                default: None,
+                ..template_arg.clone()
+            })
+            .expect("args without defaults always have a value")
+        });
         Self {
             var: template_arg.var,
             display: template_arg.display,
+            description: template_arg.description,
+            required: template_arg.required,
             value,
         }
     }
+
+    /// The arg of a bot that the launcher generates as an opponent.
+    ///
+    /// Nobody fills in the args of these bots, so colors use `color` if given and
+    /// empty strings use the bot's name. Other required args may stay empty.
+    pub(crate) fn for_generated_bot(
+        template_arg: BotTemplateArg,
+        bot_name: &str,
+        color: Option<&str>,
+    ) -> Result<Self, BotArgError> {
+        let mut arg = Self::default_from_template_arg(template_arg);
+        match (&mut arg.value, color) {
+            (BotArgValue::Color(_), Some(color)) => {
+                arg.value = BotArgValue::color_from_string(color)
+            }
+            (BotArgValue::String(value), _) if value.trim().is_empty() => {
+                *value = bot_name.to_owned()
+            }
+            _ => {}
+        }
+        arg.required = false;
+        arg.validate()?;
+        Ok(arg)
+    }
+
+    /// Check the value, returns why it is invalid for players.
+    pub fn validate(&self) -> Result<(), BotArgError> {
+        if self.required && self.value.is_empty() {
+            return Err(BotArgError::Required(self.display.clone()));
+        }
+        self.value.validate()
+    }
 }
 
 pub(crate) struct Bot {
@@ -68,6 +291,8 @@ pub(crate) struct Bot {
     id: String,
     name: String,
     args: Vec<BotArg>,
+    /// Package path from the template manifest relative to `root`.
+    package: Option<String>,
 }
 
 impl Bot {
@@ -78,14 +303,25 @@ impl Bot {
         name: String,
         args: Vec<BotArg>,
     ) -> anyhow::Result<Self> {
+        let template = load_bot_template(project.clone()).await?;
+        let args = template.complete_args(args)?;
+        let vars = TemplateVars::new(&id, &name, &args);
+        template.validate_conditions(&vars)?;
         copy_bot_template(project.clone(), target).await?;
+
         let bot = Self {
             root: target.to_path_buf(),
             id,
             name,
             args,
+            package: template
+                .package
+                .as_deref()
+                .map(|package| vars.render(package)),
         };
+        bot.render_files(&vars, &template.render).await?;
         bot.apply_args(&bot.id, &bot.name, &bot.args).await?;
+        template.run_post_render(&bot.root, &vars).await?;
         project
             .lock()
             .await
@@ -101,9 +337,9 @@ impl Bot {
         name: &str,
         args: &[BotArg],
     ) -> anyhow::Result<()> {
-        self.move_package_src(id)?;
+        let package_path = self.move_package_src(id)?;
 
-        let config_path = self.config_file_path()?;
+        let config_path = package_path.join("config.toml");
         let mut config = tokio::fs::read_to_string(&config_path)
             .await?
             .parse::<toml_edit::DocumentMut>()?;
@@ -125,11 +361,22 @@ impl Bot {
         Ok(())
     }
 
+    /// Substitute placeholders in the files selected by the template and game config.
+    async fn render_files(&self, vars: &TemplateVars, config: &RenderConfig) -> anyhow::Result<()> {
+        let root = self.root.clone();
+        let vars = vars.clone();
+        let config = config.clone();
+        tokio::task::spawn_blocking(move || render_template_dir(&root, &vars, &config)).await?
+    }
+
     pub(crate) fn root(&self) -> &Path {
         &self.root
     }
 
     fn package_src_path(&self) -> anyhow::Result<PathBuf> {
+        if let Some(package) = &self.package {
+            return Ok(self.root.join(package));
+        }
         let src_path = self.root.join("src");
         let mut entries = std::fs::read_dir(src_path)?;
         let entry = entries
@@ -141,26 +388,169 @@ impl Bot {
         Ok(entry?.path())
     }
 
-    fn config_file_path(&self) -> anyhow::Result<PathBuf> {
-        Ok(self.package_src_path()?.join("config.toml"))
-    }
-
     fn pyproject_file_path(&self) -> PathBuf {
         self.root.join("pyproject.toml")
     }
 
-    fn move_package_src(&self, new_name: &str) -> anyhow::Result<()> {
+    /// Rename the package directory and return its new path.
+    fn move_package_src(&self, new_name: &str) -> anyhow::Result<PathBuf> {
         let old = self.package_src_path()?;
         let new = old.with_file_name(new_name);
-        std::fs::rename(old, new)?;
-        Ok(())
+        // The template may already use the id as package name through a placeholder.
+        if new != old {
+            std::fs::rename(old, &new)?;
+        }
+        Ok(new)
     }
 }
 
+async fn load_bot_template(project: Arc<Mutex<Project>>) -> anyhow::Result<BotTemplate> {
+    let (template_path, game_bot) = {
+        let project = project.lock().await;
+        (
+            project.cfg().bot_template_path.clone(),
+            project.cfg().game_config.bot.clone(),
+        )
+    };
+    let manifest = TemplateManifest::load(&template_path)
+        .await?
+        .unwrap_or_default();
+    Ok(BotTemplate::new(manifest, &game_bot))
+}
+
 async fn copy_bot_template(project: Arc<Mutex<Project>>, target: &Path) -> anyhow::Result<()> {
     let src = {
         let project = project.lock().await;
         project.cfg().bot_template_path.clone()
     };
-    copy_dir_recursive(&src, target).await
+    // The template is a git checkout, whose history and build outputs the bot should not inherit.
+    let options = CopyOptions {
+        ignore: vec![
+            ".git".to_owned(),
+            format!("/{}", config::TEMPLATE_MANIFEST_FILE_NAME),
+        ],
+        gitignore: true,
+    };
+    copy_dir(&src, target, options, |_| {}).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn template_arg(ty: BotTemplateArgType) -> BotTemplateArg {
+        BotTemplateArg {
+            var: "VAR".into(),
+            display: "Arg".into(),
+            ty,
+            description: None,
+            required: false,
+            default: None,
+            min: None,
+            max: None,
+            options: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn color_from_string_parses_hex() {
+        assert_eq!(
+            BotArgValue::color_from_string("#ff8001"),
+            BotArgValue::Color([255, 128, 1])
+        );
+    }
+
+    #[test]
+    fn integer_defaults_to_min() {
+        let arg = BotTemplateArg {
+            min: Some(1.0),
+            max: Some(5.0),
+            ..template_arg(BotTemplateArgType::Integer)
+        };
+        assert_eq!(
+            BotArgValue::from_template_arg(&arg),
+            Ok(BotArgValue::Integer {
+                value: 1,
+                min: Some(1),
+                max: Some(5)
+            })
+        );
+    }
+
+    #[test]
+    fn default_must_match_type() {
+        let arg = BotTemplateArg {
+            default: Some(BotTemplateArgDefault::String("yes".into())),
+            ..template_arg(BotTemplateArgType::Bool)
+        };
+        assert!(BotArgValue::from_template_arg(&arg).is_err());
+    }
+
+    #[test]
+    fn validate_checks_range_and_options() {
+        let value = BotArgValue::Float {
+            value: 1.5,
+            min: Some(0.0),
+            max: Some(1.0),
+        };
+        assert_eq!(value.validate(), Err(BotArgError::TooLarge("1".into())));
+        let value = BotArgValue::Choice {
+            value: "insane".into(),
+            options: vec!["easy".into(), "hard".into()],
+        };
+        assert_eq!(
+            value.validate(),
+            Err(BotArgError::NotAnOption(vec!["easy".into(), "hard".into()]))
+        );
+    }
+
+    #[test]
+    fn required_args_must_not_be_empty() {
+        let mut arg = BotArg::default_from_template_arg(BotTemplateArg {
+            required: true,
+            ..template_arg(BotTemplateArgType::String)
+        });
+        assert_eq!(arg.validate(), Err(BotArgError::Required("Arg".into())));
+        arg.value = BotArgValue::String("x".into());
+        assert_eq!(arg.validate(), Ok(()));
+    }
+
+    #[test]
+    fn generated_bots_get_values_for_required_args() {
+        let required = |ty| BotTemplateArg {
+            required: true,
+            ..template_arg(ty)
+        };
+        let arg =
+            BotArg::for_generated_bot(required(BotTemplateArgType::String), "Ada", Some("#ff0000"))
+                .unwrap();
+        assert_eq!(arg.value, BotArgValue::String("Ada".into()));
+        let arg =
+            BotArg::for_generated_bot(required(BotTemplateArgType::Path), "Ada", Some("#ff0000"))
+                .unwrap();
+        assert_eq!(arg.value, BotArgValue::Path(String::new()));
+        let arg =
+            BotArg::for_generated_bot(required(BotTemplateArgType::Color), "Ada", Some("#ff0000"))
+                .unwrap();
+        assert_eq!(arg.value, BotArgValue::Color([255, 0, 0]));
+    }
+
+    #[test]
+    fn serialize_value_keeps_types() {
+        let value = BotArgValue::Integer {
+            value: 3,
+            min: None,
+            max: None,
+        };
+        assert_eq!(value.serialize_value().as_integer(), Some(3));
+        assert_eq!(
+            BotArgValue::Bool(true).serialize_value().as_bool(),
+            Some(true)
+        );
+        assert_eq!(
+            BotArgValue::Color([255, 0, 16]).serialize_value().as_str(),
+            Some("#ff0010")
+        );
+    }
 }
@@ -0,0 +1,320 @@
+use anyhow::{Context, Result, bail};
+use quick_xml::events::{BytesStart, Event};
+use std::path::Path;
+
+use crate::Project;
+use crate::config;
+
+/// Results of running the test suite of a bot.
+#[derive(Clone, Debug, Default)]
+pub struct TestReport {
+    pub cases: Vec<TestCase>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TestCase {
+    /// Dotted path of the module and class that contains the test.
+    pub class_name: String,
+    pub name: String,
+    pub file: Option<String>,
+    pub line: Option<u32>,
+    pub outcome: TestOutcome,
+    /// Captured stdout, stderr, and log output.
+    pub output: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum TestOutcome {
+    #[default]
+    Passed,
+    Failed {
+        message: String,
+        details: String,
+    },
+    Error {
+        message: String,
+        details: String,
+    },
+    Skipped {
+        message: String,
+    },
+}
+
+/// Which tests to run with [`Project::run_bot_tests`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TestSelection {
+    All,
+    /// Only the tests that failed in the previous run.
+    Failed,
+}
+
+impl TestReport {
+    pub fn n_passed(&self) -> usize {
+        self.count(|outcome| matches!(outcome, TestOutcome::Passed))
+    }
+
+    pub fn n_failed(&self) -> usize {
+        self.count(|outcome| matches!(outcome, TestOutcome::Failed { .. }))
+    }
+
+    pub fn n_errors(&self) -> usize {
+        self.count(|outcome| matches!(outcome, TestOutcome::Error { .. }))
+    }
+
+    pub fn n_skipped(&self) -> usize {
+        self.count(|outcome| matches!(outcome, TestOutcome::Skipped { .. }))
+    }
+
+    fn count(&self, predicate: impl Fn(&TestOutcome) -> bool) -> usize {
+        self.cases
+            .iter()
+            .filter(|case| predicate(&case.outcome))
+            .count()
+    }
+
+    /// Parse a JUnit XML file as written by pytest.
+    pub fn from_junit_xml(xml: &str) -> Result<Self> {
+        let mut reader = quick_xml::Reader::from_str(xml);
+        reader.config_mut().trim_text(true);
+
+        let mut cases = Vec::new();
+        let mut current: Option<TestCase> = None;
+        let mut open_element: Option<OpenElement> = None;
+        loop {
+            match reader.read_event()? {
+                Event::Start(element) => match element.name().as_ref() {
+                    b"testcase" => current = Some(parse_test_case(&element)?),
+                    name => open_element = OpenElement::start(name, &element)?,
+                },
+                Event::Empty(element) => match element.name().as_ref() {
+                    b"testcase" => cases.push(parse_test_case(&element)?),
+                    name => {
+                        if let (Some(case), Some(open)) =
+                            (current.as_mut(), OpenElement::start(name, &element)?)
+                        {
+                            open.apply_to(case);
+                        }
+                    }
+                },
+                Event::Text(text) => {
+                    if let Some(open) = open_element.as_mut() {
+                        open.text.push_str(&text.unescape()?);
+                    }
+                }
+                Event::CData(data) => {
+                    if let Some(open) = open_element.as_mut() {
+                        open.text.push_str(&String::from_utf8_lossy(&data));
+                    }
+                }
+                Event::End(element) => match element.name().as_ref() {
+                    b"testcase" => cases.extend(current.take()),
+                    _ => {
+                        if let (Some(case), Some(open)) = (current.as_mut(), open_element.take()) {
+                            open.apply_to(case);
+                        }
+                    }
+                },
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        Ok(Self { cases })
+    }
+}
+
+impl TestCase {
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self.outcome,
+            TestOutcome::Failed { .. } | TestOutcome::Error { .. }
+        )
+    }
+}
+
+/// A child element of a `testcase` whose text has not been read completely yet.
+struct OpenElement {
+    kind: OpenElementKind,
+    message: String,
+    text: String,
+}
+
+enum OpenElementKind {
+    Failure,
+    Error,
+    Skipped,
+    Output,
+}
+
+impl OpenElement {
+    fn start(name: &[u8], element: &BytesStart) -> Result<Option<Self>> {
+        let kind = match name {
+            b"failure" => OpenElementKind::Failure,
+            b"error" => OpenElementKind::Error,
+            b"skipped" => OpenElementKind::Skipped,
+            b"system-out" | b"system-err" => OpenElementKind::Output,
+            _ => return Ok(None),
+        };
+        Ok(Some(Self {
+            kind,
+            message: attribute(element, b"message")?.unwrap_or_default(),
+            text: String::new(),
+        }))
+    }
+
+    fn apply_to(self, case: &mut TestCase) {
+        match self.kind {
+            OpenElementKind::Failure => {
+                case.outcome = TestOutcome::Failed {
+                    message: self.message,
+                    details: self.text,
+                }
+            }
+            OpenElementKind::Error => {
+                case.outcome = TestOutcome::Error {
+                    message: self.message,
+                    details: self.text,
+                }
+            }
+            OpenElementKind::Skipped => {
+                case.outcome = TestOutcome::Skipped {
+                    message: self.message,
+                }
+            }
+            OpenElementKind::Output => {
+                if !case.output.is_empty() {
+                    case.output.push('\n');
+                }
+                case.output.push_str(&self.text);
+            }
+        }
+    }
+}
+
+fn parse_test_case(element: &BytesStart) -> Result<TestCase> {
+    Ok(TestCase {
+        class_name: attribute(element, b"classname")?.unwrap_or_default(),
+        name: attribute(element, b"name")?.unwrap_or_default(),
+        file: attribute(element, b"file")?,
+        line: attribute(element, b"line")?.and_then(|line| line.parse().ok()),
+        ..Default::default()
+    })
+}
+
+fn attribute(element: &BytesStart, key: &[u8]) -> Result<Option<String>> {
+    for attr in element.attributes() {
+        let attr = attr?;
+        if attr.key.as_ref() == key {
+            return Ok(Some(attr.unescape_value()?.into_owned()));
+        }
+    }
+    Ok(None)
+}
+
+impl Project {
+    /// Run the test suite of a bot with pytest.
+    ///
+    /// pytest must be installed in the venv, usually through the bot's `dev` group.
+    pub async fn run_bot_tests(
+        &self,
+        bot_id: &str,
+        selection: TestSelection,
+    ) -> Result<TestReport> {
+        let bot_path = self.bot_path(bot_id)?;
+        let results_dir = config::test_results_dir(self.root());
+        tokio::fs::create_dir_all(&results_dir).await?;
+        let report_path = results_dir.join(format!("{bot_id}.xml"));
+        if report_path.exists() {
+            tokio::fs::remove_file(&report_path).await?;
+        }
+
+        let mut command = tokio::process::Command::from(self.python_command()?);
+        command
+            .args([
+                "-m",
+                "pytest",
+                "-o",
+                "junit_family=xunit1",
+                "-o",
+                "junit_logging=all",
+            ])
+            .arg(format!("--junit-xml={}", report_path.display()))
+            .current_dir(bot_path);
+        if selection == TestSelection::Failed {
+            command.arg("--last-failed");
+        }
+        let output = command.output().await.context("Failed to run pytest")?;
+
+        if !report_path.exists() {
+            bail!(
+                "pytest did not produce a report:\n{}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        read_report(&report_path).await
+    }
+}
+
+async fn read_report(path: &Path) -> Result<TestReport> {
+    TestReport::from_junit_xml(&tokio::fs::read_to_string(path).await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const REPORT: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<testsuites><testsuite name="pytest" errors="1" failures="1" skipped="1" tests="4">
+<testcase classname="tests.test_bot" name="test_move" file="tests/test_bot.py" line="3" time="0.001" />
+<testcase classname="tests.test_bot" name="test_attack" file="tests/test_bot.py" line="7" time="0.002"><failure message="assert 1 == 2">def test_attack():
+&gt;       assert 1 == 2</failure><system-out>attacking</system-out></testcase>
+<testcase classname="tests.test_bot.TestSetup" name="test_config" file="tests/test_bot.py" line="12" time="0.000"><error message="failed on setup with &quot;KeyError&quot;">KeyError: 'color'</error></testcase>
+<testcase classname="tests.test_bot" name="test_later" file="tests/test_bot.py" line="20" time="0.000"><skipped type="pytest.skip" message="not yet">skipped</skipped></testcase>
+</testsuite></testsuites>"#;
+
+    #[test]
+    fn parse_junit_xml_reads_all_test_cases() {
+        let report = TestReport::from_junit_xml(REPORT).unwrap();
+        let names: Vec<_> = report.cases.iter().map(|case| case.name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["test_move", "test_attack", "test_config", "test_later"]
+        );
+        assert_eq!(report.n_passed(), 1);
+        assert_eq!(report.n_failed(), 1);
+        assert_eq!(report.n_errors(), 1);
+        assert_eq!(report.n_skipped(), 1);
+    }
+
+    #[test]
+    fn parse_junit_xml_reads_failure_details_and_output() {
+        let report = TestReport::from_junit_xml(REPORT).unwrap();
+        assert_eq!(
+            report.cases[1],
+            TestCase {
+                class_name: "tests.test_bot".into(),
+                name: "test_attack".into(),
+                file: Some("tests/test_bot.py".into()),
+                line: Some(7),
+                outcome: TestOutcome::Failed {
+                    message: "assert 1 == 2".into(),
+                    details: "def test_attack():\n>       assert 1 == 2".into(),
+                },
+                output: "attacking".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_junit_xml_reads_errors() {
+        let report = TestReport::from_junit_xml(REPORT).unwrap();
+        assert_eq!(
+            report.cases[2].outcome,
+            TestOutcome::Error {
+                message: "failed on setup with \"KeyError\"".into(),
+                details: "KeyError: 'color'".into(),
+            }
+        );
+        assert_eq!(report.cases[2].class_name, "tests.test_bot.TestSetup");
+    }
+}
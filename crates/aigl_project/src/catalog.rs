@@ -0,0 +1,284 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::config::game::{GameConfig, find_translation};
+
+pub const CATALOG_FILE_NAME: &str = "index.toml";
+
+/// The list of games that players can choose from.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Catalog {
+    #[serde(default, rename = "game")]
+    pub games: Vec<CatalogEntry>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CatalogEntry {
+    /// Code that identifies the game config file.
+    pub code: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub min_launcher_version: Option<String>,
+    /// Names and descriptions in other languages by language code.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub translations: BTreeMap<String, CatalogTranslation>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CatalogTranslation {
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
+
+impl Catalog {
+    /// Build a catalog from all game configs in `dir`.
+    ///
+    /// The code of a game is the stem of its file name.
+    pub fn from_game_dir(dir: &Path) -> Result<Self> {
+        let mut games = Vec::new();
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|ext| ext != "toml")
+                || path
+                    .file_name()
+                    .is_some_and(|name| name == CATALOG_FILE_NAME)
+            {
+                continue;
+            }
+            let Some(code) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            let content = std::fs::read_to_string(&path)?;
+            let config = GameConfig::validate_toml_str(&content)
+                .with_context(|| format!("Invalid game config {}", path.display()))?;
+            if !config.catalog.unlisted {
+                games.push(CatalogEntry::from_game_config(code, config));
+            }
+        }
+        games.sort_by(|a, b| a.code.cmp(&b.code));
+        Ok(Self { games })
+    }
+
+    pub fn from_toml_str(content: &str) -> Result<Self> {
+        Ok(toml_edit::de::from_str(content)?)
+    }
+
+    pub fn to_toml_string(&self) -> Result<String> {
+        Ok(format!(
+            "# Generated by `aigl catalog`, do not edit.\n\n{}",
+            toml_edit::ser::to_string_pretty(self)?
+        ))
+    }
+
+    /// Add the games of `other` whose codes are not in this catalog yet.
+    pub fn merge(&mut self, other: Catalog) {
+        for game in other.games {
+            if !self.games.iter().any(|existing| existing.code == game.code) {
+                self.games.push(game);
+            }
+        }
+    }
+
+    /// The games that match a search query, see [`CatalogEntry::matches`].
+    pub fn search<'a>(&'a self, query: &'a str) -> impl Iterator<Item = &'a CatalogEntry> {
+        self.games.iter().filter(move |game| game.matches(query))
+    }
+}
+
+impl CatalogEntry {
+    pub fn from_game_config(code: &str, config: GameConfig) -> Self {
+        Self {
+            code: code.to_owned(),
+            name: config.name,
+            description: config.catalog.description,
+            tags: config.catalog.tags,
+            min_launcher_version: config.catalog.min_launcher_version,
+            translations: config
+                .translations
+                .into_iter()
+                .filter(|(_, translation)| {
+                    translation.name.is_some() || translation.description.is_some()
+                })
+                .map(|(language, translation)| {
+                    let translation = CatalogTranslation {
+                        name: translation.name,
+                        description: translation.description,
+                    };
+                    (language, translation)
+                })
+                .collect(),
+        }
+    }
+
+    pub fn localized_name(&self, languages: &[impl AsRef<str>]) -> &str {
+        find_translation(&self.translations, languages)
+            .and_then(|translation| translation.name.as_deref())
+            .unwrap_or(&self.name)
+    }
+
+    pub fn localized_description(&self, languages: &[impl AsRef<str>]) -> &str {
+        find_translation(&self.translations, languages)
+            .and_then(|translation| translation.description.as_deref())
+            .unwrap_or(&self.description)
+    }
+
+    /// Check whether all words in `query` occur in the code, name, description, or tags.
+    ///
+    /// Names and descriptions in all languages are searched.
+    /// The comparison is case-insensitive.
+    pub fn matches(&self, query: &str) -> bool {
+        let translated = self.translations.values().flat_map(|translation| {
+            [&translation.name, &translation.description]
+                .into_iter()
+                .flatten()
+                .map(String::as_str)
+        });
+        let haystack = [self.code.as_str(), &self.name, &self.description]
+            .into_iter()
+            .chain(self.tags.iter().map(String::as_str))
+            .chain(translated)
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        query
+            .split_whitespace()
+            .all(|word| haystack.contains(&word.to_lowercase()))
+    }
+
+    /// Check whether a launcher with `launcher_version` is new enough to install the game.
+    pub fn is_supported(&self, launcher_version: &str) -> bool {
+        self.min_launcher_version.as_deref().is_none_or(|required| {
+            match (parse_version(required), parse_version(launcher_version)) {
+                (Some(required), Some(current)) => current >= required,
+                _ => false,
+            }
+        })
+    }
+}
+
+/// Parse a version of the form `major.minor.patch` where trailing components are optional.
+pub(crate) fn parse_version(version: &str) -> Option<[u64; 3]> {
+    let mut parsed = [0; 3];
+    for (i, component) in version.trim().split('.').enumerate() {
+        *parsed.get_mut(i)? = component.parse().ok()?;
+    }
+    Some(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::path::PathBuf;
+
+    /// Set this environment variable to update the catalog in `resources`.
+    const UPDATE_ENV_VAR: &str = "AIGL_UPDATE_CATALOG";
+
+    fn games_dir() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../resources/games")
+    }
+
+    fn entry(name: &str, tags: &[&str]) -> CatalogEntry {
+        CatalogEntry {
+            code: name.to_lowercase().replace(' ', "_"),
+            name: name.into(),
+            description: "Bots fight for territory.".into(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            min_launcher_version: None,
+            translations: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn checked_in_catalog_is_up_to_date() {
+        let path = games_dir().join(CATALOG_FILE_NAME);
+        let catalog = Catalog::from_game_dir(&games_dir()).unwrap();
+        if std::env::var_os(UPDATE_ENV_VAR).is_some() {
+            std::fs::write(&path, catalog.to_toml_string().unwrap()).unwrap();
+            return;
+        }
+        let checked_in = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| Catalog::from_toml_str(&content).ok());
+        assert!(
+            checked_in.as_ref() == Some(&catalog),
+            "{} is stale, run the tests with {UPDATE_ENV_VAR}=1 or `aigl catalog`",
+            path.display(),
+        );
+    }
+
+    #[test]
+    fn catalog_round_trips_through_toml() {
+        let catalog = Catalog {
+            games: vec![entry("Territory", &["grid"]), entry("Race", &[])],
+        };
+        let toml = catalog.to_toml_string().unwrap();
+        assert_eq!(Catalog::from_toml_str(&toml).unwrap(), catalog);
+    }
+
+    #[test]
+    fn merge_keeps_first_game_with_a_code() {
+        let mut catalog = Catalog {
+            games: vec![entry("Territory", &["private"])],
+        };
+        catalog.merge(Catalog {
+            games: vec![entry("Territory", &["public"]), entry("Race", &[])],
+        });
+        assert_eq!(
+            catalog.games,
+            vec![entry("Territory", &["private"]), entry("Race", &[])]
+        );
+    }
+
+    #[test]
+    fn search_matches_all_words_case_insensitively() {
+        let game = entry("Territory Wars", &["grid", "teams"]);
+        assert!(game.matches(""));
+        assert!(game.matches("territory"));
+        assert!(game.matches("WARS grid"));
+        assert!(game.matches("fight"));
+        assert!(!game.matches("territory race"));
+    }
+
+    #[test]
+    fn translated_names_are_shown_and_searched() {
+        let mut game = entry("Territory", &[]);
+        game.translations.insert(
+            "de".into(),
+            CatalogTranslation {
+                name: Some("Territorium".into()),
+                description: None,
+            },
+        );
+        assert_eq!(game.localized_name(&["de-CH"]), "Territorium");
+        assert_eq!(game.localized_name(&["en"]), "Territory");
+        assert_eq!(game.localized_description(&["de"]), game.description);
+        assert!(game.matches("territorium"));
+    }
+
+    #[test]
+    fn required_launcher_version_is_compared_numerically() {
+        let mut game = entry("Race", &[]);
+        assert!(game.is_supported("0.1.0"));
+        game.min_launcher_version = Some("0.0.1".into());
+        assert!(game.is_supported("0.1.0"));
+        game.min_launcher_version = Some("0.10".into());
+        assert!(game.is_supported("0.10.0"));
+        assert!(!game.is_supported("0.9.9"));
+        game.min_launcher_version = Some("not a version".into());
+        assert!(!game.is_supported("0.1.0"));
+    }
+
+    #[test]
+    fn parse_version_fills_missing_components() {
+        assert_eq!(parse_version("1.2.3"), Some([1, 2, 3]));
+        assert_eq!(parse_version("0.10"), Some([0, 10, 0]));
+        assert_eq!(parse_version("1.2.3.4"), None);
+        assert_eq!(parse_version("1.x"), None);
+    }
+}
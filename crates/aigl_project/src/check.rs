@@ -0,0 +1,511 @@
+use anyhow::{Result, bail};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::Project;
+use crate::config;
+use crate::config::project::ProjectConfig;
+
+/// A problem found by [`Project::check`].
+#[derive(Clone, Debug)]
+pub struct Finding {
+    pub severity: Severity,
+    pub message: String,
+    pub fix: Option<Fix>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// An automatic repair for a [`Finding`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Fix {
+    /// Delete the virtual environment, create a new one, and reinstall all packages.
+    RecreateVenv,
+    /// Reinstall the game and all bots as editable packages.
+    ReinstallPackages,
+    /// Rewrite the paths in `project.json` relative to the current project directory.
+    RelocatePaths,
+    /// Remove `[[player]]` entries with the given packages from the game config.
+    RemoveUnknownPlayers(Vec<String>),
+}
+
+impl Finding {
+    fn error(message: impl Into<String>, fix: Option<Fix>) -> Self {
+        Self {
+            severity: Severity::Error,
+            message: message.into(),
+            fix,
+        }
+    }
+
+    fn warning(message: impl Into<String>, fix: Option<Fix>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            message: message.into(),
+            fix,
+        }
+    }
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(f, "{severity}: {}", self.message)
+    }
+}
+
+impl Fix {
+    pub fn description(&self) -> String {
+        match self {
+            Self::RecreateVenv => "Re-create the virtual environment".into(),
+            Self::ReinstallPackages => "Reinstall the game and bots".into(),
+            Self::RelocatePaths => "Update paths to the current project location".into(),
+            Self::RemoveUnknownPlayers(packages) => {
+                format!("Remove players using {}", packages.join(", "))
+            }
+        }
+    }
+}
+
+impl Project {
+    /// Check the installation for common problems.
+    ///
+    /// This does not modify the project.
+    /// Use [`Project::fix`] to apply the fixes attached to the findings.
+    pub async fn check(&self) -> Vec<Finding> {
+        let mut findings = self.check_paths();
+        if findings.iter().any(|f| f.fix == Some(Fix::RelocatePaths)) {
+            // All further checks would fail because of the wrong paths.
+            return findings;
+        }
+        findings.extend(self.check_venv().await);
+        findings.extend(self.check_player_config().await);
+        findings
+    }
+
+    /// Apply an automatic fix returned by [`Project::check`].
+    pub async fn fix(&mut self, fix: &Fix) -> Result<()> {
+        match fix {
+            Fix::RecreateVenv => {
+                if let Ok(venv_path) = self.venv_path() {
+                    if venv_path.exists() {
+                        tokio::fs::remove_dir_all(&venv_path).await?;
+                    }
+                }
+                self.create_venv().await?;
+            }
+            Fix::ReinstallPackages => {
+                let venv = self.venv()?;
+                self.install_packages(&venv).await?;
+            }
+            Fix::RelocatePaths => self.relocate_paths()?,
+            Fix::RemoveUnknownPlayers(packages) => self.remove_players(packages).await?,
+        }
+        self.save_config().await
+    }
+
+    fn check_paths(&self) -> Vec<Finding> {
+        check_paths(self.root(), self.cfg())
+    }
+
+    async fn check_venv(&self) -> Vec<Finding> {
+        let venv_path = match self.venv_path() {
+            Ok(path) => path,
+            Err(err) => {
+                return vec![Finding::error(
+                    format!("No virtual environment: {err}"),
+                    Some(Fix::RecreateVenv),
+                )];
+            }
+        };
+        if !venv_path.starts_with(self.root()) {
+            return vec![Finding::error(
+                format!(
+                    "The virtual environment {} is outside of the project directory",
+                    venv_path.display()
+                ),
+                Some(Fix::RelocatePaths),
+            )];
+        }
+        let venv = match self.venv() {
+            Ok(venv) => venv,
+            Err(err) => {
+                return vec![Finding::error(
+                    format!(
+                        "Failed to open the virtual environment at {}: {err}",
+                        venv_path.display()
+                    ),
+                    Some(Fix::RecreateVenv),
+                )];
+            }
+        };
+        let python = venv.python_executable();
+        if !python.exists() {
+            return vec![Finding::error(
+                format!("The Python interpreter {} does not exist", python.display()),
+                Some(Fix::RecreateVenv),
+            )];
+        }
+
+        let mut findings = Vec::new();
+        let mut modules = self.bot_modules();
+        match self.game_module().await {
+            Ok(module) => {
+                modules.insert(module, self.cfg().game_path.clone());
+            }
+            Err(err) => findings.push(Finding::error(
+                format!("Cannot determine the module of the game: {err}"),
+                None,
+            )),
+        }
+        let origins = match self.find_module_origins(modules.keys()).await {
+            Ok(origins) => origins,
+            Err(err) => {
+                return vec![Finding::error(
+                    format!(
+                        "The Python interpreter {} does not run: {err}",
+                        python.display()
+                    ),
+                    Some(Fix::RecreateVenv),
+                )];
+            }
+        };
+
+        for (module, expected_dir) in &modules {
+            match origins.get(module).cloned().flatten() {
+                None => findings.push(Finding::error(
+                    format!("Package '{module}' is not installed"),
+                    Some(Fix::ReinstallPackages),
+                )),
+                Some(origin) if !is_in_dir(&origin, expected_dir) => {
+                    findings.push(Finding::warning(
+                        format!(
+                            "Package '{module}' is imported from {} instead of {}",
+                            origin.display(),
+                            expected_dir.display()
+                        ),
+                        Some(Fix::ReinstallPackages),
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+        findings
+    }
+
+    async fn check_player_config(&self) -> Vec<Finding> {
+        let config_path = match self.player_config_path() {
+            Ok(path) => path,
+            Err(err) => return vec![Finding::error(err.to_string(), None)],
+        };
+        let config = match read_toml(&config_path).await {
+            Ok(config) => config,
+            Err(err) => {
+                return vec![Finding::error(
+                    format!(
+                        "Failed to read game config {}: {err}",
+                        config_path.display()
+                    ),
+                    None,
+                )];
+            }
+        };
+
+        let bot_ids = self.bot_ids();
+        let unknown: Vec<_> = player_packages(&config)
+            .into_iter()
+            .filter(|package| !bot_ids.contains(package))
+            .collect();
+        if unknown.is_empty() {
+            Vec::new()
+        } else {
+            vec![Finding::error(
+                format!(
+                    "The game config {} uses unknown bots: {}",
+                    config_path.display(),
+                    unknown.join(", ")
+                ),
+                Some(Fix::RemoveUnknownPlayers(unknown)),
+            )]
+        }
+    }
+
+    /// Map from the bots' module names to the directory they should be loaded from.
+    fn bot_modules(&self) -> HashMap<String, PathBuf> {
+        self.cfg()
+            .bot_paths
+            .iter()
+            .filter_map(|path| {
+                let id = path.file_name()?.to_str()?.to_owned();
+                Some((id, path.clone()))
+            })
+            .collect()
+    }
+
+    /// The module that the game is imported as, read from the game package's `pyproject.toml`.
+    async fn game_module(&self) -> Result<String> {
+        let pyproject_path = self.cfg().game_path.join("pyproject.toml");
+        let pyproject = read_toml(&pyproject_path).await?;
+        let Some(module) = module_from_pyproject(&pyproject) else {
+            bail!("{} has no project name", pyproject_path.display());
+        };
+        Ok(module)
+    }
+
+    fn relocate_paths(&mut self) -> Result<()> {
+        let Some(old_root) = previous_root(self.cfg()) else {
+            bail!("Cannot determine the previous project location");
+        };
+        let new_root = self.root().to_path_buf();
+        let relocate = |path: &mut PathBuf| {
+            if let Ok(relative) = path.strip_prefix(&old_root) {
+                *path = new_root.join(relative);
+            }
+        };
+
+        let cfg = self.cfg_mut();
+        relocate(&mut cfg.game_path);
+        relocate(&mut cfg.bot_template_path);
+        cfg.bot_paths.iter_mut().for_each(relocate);
+        cfg.venv_paths.values_mut().for_each(relocate);
+        Ok(())
+    }
+
+    async fn remove_players(&self, packages: &[String]) -> Result<()> {
+        let config_path = self.player_config_path()?;
+        let mut config = read_toml(&config_path).await?;
+        if let Some(players) = config
+            .get_mut("player")
+            .and_then(|item| item.as_array_of_tables_mut())
+        {
+            players.retain(|table| {
+                table
+                    .get("package")
+                    .and_then(|package| package.as_str())
+                    .is_none_or(|package| !packages.iter().any(|p| p == package))
+            });
+        }
+        tokio::fs::write(&config_path, config.to_string()).await?;
+        Ok(())
+    }
+
+    async fn find_module_origins(
+        &self,
+        modules: impl IntoIterator<Item = &String>,
+    ) -> Result<HashMap<String, Option<PathBuf>>> {
+        let mut command = tokio::process::Command::from(self.python_command()?);
+        command.arg("-c").arg(FIND_MODULES_SCRIPT).args(modules);
+        let output = command.output().await?;
+        if !output.status.success() {
+            bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+        }
+        Ok(serde_json::from_slice(&output.stdout)?)
+    }
+}
+
+/// Report every stored path that is not inside `root` or does not exist.
+fn check_paths(root: &Path, cfg: &ProjectConfig) -> Vec<Finding> {
+    let paths = std::iter::once(("game", &cfg.game_path))
+        .chain(std::iter::once(("bot template", &cfg.bot_template_path)))
+        .chain(cfg.bot_paths.iter().map(|path| ("bot", path)));
+    let mut findings = Vec::new();
+    for (kind, path) in paths {
+        if !path.starts_with(root) {
+            findings.push(Finding::error(
+                format!(
+                    "The {kind} path {} is outside of the project directory {}",
+                    path.display(),
+                    root.display()
+                ),
+                Some(Fix::RelocatePaths),
+            ));
+        } else if !path.exists() {
+            findings.push(Finding::error(
+                format!("The {kind} directory {} does not exist", path.display()),
+                None,
+            ));
+        }
+    }
+    findings
+}
+
+/// The project directory that the paths in `cfg` were stored for.
+///
+/// The bot template is always cloned to the same place in the project,
+/// bots are rendered directly into the project directory.
+fn previous_root(cfg: &ProjectConfig) -> Option<PathBuf> {
+    let template_dir = config::bot_template_repo_dir(Path::new(""));
+    strip_path_suffix(&cfg.bot_template_path, &template_dir).or_else(|| {
+        let bot_path = cfg.bot_paths.first()?;
+        let bot_id = bot_path.file_name()?.to_str()?;
+        strip_path_suffix(bot_path, &config::bot_dir(Path::new(""), bot_id))
+    })
+}
+
+fn strip_path_suffix(path: &Path, suffix: &Path) -> Option<PathBuf> {
+    if !path.ends_with(suffix) {
+        return None;
+    }
+    let mut prefix = path.to_path_buf();
+    for _ in suffix.components() {
+        prefix.pop();
+    }
+    Some(prefix)
+}
+
+/// The module name of a Python package, by convention the normalized project name.
+fn module_from_pyproject(pyproject: &toml_edit::DocumentMut) -> Option<String> {
+    let name = pyproject.get("project")?.get("name")?.as_str()?;
+    Some(name.replace(['-', '.'], "_").to_lowercase())
+}
+
+const FIND_MODULES_SCRIPT: &str = r#"
+import importlib.util, json, sys
+origins = {}
+for name in sys.argv[1:]:
+    try:
+        spec = importlib.util.find_spec(name)
+    except Exception:
+        spec = None
+    origins[name] = None if spec is None else spec.origin
+print(json.dumps(origins))
+"#;
+
+fn is_in_dir(path: &Path, dir: &Path) -> bool {
+    match (path.canonicalize(), dir.canonicalize()) {
+        (Ok(path), Ok(dir)) => path.starts_with(dir),
+        _ => path.starts_with(dir),
+    }
+}
+
+async fn read_toml(path: &Path) -> Result<toml_edit::DocumentMut> {
+    Ok(tokio::fs::read_to_string(path)
+        .await?
+        .parse::<toml_edit::DocumentMut>()?)
+}
+
+fn player_packages(config: &toml_edit::DocumentMut) -> Vec<String> {
+    config
+        .get("player")
+        .and_then(|item| item.as_array_of_tables())
+        .map(|players| {
+            players
+                .iter()
+                .filter_map(|table| Some(table.get("package")?.as_str()?.to_owned()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn player_packages_are_read_from_player_tables() {
+        let config = r##"
+speed = 3
+
+[[player]]
+package = "bot_me"
+
+[[player]]
+package = "template_bot"
+overrides = { name = "Botulf", color = "#3a4cd5" }
+"##
+        .parse::<toml_edit::DocumentMut>()
+        .unwrap();
+        assert_eq!(
+            player_packages(&config),
+            vec!["bot_me".to_string(), "template_bot".to_string()]
+        );
+    }
+
+    #[test]
+    fn player_packages_without_players_is_empty() {
+        let config = "speed = 3".parse::<toml_edit::DocumentMut>().unwrap();
+        assert!(player_packages(&config).is_empty());
+    }
+
+    fn project_config(root: &Path) -> ProjectConfig {
+        let game_config = crate::config::game::GameConfig::load_toml_str(
+            r#"
+name = "Planets"
+
+[game]
+url = "https://example.com/planets.git"
+base_config_in_repo = "config/config.toml"
+launch_args = ["planets", "{config}"]
+
+[bot]
+template_url = "https://example.com/planets-bot.git"
+
+[players]
+mode = "free-for-all"
+n_min = 2
+
+[python]
+version = "3.13"
+"#,
+        )
+        .unwrap();
+        ProjectConfig {
+            schema_version: config::project::PROJECT_CONFIG_VERSION,
+            game_config,
+            game_path: root.join("Planets"),
+            bot_paths: vec![root.join("bot_me"), root.join("template_bot")],
+            bot_template_path: config::bot_template_repo_dir(root),
+            venv_paths: HashMap::from([("game".into(), root.join(".venv"))]),
+        }
+    }
+
+    #[test]
+    fn every_path_outside_of_the_project_is_reported() {
+        let cfg = project_config(Path::new("/old/project"));
+        let findings = check_paths(Path::new("/new/project"), &cfg);
+        assert_eq!(findings.len(), 4);
+        assert!(
+            findings
+                .iter()
+                .all(|finding| finding.fix == Some(Fix::RelocatePaths))
+        );
+    }
+
+    #[test]
+    fn previous_root_is_found_from_the_bot_template_path() {
+        let mut cfg = project_config(Path::new("/old/project"));
+        cfg.game_path = PathBuf::from("/somewhere/else/Planets");
+        assert_eq!(previous_root(&cfg), Some(PathBuf::from("/old/project")));
+    }
+
+    #[test]
+    fn previous_root_falls_back_to_the_bot_paths() {
+        let mut cfg = project_config(Path::new("/old/project"));
+        cfg.bot_template_path = PathBuf::from("/somewhere/else/template");
+        assert_eq!(previous_root(&cfg), Some(PathBuf::from("/old/project")));
+    }
+
+    #[test]
+    fn game_module_is_the_normalized_project_name() {
+        let pyproject = "[project]\nname = \"Planets-Game\"\n"
+            .parse::<toml_edit::DocumentMut>()
+            .unwrap();
+        assert_eq!(
+            module_from_pyproject(&pyproject),
+            Some("planets_game".to_string())
+        );
+        let pyproject = "[tool.ruff]\nline-length = 100\n"
+            .parse::<toml_edit::DocumentMut>()
+            .unwrap();
+        assert_eq!(module_from_pyproject(&pyproject), None);
+    }
+}
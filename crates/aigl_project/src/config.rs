@@ -7,9 +7,25 @@ pub const LAUNCHER_DIR_NAME: &str = ".aigl";
 pub const PYTHON_DIR_NAME: &str = "python";
 pub const UV_CACHE_DIR_NAME: &str = "uv_cache";
 pub const BOT_TEMPLATES_DIR_NAME: &str = "bot_templates";
+pub const TEST_RESULTS_DIR_NAME: &str = "test_results";
+pub const LOGS_DIR_NAME: &str = "logs";
+/// Directory in the system's temporary directory for logs that cannot be written into a project.
+pub const TEMP_LOGS_DIR_NAME: &str = "aigl-logs";
 
 pub const PROJECT_CONFIG_FILE_NAME: &str = "project.json";
 
+/// Optional manifest in the root of a bot template repository.
+pub const TEMPLATE_MANIFEST_FILE_NAME: &str = "aigl-template.toml";
+
+pub const USER_CONFIG_DIR_NAME: &str = "aigl";
+pub const USER_CONFIG_FILE_NAME: &str = "config.toml";
+/// List of installed projects in the user config directory.
+pub const PROJECT_REGISTRY_FILE_NAME: &str = "projects.toml";
+/// Settings that the launcher remembers between runs, in the user config directory.
+pub const PREFERENCES_FILE_NAME: &str = "preferences.toml";
+/// Directory in the user config directory with `.ron` themes for the GUI.
+pub const USER_THEMES_DIR_NAME: &str = "themes";
+
 pub(crate) fn launcher_dir(project_root: &Path) -> PathBuf {
     project_root.join(LAUNCHER_DIR_NAME)
 }
@@ -26,31 +42,55 @@ pub(crate) fn bot_templates_dir(project_root: &Path) -> PathBuf {
     launcher_dir(project_root).join(BOT_TEMPLATES_DIR_NAME)
 }
 
+/// Where the bot template repository is cloned to.
+pub(crate) fn bot_template_repo_dir(project_root: &Path) -> PathBuf {
+    bot_templates_dir(project_root).join("template")
+}
+
+/// Where a bot is rendered to, the directory name is the bot's package name.
+pub(crate) fn bot_dir(project_root: &Path, bot_id: &str) -> PathBuf {
+    project_root.join(bot_id)
+}
+
+pub(crate) fn test_results_dir(project_root: &Path) -> PathBuf {
+    launcher_dir(project_root).join(TEST_RESULTS_DIR_NAME)
+}
+
+pub(crate) fn logs_dir(project_root: &Path) -> PathBuf {
+    launcher_dir(project_root).join(LOGS_DIR_NAME)
+}
+
 pub(crate) fn project_config_file(project_root: &Path) -> PathBuf {
     launcher_dir(project_root).join(PROJECT_CONFIG_FILE_NAME)
 }
 
-/// Initialise the environment for the given project directory.
-///
-/// # Safety
-///
-/// This function sets environment variables and is only safe when called
-/// in single-threaded code.
-pub unsafe fn init_environment(project_root: &Path) {
-    let launcher_root = launcher_dir(project_root);
-    unsafe {
-        // Required to control where Python is installed.
-        std::env::set_var("UV_PYTHON_INSTALL_DIR", python_dir(&launcher_root));
-        // Should be unused, but setting it here for good measure.
-        std::env::set_var("UV_CACHE_DIR", uv_cache_dir(&launcher_root));
-    }
+/// Directory for settings that apply to all projects of the current user.
+pub fn user_config_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join(USER_CONFIG_DIR_NAME))
+}
+
+/// Directory with themes that the user has added.
+pub fn user_themes_dir() -> Option<PathBuf> {
+    user_config_dir().map(|dir| dir.join(USER_THEMES_DIR_NAME))
 }
 
 pub mod project {
     use super::*;
+    use anyhow::{Context, bail};
+    use serde_json::Value;
+
+    /// Version of the `project.json` format written by this launcher.
+    ///
+    /// Increment this when changing [`ProjectConfig`] and add a migration to [`MIGRATIONS`].
+    pub const PROJECT_CONFIG_VERSION: u32 = 1;
+
+    /// `MIGRATIONS[i]` upgrades a project config from version `i` to `i + 1`.
+    const MIGRATIONS: [fn(&mut Value) -> Result<()>; PROJECT_CONFIG_VERSION as usize] =
+        [migrate_v0_to_v1];
 
     #[derive(Clone, Debug, Serialize, Deserialize)]
     pub struct ProjectConfig {
+        pub schema_version: u32,
         pub game_config: game::GameConfig,
         pub game_path: PathBuf,
         // The first bot is the player
@@ -60,10 +100,37 @@ pub mod project {
     }
 
     impl ProjectConfig {
+        /// Load a project config and upgrade it to the current version if needed.
+        ///
+        /// The original file is kept as a backup when it gets upgraded.
         pub async fn load_json(path: &Path) -> Result<Self> {
-            Ok(serde_json::from_str(
-                &tokio::fs::read_to_string(path).await?,
-            )?)
+            let value: Value = serde_json::from_str(&tokio::fs::read_to_string(path).await?)?;
+            let (cfg, old_version) = Self::upgrade(value)
+                .with_context(|| format!("Failed to load project config {}", path.display()))?;
+            if let Some(old_version) = old_version {
+                tokio::fs::copy(path, backup_path(path, old_version)).await?;
+                cfg.save_json(path).await?;
+            }
+            Ok(cfg)
+        }
+
+        /// Apply all necessary migrations.
+        ///
+        /// Returns the config and the original version if it was migrated.
+        fn upgrade(mut value: Value) -> Result<(Self, Option<u32>)> {
+            let version = schema_version(&value)?;
+            if version > PROJECT_CONFIG_VERSION {
+                bail!(
+                    "The project was created by a newer version of the launcher \
+                    (format version {version}, this launcher supports up to version \
+                    {PROJECT_CONFIG_VERSION}). Please update the launcher."
+                );
+            }
+            for migration in &MIGRATIONS[version as usize..] {
+                migration(&mut value)?;
+            }
+            let old_version = (version < PROJECT_CONFIG_VERSION).then_some(version);
+            Ok((serde_json::from_value(value)?, old_version))
         }
 
         pub async fn save_json(&self, path: &Path) -> Result<()> {
@@ -71,40 +138,248 @@ pub mod project {
             Ok(())
         }
     }
+
+    fn schema_version(value: &Value) -> Result<u32> {
+        match value.get("schema_version") {
+            // Files written before versioning was introduced.
+            None => Ok(0),
+            Some(version) => version
+                .as_u64()
+                .and_then(|version| u32::try_from(version).ok())
+                .ok_or_else(|| anyhow::anyhow!("Invalid schema_version: {version}")),
+        }
+    }
+
+    fn backup_path(path: &Path, version: u32) -> PathBuf {
+        let mut file_name = path.file_name().unwrap_or_default().to_owned();
+        file_name.push(format!(".v{version}.bak"));
+        path.with_file_name(file_name)
+    }
+
+    fn migrate_v0_to_v1(value: &mut Value) -> Result<()> {
+        let Some(object) = value.as_object_mut() else {
+            bail!("Project config is not a JSON object");
+        };
+        object.insert("schema_version".into(), 1.into());
+        Ok(())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use pretty_assertions::assert_eq;
+        use serde_json::json;
+
+        fn v0_config() -> Value {
+            json!({
+                "game_config": {
+                    "name": "Test",
+                    "game": {
+                        "url": "https://example.com/game.git",
+                        "base_config_in_repo": "config.toml",
+                        "launch_args": ["game", "{config}"]
+                    },
+                    "bot": {"template_url": "https://example.com/bot.git"},
+                    "players": {"mode": "free-for-all", "n_min": 2},
+                    "python": {"version": "3.13"}
+                },
+                "game_path": "/game/Test",
+                "bot_paths": ["/game/bot_me"],
+                "bot_template_path": "/game/.aigl/bot_templates/template",
+                "venv_paths": {"game": "/game/.venv"}
+            })
+        }
+
+        #[test]
+        fn upgrade_unversioned_config() {
+            let (cfg, old_version) = ProjectConfig::upgrade(v0_config()).unwrap();
+            assert_eq!(old_version, Some(0));
+            assert_eq!(cfg.schema_version, PROJECT_CONFIG_VERSION);
+            assert_eq!(cfg.bot_paths, vec![PathBuf::from("/game/bot_me")]);
+        }
+
+        #[test]
+        fn upgrade_current_config_is_noop() {
+            let mut value = v0_config();
+            value["schema_version"] = PROJECT_CONFIG_VERSION.into();
+            let (cfg, old_version) = ProjectConfig::upgrade(value).unwrap();
+            assert_eq!(old_version, None);
+            assert_eq!(cfg.schema_version, PROJECT_CONFIG_VERSION);
+        }
+
+        #[test]
+        fn upgrade_refuses_newer_config() {
+            let mut value = v0_config();
+            value["schema_version"] = (PROJECT_CONFIG_VERSION + 1).into();
+            let err = ProjectConfig::upgrade(value).unwrap_err();
+            assert!(err.to_string().contains("newer version of the launcher"));
+        }
+
+        #[test]
+        fn backup_path_includes_version() {
+            assert_eq!(
+                backup_path(Path::new("/game/.aigl/project.json"), 0),
+                PathBuf::from("/game/.aigl/project.json.v0.bak")
+            );
+        }
+
+        #[tokio::test]
+        async fn load_json_keeps_backup_of_migrated_file() {
+            let dir =
+                std::env::temp_dir().join(format!("aigl-test-migration-{}", std::process::id()));
+            std::fs::create_dir_all(&dir).unwrap();
+            let path = dir.join("project.json");
+            let original = v0_config().to_string();
+            std::fs::write(&path, &original).unwrap();
+
+            let cfg = ProjectConfig::load_json(&path).await.unwrap();
+            assert_eq!(cfg.schema_version, PROJECT_CONFIG_VERSION);
+            assert_eq!(
+                std::fs::read_to_string(backup_path(&path, 0)).unwrap(),
+                original
+            );
+            let saved: Value =
+                serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+            assert_eq!(saved["schema_version"], json!(PROJECT_CONFIG_VERSION));
+
+            std::fs::remove_dir_all(&dir).unwrap();
+        }
+    }
 }
 
 pub mod game {
     use super::*;
+    use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+    use std::borrow::Cow;
+    use std::collections::BTreeMap;
 
-    #[derive(Clone, Debug, Serialize, Deserialize)]
+    /// Configuration of a game that can be installed by the launcher.
+    #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
     pub struct GameConfig {
+        /// Display name of the game, also used as the name of the game directory.
         pub name: String,
         pub game: Game,
         pub bot: Bot,
         pub players: Players,
         pub python: Python,
+        /// How the game is presented in the game catalog.
+        #[serde(default)]
+        pub catalog: CatalogInfo,
+        /// Texts shown to players in other languages by language code, e.g., "de" or "pt-BR".
+        #[serde(default)]
+        pub translations: BTreeMap<String, Translation>,
     }
 
-    #[derive(Clone, Debug, Serialize, Deserialize)]
+    /// Texts of a game config in one language, missing texts fall back to the originals.
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+    pub struct Translation {
+        /// Display name of the game, the game directory keeps the original name.
+        pub name: Option<String>,
+        /// Short description shown in the game list.
+        pub description: Option<String>,
+        /// Texts of template args by their key in `bot.template_args`.
+        #[serde(default)]
+        pub template_args: BTreeMap<String, TemplateArgTranslation>,
+    }
+
+    #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+    pub struct TemplateArgTranslation {
+        /// Label shown to players.
+        pub display: Option<String>,
+        /// Longer explanation shown as a tooltip.
+        pub description: Option<String>,
+    }
+
+    #[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
+    pub struct CatalogInfo {
+        /// Short description shown in the game list.
+        #[serde(default)]
+        pub description: String,
+        /// Keywords for searching the game list.
+        #[serde(default)]
+        pub tags: Vec<String>,
+        /// Oldest launcher version that can install the game, e.g., "0.2.0".
+        pub min_launcher_version: Option<String>,
+        /// Leave the game out of the catalog; it can still be installed by its code.
+        #[serde(default)]
+        pub unlisted: bool,
+    }
+
+    #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
     pub struct Game {
+        /// Git URL of the game repository.
         pub url: String,
+        /// Path of the game's config file relative to the repository root.
         pub base_config_in_repo: PathBuf,
+        /// Arguments passed to `python -m`, '{config}' is replaced by the config file.
         pub launch_args: Vec<String>,
+        /// Full hash of the commit to check out instead of the default branch.
+        pub commit: Option<String>,
     }
 
-    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
     pub struct Bot {
+        /// Git URL of the bot template repository.
         pub template_url: String,
+        /// Full hash of the commit of the template to check out instead of the default branch.
+        pub template_commit: Option<String>,
+        /// Arguments that players choose when creating a bot.
+        ///
+        /// `id` and `name` are always present and must not be redefined.
         #[serde(default)]
         pub template_args: HashMap<String, BotTemplateArg>,
+        /// How the files of the template are rendered for a new bot.
+        #[serde(default)]
+        pub render: RenderConfig,
     }
 
-    #[derive(Clone, Debug, Serialize, Deserialize)]
+    /// Placeholders like `{{ bot_id }}`, `{{ bot_name }}`, and `{{ <arg var> }}` are replaced
+    /// in the contents of the selected files and in all file and directory names.
+    ///
+    /// Paths are relative to the template root and use `/` as separator.
+    #[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
+    pub struct RenderConfig {
+        /// Glob patterns of files whose contents are rendered, e.g., `**/*.py`.
+        #[serde(default)]
+        pub files: Vec<String>,
+        /// Files and directories that are only kept if a condition holds.
+        #[serde(default)]
+        pub conditional: Vec<ConditionalPath>,
+    }
+
+    #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+    pub struct ConditionalPath {
+        /// Glob pattern of the files or directories, matched against rendered names.
+        pub path: String,
+        /// Variable that must be true or non-empty, prefix with `!` to negate.
+        ///
+        /// Can also be an arg of the template's `aigl-template.toml`,
+        /// unknown variables are reported when a bot is created.
+        pub when: String,
+    }
+
+    #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
     pub struct BotTemplateArg {
+        /// Key in the bot's `config.toml`.
         pub var: String,
+        /// Label shown to players.
         pub display: String,
         #[serde(default, rename = "type")]
         pub ty: BotTemplateArgType,
+        /// Longer explanation shown as a tooltip.
+        pub description: Option<String>,
+        /// Players must provide a non-empty value.
+        #[serde(default)]
+        pub required: bool,
+        /// Initial value, must match the type.
+        pub default: Option<BotTemplateArgDefault>,
+        /// Smallest allowed value of `integer` and `float` args.
+        pub min: Option<f64>,
+        /// Largest allowed value of `integer` and `float` args.
+        pub max: Option<f64>,
+        /// Allowed values of `choice` args.
+        #[serde(default)]
+        pub options: Vec<String>,
     }
 
     #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -116,19 +391,62 @@ pub mod game {
         Color,
         #[serde(rename = "path")]
         Path,
+        #[serde(rename = "integer")]
+        Integer,
+        #[serde(rename = "float")]
+        Float,
+        #[serde(rename = "bool")]
+        Bool,
+        /// One of the values in `options`.
+        #[serde(rename = "choice")]
+        Choice,
     }
 
-    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+    #[serde(untagged)]
+    pub enum BotTemplateArgDefault {
+        Bool(bool),
+        Integer(i64),
+        Float(f64),
+        String(String),
+    }
+
+    // Implemented manually because schemars does not include serde aliases.
+    // `schema::tests` checks that the list of types stays in sync with the variants.
+    impl JsonSchema for BotTemplateArgType {
+        fn schema_name() -> Cow<'static, str> {
+            "BotTemplateArgType".into()
+        }
+
+        fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+            json_schema!({
+                "description": "Type of the argument, determines the input widget.",
+                "type": "string",
+                "enum": [
+                    "string", "color", "colour", "path", "integer", "float", "bool", "choice"
+                ],
+                "default": "string",
+            })
+        }
+    }
+
+    /// How many bots play in a match and how they are grouped.
+    #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
     #[serde(tag = "mode")]
     pub enum Players {
+        /// Every bot plays for itself.
         #[serde(rename = "free-for-all")]
         FFA {
+            /// Minimum number of bots in a match.
             n_min: usize,
+            /// Maximum number of bots in a match.
             #[serde(default)]
             n_max: Option<usize>,
+            /// Number of bots in the initial game config, defaults to `n_min`.
             #[serde(default)]
             n_initial: Option<usize>,
         },
+        /// Bots play in teams.
         #[serde(rename = "teams")]
         Teams {
             n_teams_min: usize,
@@ -144,18 +462,22 @@ pub mod game {
         },
     }
 
-    #[derive(Clone, Debug, Serialize, Deserialize)]
+    #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
     pub struct Python {
+        /// Python version to install, e.g., "3.13".
         pub version: String,
         #[serde(default)]
         pub venv: VenvKind,
     }
 
-    #[derive(Clone, Debug, Default, Serialize, Deserialize)]
+    /// Which bots share a virtual environment.
+    #[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
     pub enum VenvKind {
+        /// One environment for the game and all bots.
         #[default]
         #[serde(rename = "single")]
         Single,
+        /// A separate environment for each bot.
         #[serde(rename = "per-bot")]
         PerBot,
     }
@@ -173,5 +495,217 @@ pub mod game {
             tokio::fs::write(path, toml_edit::ser::to_string(self)?).await?;
             Ok(())
         }
+
+        /// The display name of the game in the first of `languages` that it is translated to.
+        pub fn localized_name(&self, languages: &[impl AsRef<str>]) -> &str {
+            find_translation(&self.translations, languages)
+                .and_then(|translation| translation.name.as_deref())
+                .unwrap_or(&self.name)
+        }
+
+        /// The template args with their labels and descriptions in the first of `languages`
+        /// that the game is translated to.
+        pub fn localized_template_args(
+            &self,
+            languages: &[impl AsRef<str>],
+        ) -> Vec<BotTemplateArg> {
+            let translation = find_translation(&self.translations, languages);
+            let mut args: Vec<_> = self.bot.template_args.iter().collect();
+            // Keep the order stable between languages.
+            args.sort_by_key(|(key, _)| *key);
+            args.into_iter()
+                .map(|(key, arg)| {
+                    let mut arg = arg.clone();
+                    if let Some(text) =
+                        translation.and_then(|translation| translation.template_args.get(key))
+                    {
+                        if let Some(display) = &text.display {
+                            arg.display = display.clone();
+                        }
+                        if let Some(description) = &text.description {
+                            arg.description = Some(description.clone());
+                        }
+                    }
+                    arg
+                })
+                .collect()
+        }
+    }
+
+    /// Look up the translation for the first of `languages` that has one.
+    ///
+    /// Each language falls back to its primary language, e.g., "de" for "de-AT".
+    pub fn find_translation<'a, T>(
+        translations: &'a BTreeMap<String, T>,
+        languages: &[impl AsRef<str>],
+    ) -> Option<&'a T> {
+        languages.iter().find_map(|language| {
+            let language = language.as_ref();
+            translations.get(language).or_else(|| {
+                let (primary, _) = language.split_once('-')?;
+                translations.get(primary)
+            })
+        })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        const CONFIG: &str = r#"
+name = "Planets"
+
+[game]
+url = "https://example.com/planets.git"
+base_config_in_repo = "config.toml"
+launch_args = ["planets", "{config}"]
+
+[bot]
+template_url = "https://example.com/planets-bot.git"
+
+[bot.template_args]
+color = { type = "color", display = "Color", var = "BOT_COLOR" }
+speed = { type = "integer", display = "Speed", description = "Cells per turn", var = "SPEED" }
+
+[players]
+mode = "free-for-all"
+n_min = 2
+
+[python]
+version = "3.13"
+
+[translations.de]
+name = "Planeten"
+
+[translations.de.template_args]
+color = { display = "Farbe" }
+speed = { description = "Felder pro Zug" }
+"#;
+
+        fn texts(config: &GameConfig, language: &str) -> Vec<(String, Option<String>)> {
+            config
+                .localized_template_args(&[language])
+                .into_iter()
+                .map(|arg| (arg.display, arg.description))
+                .collect()
+        }
+
+        #[test]
+        fn translations_replace_only_provided_texts() {
+            let config = GameConfig::load_toml_str(CONFIG).unwrap();
+            assert_eq!(config.localized_name(&["de"]), "Planeten");
+            assert_eq!(
+                texts(&config, "de"),
+                vec![
+                    ("Farbe".to_string(), None),
+                    ("Speed".to_string(), Some("Felder pro Zug".to_string())),
+                ]
+            );
+        }
+
+        #[test]
+        fn regional_languages_fall_back_to_primary_language() {
+            let config = GameConfig::load_toml_str(CONFIG).unwrap();
+            assert_eq!(config.localized_name(&["de-AT"]), "Planeten");
+            assert_eq!(config.localized_name(&["fr"]), "Planets");
+            assert_eq!(texts(&config, "fr")[0].0, "Color");
+        }
+
+        #[test]
+        fn first_translated_language_is_used() {
+            let config = GameConfig::load_toml_str(CONFIG).unwrap();
+            assert_eq!(config.localized_name(&["fr-FR", "de-CH", "en"]), "Planeten");
+            assert_eq!(config.localized_name(&["en", "de"]), "Planeten");
+            let no_languages: [&str; 0] = [];
+            assert_eq!(config.localized_name(&no_languages), "Planets");
+        }
+    }
+}
+
+pub mod user {
+    use super::*;
+    use anyhow::Context;
+
+    /// Settings that apply to all projects of the current user.
+    #[derive(Clone, Debug, Default, Serialize, Deserialize)]
+    pub struct UserConfig {
+        /// Where to look for game configs, see [`crate::sources::GameSource`].
+        #[serde(default)]
+        pub game_sources: Vec<String>,
+        /// Publishers whose game configs are trusted in addition to the built-in ones.
+        #[serde(default)]
+        pub trusted_publishers: Vec<crate::signing::PublisherKey>,
+        /// Refuse to install games that are not signed by a trusted publisher.
+        #[serde(default)]
+        pub require_signed_games: bool,
+    }
+
+    impl UserConfig {
+        pub fn path() -> Option<PathBuf> {
+            user_config_dir().map(|dir| dir.join(USER_CONFIG_FILE_NAME))
+        }
+
+        /// Load the user config or return the default if there is none.
+        pub fn load() -> Result<Self> {
+            match Self::path() {
+                Some(path) if path.exists() => Self::load_toml(&path),
+                _ => Ok(Self::default()),
+            }
+        }
+
+        pub fn load_toml(path: &Path) -> Result<Self> {
+            let content = std::fs::read_to_string(path)?;
+            toml_edit::de::from_str(&content)
+                .with_context(|| format!("Invalid user config {}", path.display()))
+        }
+    }
+}
+
+pub mod template {
+    use super::*;
+    use anyhow::Context;
+
+    /// Metadata that a bot template provides about itself in [`TEMPLATE_MANIFEST_FILE_NAME`].
+    ///
+    /// The game config can add to and override these settings.
+    #[derive(Clone, Debug, Default, Serialize, Deserialize)]
+    pub struct TemplateManifest {
+        /// Arguments of bots created from the template.
+        ///
+        /// The manifest is only read after players have configured their bot,
+        /// so they cannot choose values for these args and the defaults are used.
+        /// Games add an arg with the same key to `[bot.template_args]` to let players choose.
+        #[serde(default)]
+        pub args: HashMap<String, game::BotTemplateArg>,
+        /// Path of the Python package relative to the template root, e.g., `src/my_bot`.
+        ///
+        /// Defaults to the only entry in `src`.
+        pub package: Option<String>,
+        #[serde(default)]
+        pub render: game::RenderConfig,
+        /// Commands that are run in the bot directory after rendering.
+        #[serde(default)]
+        pub post_render: Vec<PostRenderCommand>,
+    }
+
+    #[derive(Clone, Debug, Serialize, Deserialize)]
+    pub struct PostRenderCommand {
+        /// Program and arguments, placeholders are rendered.
+        pub command: Vec<String>,
+    }
+
+    impl TemplateManifest {
+        /// Load the manifest of the template at `template_root` if it has one.
+        pub async fn load(template_root: &Path) -> Result<Option<Self>> {
+            let path = template_root.join(TEMPLATE_MANIFEST_FILE_NAME);
+            if !path.exists() {
+                return Ok(None);
+            }
+            let content = tokio::fs::read_to_string(&path).await?;
+            let manifest = toml_edit::de::from_str(&content)
+                .with_context(|| format!("Invalid template manifest {}", path.display()))?;
+            Ok(Some(manifest))
+        }
     }
 }
@@ -0,0 +1,145 @@
+use anyhow::Result;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::config;
+use crate::project::dir_is_incomplete;
+
+/// Everything players need to report a failure, including the full cause chain.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ErrorReport {
+    /// What the player was doing, e.g., "Failed to install".
+    pub summary: String,
+    /// Version of the launcher that failed.
+    pub launcher_version: String,
+    /// The outermost error message, usually names the step that failed.
+    pub step: String,
+    /// The underlying errors from outermost to innermost.
+    pub causes: Vec<String>,
+    /// Settings that were used, e.g., URLs, paths, and the requested Python version.
+    pub context: Vec<(String, String)>,
+}
+
+impl ErrorReport {
+    pub fn new(
+        summary: impl Into<String>,
+        launcher_version: impl Into<String>,
+        err: &anyhow::Error,
+    ) -> Self {
+        let mut chain = err.chain().map(ToString::to_string);
+        Self {
+            summary: summary.into(),
+            launcher_version: launcher_version.into(),
+            step: chain.next().unwrap_or_default(),
+            causes: chain.collect(),
+            context: Vec::new(),
+        }
+    }
+
+    pub fn with_context(mut self, label: impl Into<String>, value: impl fmt::Display) -> Self {
+        self.context.push((label.into(), value.to_string()));
+        self
+    }
+
+    /// Write the report to a new file in the project's log directory.
+    ///
+    /// Falls back to a temporary directory if the project does not exist or is incomplete
+    /// because incomplete projects are removed when an installation is cancelled.
+    pub fn write_log(&self, project_root: Option<&Path>) -> Result<PathBuf> {
+        let launcher_dir = project_root.map(config::launcher_dir);
+        let dir = match (project_root, launcher_dir) {
+            (Some(root), Some(launcher_dir))
+                if launcher_dir.is_dir() && !dir_is_incomplete(&launcher_dir) =>
+            {
+                config::logs_dir(root)
+            }
+            _ => std::env::temp_dir().join(config::TEMP_LOGS_DIR_NAME),
+        };
+        std::fs::create_dir_all(&dir)?;
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis())
+            .unwrap_or_default();
+        let path = dir.join(format!("error-{timestamp}.log"));
+        std::fs::write(&path, self.to_string())?;
+        Ok(path)
+    }
+}
+
+impl fmt::Display for ErrorReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}: {}", self.summary, self.step)?;
+        if !self.causes.is_empty() {
+            writeln!(f, "\nCaused by:")?;
+            for (i, cause) in self.causes.iter().enumerate() {
+                writeln!(f, "    {i}: {cause}")?;
+            }
+        }
+        writeln!(f, "\nLauncher version: {}", self.launcher_version)?;
+        writeln!(
+            f,
+            "Operating system: {} ({})",
+            std::env::consts::OS,
+            std::env::consts::ARCH
+        )?;
+        for (label, value) in &self.context {
+            writeln!(f, "{label}: {value}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+    use pretty_assertions::assert_eq;
+
+    fn report() -> ErrorReport {
+        let err = Err::<(), _>(std::io::Error::other("connection reset"))
+            .context("Failed to fetch https://example.com/game.git")
+            .context("Failed to clone the game repository")
+            .unwrap_err();
+        ErrorReport::new("Failed to install", "1.2.3", &err).with_context("Python", "3.13")
+    }
+
+    #[test]
+    fn report_contains_the_full_cause_chain() {
+        let report = report();
+        assert_eq!(report.step, "Failed to clone the game repository");
+        assert_eq!(
+            report.causes,
+            vec![
+                "Failed to fetch https://example.com/game.git".to_string(),
+                "connection reset".to_string()
+            ]
+        );
+        let text = report.to_string();
+        assert!(text.starts_with("Failed to install: Failed to clone the game repository\n"));
+        assert!(text.contains("    1: connection reset\n"));
+        assert!(text.contains("Launcher version: 1.2.3\n"));
+        assert!(text.contains("Python: 3.13\n"));
+    }
+
+    #[test]
+    fn log_is_written_to_temp_dir_without_project() {
+        let missing = std::env::temp_dir().join("aigl-test-missing-project");
+        let path = report().write_log(Some(&missing)).unwrap();
+        assert!(path.starts_with(std::env::temp_dir().join(config::TEMP_LOGS_DIR_NAME)));
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            report().to_string()
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn log_is_written_to_project_logs_dir() {
+        let root = std::env::temp_dir().join(format!("aigl-test-logs-{}", std::process::id()));
+        std::fs::create_dir_all(config::launcher_dir(&root)).unwrap();
+        let path = report().write_log(Some(&root)).unwrap();
+        assert!(path.starts_with(config::logs_dir(&root)));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
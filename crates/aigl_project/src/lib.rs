@@ -1,9 +1,37 @@
 mod bot;
 mod bot_colors;
 mod bot_names;
+mod bot_tests;
+pub mod catalog;
+mod check;
 pub mod config;
+mod error_report;
+pub mod link;
+mod lint;
+mod manage;
+mod match_setup;
+mod package_id;
+mod plan;
+pub mod preferences;
 mod project;
+pub mod registry;
+pub mod schema;
+pub mod signing;
+pub mod sources;
+mod template;
 mod unique_selection;
+pub mod user_file;
+mod validation;
 
-pub use bot::{BotArg, BotArgValue};
+pub use bot::{BotArg, BotArgError, BotArgValue};
+pub use bot_tests::{TestCase, TestOutcome, TestReport, TestSelection};
+pub use check::{Finding, Fix, Severity};
+pub use error_report::ErrorReport;
+pub use lint::Diagnostic;
+pub use match_setup::{MatchPlayer, validate_match};
+pub use package_id::{
+    BotIdError, RESERVED_BOT_IDS, TEMPLATE_BOT_ID, bot_id_from_name, validate_bot_id,
+};
+pub use plan::{BotPlan, InstallPlan, PlanWarning, RepoPlan, RepoRef, VenvPlan, format_size};
 pub use project::{Project, dir_is_incomplete};
+pub use validation::{Location, Span, ValidationError, ValidationErrors};
@@ -0,0 +1,165 @@
+use anyhow::{Result, bail};
+use std::path::Path;
+
+use crate::sources::GameSource;
+
+/// Scheme of links that open the launcher, e.g., `aigl://install/arena?name=Ada`.
+pub const LINK_SCHEME: &str = "aigl";
+/// Extension of downloaded game config files that open the launcher.
+pub const GAME_FILE_EXTENSION: &str = "aigl";
+
+/// A game that the launcher was asked to install on start.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct InstallLink {
+    pub game_code: String,
+    /// A source to search before the configured ones, set for game files.
+    pub game_source: Option<GameSource>,
+    /// Name of the player's bot.
+    pub player_name: Option<String>,
+}
+
+impl InstallLink {
+    /// Parse an `aigl://install/<game-code>?name=<bot-name>` link or the path of a `.aigl` file.
+    ///
+    /// Relative paths are resolved against `base`.
+    pub fn parse(arg: &str, base: &Path) -> Result<Self> {
+        match arg
+            .strip_prefix(LINK_SCHEME)
+            .and_then(|rest| rest.strip_prefix("://"))
+        {
+            Some(rest) => Self::parse_url(rest),
+            None => Self::from_game_file(Path::new(arg), base),
+        }
+    }
+
+    fn parse_url(rest: &str) -> Result<Self> {
+        let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let Some(game_code) = path.strip_prefix("install/") else {
+            bail!(
+                "Unsupported link '{LINK_SCHEME}://{rest}', expected '{LINK_SCHEME}://install/<game>'"
+            );
+        };
+        let game_code = percent_decode(game_code.trim_end_matches('/'))?;
+        // Codes become file names, so they must not be able to escape the game sources.
+        if game_code.is_empty()
+            || !game_code
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            bail!("Invalid game code '{game_code}' in link");
+        }
+
+        let mut player_name = None;
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            // Ignore unknown parameters so that newer links still work.
+            if key == "name" {
+                player_name = Some(percent_decode(value)?).filter(|name| !name.is_empty());
+            }
+        }
+        Ok(Self {
+            game_code,
+            game_source: None,
+            player_name,
+        })
+    }
+
+    fn from_game_file(path: &Path, base: &Path) -> Result<Self> {
+        if path
+            .extension()
+            .is_none_or(|ext| ext != GAME_FILE_EXTENSION)
+        {
+            bail!(
+                "Expected a '{LINK_SCHEME}://' link or a .{GAME_FILE_EXTENSION} file, got {}",
+                path.display()
+            );
+        }
+        // Browsers add suffixes like " (1)" to repeated downloads, so any stem is allowed.
+        let Some(game_code) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            bail!("Invalid game file name {}", path.display());
+        };
+        Ok(Self {
+            game_code: game_code.to_owned(),
+            game_source: Some(GameSource::File(base.join(path))),
+            player_name: None,
+        })
+    }
+}
+
+/// Decode `%XX` escapes and `+` for spaces as written by browsers.
+fn percent_decode(value: &str) -> Result<String> {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut rest = value.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        rest = tail;
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let decoded = rest
+                    .get(..2)
+                    .and_then(|hex| std::str::from_utf8(hex).ok())
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+                let Some(decoded) = decoded else {
+                    bail!("Invalid escape sequence in '{value}'");
+                };
+                bytes.push(decoded);
+                rest = &rest[2..];
+            }
+            _ => bytes.push(byte),
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| anyhow::anyhow!("Invalid UTF-8 in '{value}'"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::path::PathBuf;
+
+    fn parse(arg: &str) -> Result<InstallLink> {
+        InstallLink::parse(arg, Path::new("/home/user/Downloads"))
+    }
+
+    #[test]
+    fn install_links_are_parsed() {
+        assert_eq!(
+            parse("aigl://install/test_game").unwrap(),
+            InstallLink {
+                game_code: "test_game".into(),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            parse("aigl://install/arena-2/?name=J%C3%BCrgen+der%20Bot&unknown=1").unwrap(),
+            InstallLink {
+                game_code: "arena-2".into(),
+                game_source: None,
+                player_name: Some("Jürgen der Bot".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_links_are_rejected() {
+        assert!(parse("aigl://uninstall/test_game").is_err());
+        assert!(parse("aigl://install/").is_err());
+        assert!(parse("aigl://install/..%2Fsecret").is_err());
+        assert!(parse("aigl://install/game?name=%zz").is_err());
+        assert!(parse("https://example.com/game.toml").is_err());
+    }
+
+    #[test]
+    fn game_files_are_sources() {
+        assert_eq!(
+            parse("arena (1).aigl").unwrap(),
+            InstallLink {
+                game_code: "arena (1)".into(),
+                game_source: Some(GameSource::File(PathBuf::from(
+                    "/home/user/Downloads/arena (1).aigl"
+                ))),
+                player_name: None,
+            }
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+use std::path::PathBuf;
+use std::process::Output;
+
+use crate::Project;
+
+/// A problem reported by `ruff check`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    /// Rule code, e.g., `F401`. `None` for syntax errors.
+    pub code: Option<String>,
+    pub message: String,
+    pub file: PathBuf,
+    pub line: usize,
+    pub column: usize,
+    /// Description of the fix if ruff can apply it safely.
+    pub fix: Option<String>,
+}
+
+impl Diagnostic {
+    pub fn is_fixable(&self) -> bool {
+        self.fix.is_some()
+    }
+}
+
+impl Project {
+    /// Lint a bot with ruff using the settings in the bot's `pyproject.toml`.
+    ///
+    /// Installs ruff into the venv if it is not available.
+    pub async fn check_bot_code(&self, bot_id: &str) -> Result<Vec<Diagnostic>> {
+        let output = self
+            .run_ruff(bot_id, &["check", "--output-format=json", "--exit-zero"])
+            .await?;
+        parse_diagnostics(&output.stdout)
+    }
+
+    /// Apply safe fixes to a bot and lint it again.
+    ///
+    /// If `diagnostic` is `Some`, this fixes all diagnostics with the same code in the same file
+    /// because ruff cannot restrict fixes to a location.
+    /// Fixes everything in the bot otherwise.
+    pub async fn fix_bot_code(
+        &self,
+        bot_id: &str,
+        diagnostic: Option<&Diagnostic>,
+    ) -> Result<Vec<Diagnostic>> {
+        let selection = diagnostic
+            .and_then(|diagnostic| Some((diagnostic.code.as_deref()?, diagnostic.file.to_str()?)));
+        let mut args = vec!["check", "--fix", "--exit-zero", "--quiet"];
+        if let Some((code, file)) = selection {
+            args.extend(["--select", code, file]);
+        }
+        self.run_ruff(bot_id, &args).await?;
+        self.check_bot_code(bot_id).await
+    }
+
+    /// Format all Python files of a bot with ruff.
+    ///
+    /// Returns ruff's summary, e.g., "2 files reformatted, 5 files left unchanged".
+    pub async fn format_bot_code(&self, bot_id: &str) -> Result<String> {
+        let output = self.run_ruff(bot_id, &["format"]).await?;
+        if !output.status.success() {
+            bail!(
+                "ruff format failed:\n{}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_owned())
+    }
+
+    async fn run_ruff(&self, bot_id: &str, args: &[&str]) -> Result<Output> {
+        let bot_path = self.bot_path(bot_id)?;
+        let venv = self.venv()?;
+        if !ruff_is_installed(self.python_command()?).await {
+            aigl_python::install(
+                &[aigl_python::RequirementsSource::Package("ruff".into())],
+                [],
+                false,
+                self.python_cache(),
+                &venv,
+            )
+            .await
+            .context("Failed to install ruff")?;
+        }
+
+        let mut command = tokio::process::Command::from(self.python_command()?);
+        command
+            .args(["-m", "ruff"])
+            .args(args)
+            .current_dir(bot_path);
+        let output = command.output().await.context("Failed to run ruff")?;
+        // Ruff uses exit code 2 for errors in its own operation, e.g., invalid settings.
+        if output.status.code() == Some(2) {
+            bail!(
+                "ruff failed:\n{}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(output)
+    }
+}
+
+async fn ruff_is_installed(python_command: std::process::Command) -> bool {
+    let mut command = tokio::process::Command::from(python_command);
+    command.args(["-m", "ruff", "--version"]);
+    command
+        .output()
+        .await
+        .is_ok_and(|output| output.status.success())
+}
+
+#[derive(Deserialize)]
+struct RuffDiagnostic {
+    code: Option<String>,
+    message: String,
+    filename: PathBuf,
+    location: RuffLocation,
+    fix: Option<RuffFix>,
+}
+
+#[derive(Deserialize)]
+struct RuffLocation {
+    row: usize,
+    column: usize,
+}
+
+#[derive(Deserialize)]
+struct RuffFix {
+    applicability: String,
+    message: Option<String>,
+}
+
+fn parse_diagnostics(json: &[u8]) -> Result<Vec<Diagnostic>> {
+    let diagnostics: Vec<RuffDiagnostic> =
+        serde_json::from_slice(json).context("Failed to parse the output of ruff")?;
+    Ok(diagnostics
+        .into_iter()
+        .map(|diagnostic| Diagnostic {
+            fix: diagnostic
+                .fix
+                .filter(|fix| fix.applicability == "safe")
+                .map(|fix| fix.message.unwrap_or_else(|| "Apply fix".into())),
+            code: diagnostic.code,
+            message: diagnostic.message,
+            file: diagnostic.filename,
+            line: diagnostic.location.row,
+            column: diagnostic.location.column,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_ruff_json_output() {
+        let json = r#"[
+  {
+    "cell": null,
+    "code": "F401",
+    "end_location": {"column": 10, "row": 1},
+    "filename": "/game/bot_me/src/bot_me/__init__.py",
+    "fix": {
+      "applicability": "safe",
+      "edits": [{"content": "", "end_location": {"column": 1, "row": 2}, "location": {"column": 1, "row": 1}}],
+      "message": "Remove unused import: `os`"
+    },
+    "location": {"column": 8, "row": 1},
+    "message": "`os` imported but unused",
+    "noqa_row": 1,
+    "url": "https://docs.astral.sh/ruff/rules/unused-import"
+  },
+  {
+    "cell": null,
+    "code": "E741",
+    "end_location": {"column": 2, "row": 5},
+    "filename": "/game/bot_me/src/bot_me/bot.py",
+    "fix": null,
+    "location": {"column": 1, "row": 5},
+    "message": "Ambiguous variable name: `l`",
+    "noqa_row": 5,
+    "url": "https://docs.astral.sh/ruff/rules/ambiguous-variable-name"
+  },
+  {
+    "cell": null,
+    "code": "F841",
+    "end_location": {"column": 6, "row": 9},
+    "filename": "/game/bot_me/src/bot_me/bot.py",
+    "fix": {"applicability": "unsafe", "edits": [], "message": "Remove assignment to unused variable `x`"},
+    "location": {"column": 5, "row": 9},
+    "message": "Local variable `x` is assigned to but never used",
+    "noqa_row": 9,
+    "url": "https://docs.astral.sh/ruff/rules/unused-variable"
+  }
+]"#;
+        let diagnostics = parse_diagnostics(json.as_bytes()).unwrap();
+        assert_eq!(
+            diagnostics[0],
+            Diagnostic {
+                code: Some("F401".into()),
+                message: "`os` imported but unused".into(),
+                file: "/game/bot_me/src/bot_me/__init__.py".into(),
+                line: 1,
+                column: 8,
+                fix: Some("Remove unused import: `os`".into()),
+            }
+        );
+        assert_eq!(diagnostics[1].fix, None);
+        // Unsafe fixes are not applied by `ruff check --fix`.
+        assert_eq!(diagnostics[2].fix, None);
+    }
+
+    #[test]
+    fn parse_ruff_json_output_without_diagnostics() {
+        assert!(parse_diagnostics(b"[]").unwrap().is_empty());
+    }
+}
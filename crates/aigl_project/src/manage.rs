@@ -0,0 +1,123 @@
+use anyhow::{Result, bail};
+use async_lock::Mutex;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::bot::BotArg;
+use crate::project::{make_player_config_table, render_player_bot};
+use crate::{Project, validate_bot_id};
+use aigl_git::Repository;
+
+impl Project {
+    /// Ids of all bots in the project, the player's bot comes first.
+    pub fn bot_ids(&self) -> Vec<String> {
+        self.cfg()
+            .bot_paths
+            .iter()
+            .filter_map(|path| Some(path.file_name()?.to_str()?.to_owned()))
+            .collect()
+    }
+
+    /// Command that runs the game with the player config of the project.
+    ///
+    /// The game is launched with `python -m` and the game's launch args.
+    pub fn game_command(&self) -> Result<std::process::Command> {
+        let config_path = self.player_config_path()?;
+        let config_path = config_path.to_string_lossy();
+        let mut command = self.python_command()?;
+        command
+            .arg("-m")
+            .args(
+                self.cfg()
+                    .game_config
+                    .game
+                    .launch_args
+                    .iter()
+                    .map(|arg| arg.replace("{config}", &config_path)),
+            )
+            .current_dir(self.root());
+        Ok(command)
+    }
+
+    /// Pull the latest version of the game and reinstall it.
+    ///
+    /// Returns `false` if the game was already up to date.
+    pub async fn update_game(&self) -> Result<bool> {
+        let game = &self.cfg().game_config.game;
+        if let Some(commit) = &game.commit {
+            bail!("The game config pins the game to commit {commit}");
+        }
+        if !pull(&self.cfg().game_path)? {
+            return Ok(false);
+        }
+        let venv = self.venv()?;
+        self.install_packages(&venv).await?;
+        Ok(true)
+    }
+
+    /// Pull the latest version of the bot template.
+    ///
+    /// Only bots that are added afterwards use the new version.
+    /// Returns `false` if the template was already up to date.
+    pub async fn update_bot_template(&self) -> Result<bool> {
+        if let Some(commit) = &self.cfg().game_config.bot.template_commit {
+            bail!("The game config pins the bot template to commit {commit}");
+        }
+        pull(&self.cfg().bot_template_path)
+    }
+
+    /// Create a new bot from the template, install it, and add it as a player.
+    pub async fn add_bot(
+        project: Arc<Mutex<Self>>,
+        bot_id: String,
+        bot_name: String,
+        args: Vec<BotArg>,
+    ) -> Result<()> {
+        validate_bot_id(&bot_id)?;
+        {
+            let lock = project.lock().await;
+            if lock.bot_path(&bot_id).is_ok() || lock.root().join(&bot_id).exists() {
+                bail!("The project already has a bot '{bot_id}'");
+            }
+        }
+
+        render_player_bot(project.clone(), bot_id.clone(), bot_name, args).await?;
+
+        let mut lock = project.lock().await;
+        let venv = lock.venv()?;
+        lock.install_packages(&venv).await?;
+        lock.add_player(&bot_id).await?;
+        lock.save_config().await
+    }
+
+    /// Delete the project directory including all bots.
+    pub async fn uninstall(self) -> Result<()> {
+        tokio::fs::remove_dir_all(self.root()).await?;
+        Ok(())
+    }
+
+    async fn add_player(&mut self, bot_id: &str) -> Result<()> {
+        let config_path = self.player_config_path()?;
+        let mut config = tokio::fs::read_to_string(&config_path)
+            .await?
+            .parse::<toml_edit::DocumentMut>()?;
+        let table = make_player_config_table(self, bot_id, false, None);
+        match config
+            .get_mut("player")
+            .and_then(|item| item.as_array_of_tables_mut())
+        {
+            Some(players) => players.push(table),
+            None => {
+                let mut players = toml_edit::ArrayOfTables::new();
+                players.push(table);
+                config.insert("player", players.into());
+            }
+        }
+        tokio::fs::write(&config_path, config.to_string()).await?;
+        Ok(())
+    }
+}
+
+fn pull(repo_path: &Path) -> Result<bool> {
+    Repository::open(repo_path)?.pull_fast_forward()
+}
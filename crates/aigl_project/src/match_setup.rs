@@ -0,0 +1,341 @@
+use anyhow::Result;
+use toml_edit::{ArrayOfTables, DocumentMut, InlineTable, Item, Table};
+
+use crate::Project;
+use crate::bot_colors::bot_color_selection;
+use crate::bot_names::bot_name_selection;
+use crate::config::game::Players;
+
+/// A `[[player]]` entry of the game config, i.e., one bot in a match.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MatchPlayer {
+    /// Id of the bot's Python package.
+    pub package: String,
+    /// Overrides the name from the bot's config.
+    pub name: Option<String>,
+    /// Overrides the color from the bot's config, e.g., `#3a4cd5`.
+    pub color: Option<String>,
+}
+
+impl MatchPlayer {
+    /// A player for `package` with a name and color that none of `players` uses.
+    ///
+    /// Copies of the same bot need these overrides to be told apart in the game.
+    pub fn with_unique_overrides(package: &str, players: &[MatchPlayer]) -> Self {
+        let mut names = bot_name_selection(players.iter().filter_map(|p| p.name.as_deref()));
+        let mut colors = bot_color_selection(players.iter().filter_map(|p| p.color.as_deref()));
+        Self {
+            package: package.to_owned(),
+            name: Some(names.pop()),
+            color: Some(colors.pop()),
+        }
+    }
+}
+
+/// Check that a match can be played with the bots of a project.
+pub fn validate_match(
+    players: &[MatchPlayer],
+    limits: &Players,
+    bot_ids: &[String],
+) -> Result<(), String> {
+    match limits {
+        Players::FFA { n_min, n_max, .. } => {
+            if players.len() < *n_min {
+                return Err(format!("The game needs at least {n_min} bots"));
+            }
+            if let Some(n_max) = n_max {
+                if players.len() > *n_max {
+                    return Err(format!("The game allows at most {n_max} bots"));
+                }
+            }
+        }
+        Players::Teams { .. } => return Err("Teams are not supported yet".into()),
+    }
+    for player in players {
+        if !bot_ids.contains(&player.package) {
+            return Err(format!("The project has no bot '{}'", player.package));
+        }
+        if player
+            .name
+            .as_ref()
+            .is_some_and(|name| name.trim().is_empty())
+        {
+            return Err(format!("A copy of '{}' has an empty name", player.package));
+        }
+        if let Some(color) = &player.color {
+            if !is_hex_color(color) {
+                return Err(format!("'{color}' is not a color like #3a4cd5"));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn is_hex_color(color: &str) -> bool {
+    color
+        .strip_prefix('#')
+        .is_some_and(|hex| hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+impl Project {
+    /// Read the `[[player]]` tables from the player config file.
+    pub async fn load_match(&self) -> Result<Vec<MatchPlayer>> {
+        let config = tokio::fs::read_to_string(self.player_config_path()?)
+            .await?
+            .parse::<DocumentMut>()?;
+        Ok(read_players(&config))
+    }
+
+    /// Replace the `[[player]]` tables in the player config file.
+    ///
+    /// Comments and all other settings in the file are preserved.
+    pub async fn save_match(&self, players: &[MatchPlayer]) -> Result<()> {
+        validate_match(players, &self.cfg().game_config.players, &self.bot_ids())
+            .map_err(anyhow::Error::msg)?;
+        let config_path = self.player_config_path()?;
+        let mut config = tokio::fs::read_to_string(&config_path)
+            .await?
+            .parse::<DocumentMut>()?;
+        write_players(&mut config, players);
+        tokio::fs::write(&config_path, config.to_string()).await?;
+        Ok(())
+    }
+}
+
+fn read_players(config: &DocumentMut) -> Vec<MatchPlayer> {
+    let Some(players) = config.get("player").and_then(Item::as_array_of_tables) else {
+        return Vec::new();
+    };
+    players
+        .iter()
+        .map(|table| {
+            let overrides = table.get("overrides").and_then(Item::as_table_like);
+            let get_override = |key| {
+                overrides
+                    .and_then(|overrides| overrides.get(key))
+                    .and_then(Item::as_str)
+                    .map(str::to_owned)
+            };
+            MatchPlayer {
+                package: table
+                    .get("package")
+                    .and_then(Item::as_str)
+                    .unwrap_or_default()
+                    .to_owned(),
+                name: get_override("name"),
+                color: get_override("color"),
+            }
+        })
+        .collect()
+}
+
+fn write_players(config: &mut DocumentMut, players: &[MatchPlayer]) {
+    let mut existing: Vec<Option<Table>> = config
+        .get("player")
+        .and_then(Item::as_array_of_tables)
+        .map(|tables| tables.iter().cloned().map(Some).collect())
+        .unwrap_or_default();
+    // Tables are printed in the order of their positions in the original document.
+    let mut positions: Vec<_> = existing
+        .iter()
+        .flatten()
+        .filter_map(Table::position)
+        .collect();
+    positions.sort_unstable();
+    let header = existing
+        .first()
+        .and_then(|table| table.as_ref()?.decor().prefix().cloned());
+
+    let mut tables = ArrayOfTables::new();
+    for (i, player) in players.iter().enumerate() {
+        // Reuse a table of the same bot to keep its comments and unknown keys.
+        let mut table = existing
+            .iter_mut()
+            .find(|table| {
+                table
+                    .as_ref()
+                    .and_then(|table| table.get("package")?.as_str())
+                    .is_some_and(|package| package == player.package)
+            })
+            .and_then(Option::take)
+            .unwrap_or_default();
+        if let Some(position) = positions.get(i).or(positions.last()) {
+            table.set_position(*position);
+        }
+        // The comment above the first player introduces the whole list.
+        if i == 0 {
+            if let Some(header) = &header {
+                table.decor_mut().set_prefix(header.clone());
+            }
+        } else if header.is_some() && table.decor().prefix() == header.as_ref() {
+            table.decor_mut().set_prefix("\n");
+        }
+        set_player(&mut table, player);
+        tables.push(table);
+    }
+    config.insert("player", Item::ArrayOfTables(tables));
+}
+
+fn set_player(table: &mut Table, player: &MatchPlayer) {
+    table.insert("package", toml_edit::value(&player.package));
+    if table
+        .get("overrides")
+        .is_none_or(|item| item.as_table_like().is_none())
+    {
+        table.insert("overrides", toml_edit::value(InlineTable::new()));
+    }
+    let Some(overrides) = table.get_mut("overrides").and_then(Item::as_table_like_mut) else {
+        return;
+    };
+    for (key, value) in [("name", &player.name), ("color", &player.color)] {
+        match value {
+            Some(value) => {
+                overrides.insert(key, toml_edit::value(value));
+            }
+            None => {
+                overrides.remove(key);
+            }
+        }
+    }
+    if overrides.is_empty() {
+        table.remove("overrides");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const CONFIG: &str = r##"# Game settings
+n_rounds = 10
+
+# --- Select bots for the game ---
+# Your bot:
+[[player]]
+package = "bot_me"
+
+# Add a number of extra bots:
+[[player]]
+package = "template_bot"
+overrides = { name = "Botulf", color = "#3a4cd5", speed = 2 }
+"##;
+
+    fn player(package: &str, name: Option<&str>, color: Option<&str>) -> MatchPlayer {
+        MatchPlayer {
+            package: package.into(),
+            name: name.map(Into::into),
+            color: color.map(Into::into),
+        }
+    }
+
+    fn ffa(n_min: usize, n_max: Option<usize>) -> Players {
+        Players::FFA {
+            n_min,
+            n_max,
+            n_initial: None,
+        }
+    }
+
+    #[test]
+    fn read_players_returns_packages_and_overrides() {
+        let config = CONFIG.parse::<DocumentMut>().unwrap();
+        assert_eq!(
+            read_players(&config),
+            vec![
+                player("bot_me", None, None),
+                player("template_bot", Some("Botulf"), Some("#3a4cd5")),
+            ]
+        );
+    }
+
+    #[test]
+    fn write_players_keeps_comments_and_other_settings() {
+        let mut config = CONFIG.parse::<DocumentMut>().unwrap();
+        let players = vec![
+            player("bot_me", None, None),
+            player("template_bot", Some("Boten"), Some("#3a4cd5")),
+            player("template_bot", Some("Aimbot"), None),
+        ];
+        write_players(&mut config, &players);
+        assert_eq!(
+            config.to_string(),
+            r##"# Game settings
+n_rounds = 10
+
+# --- Select bots for the game ---
+# Your bot:
+[[player]]
+package = "bot_me"
+
+# Add a number of extra bots:
+[[player]]
+package = "template_bot"
+overrides = { name = "Boten", color = "#3a4cd5", speed = 2 }
+
+[[player]]
+package = "template_bot"
+overrides = { name = "Aimbot" }
+"##
+        );
+        assert_eq!(read_players(&config), players);
+    }
+
+    #[test]
+    fn write_players_keeps_header_on_first_player() {
+        let mut config = CONFIG.parse::<DocumentMut>().unwrap();
+        write_players(
+            &mut config,
+            &[
+                player("template_bot", Some("Botulf"), None),
+                player("bot_me", None, None),
+            ],
+        );
+        assert_eq!(
+            config.to_string(),
+            r##"# Game settings
+n_rounds = 10
+
+# --- Select bots for the game ---
+# Your bot:
+[[player]]
+package = "template_bot"
+overrides = { name = "Botulf", speed = 2 }
+
+[[player]]
+package = "bot_me"
+"##
+        );
+    }
+
+    #[test]
+    fn validate_match_checks_limits_and_bots() {
+        let bot_ids = vec!["bot_me".to_string(), "template_bot".to_string()];
+        let players = vec![
+            player("bot_me", None, None),
+            player("template_bot", Some("Botulf"), Some("#3a4cd5")),
+        ];
+        assert_eq!(validate_match(&players, &ffa(2, Some(4)), &bot_ids), Ok(()));
+        assert!(validate_match(&players, &ffa(3, None), &bot_ids).is_err());
+        assert!(validate_match(&players, &ffa(1, Some(1)), &bot_ids).is_err());
+        assert!(validate_match(&players, &ffa(1, None), &bot_ids[..1]).is_err());
+        assert!(
+            validate_match(
+                &[player("bot_me", None, Some("blue"))],
+                &ffa(1, None),
+                &bot_ids
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn unique_overrides_avoid_used_names_and_colors() {
+        let players = vec![player("template_bot", Some("Botulf"), Some("#3a4cd5"))];
+        for _ in 0..20 {
+            let new = MatchPlayer::with_unique_overrides("template_bot", &players);
+            assert_ne!(new.name.as_deref(), Some("Botulf"));
+            assert_ne!(new.color.as_deref(), Some("#3a4cd5"));
+        }
+    }
+}
@@ -0,0 +1,373 @@
+use std::fmt;
+
+/// Id of the bot that the launcher renders from the template to play against the player's bot.
+pub const TEMPLATE_BOT_ID: &str = "template_bot";
+
+/// Ids that the launcher uses for its own bots.
+pub const RESERVED_BOT_IDS: [&str; 1] = [TEMPLATE_BOT_ID];
+
+const PYTHON_KEYWORDS: [&str; 35] = [
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+/// Top-level modules of the Python standard library, a package with the same name would shadow them.
+const STDLIB_MODULES: [&str; 217] = [
+    "abc",
+    "aifc",
+    "antigravity",
+    "argparse",
+    "array",
+    "ast",
+    "asynchat",
+    "asyncio",
+    "asyncore",
+    "atexit",
+    "audioop",
+    "base64",
+    "bdb",
+    "binascii",
+    "bisect",
+    "builtins",
+    "bz2",
+    "cProfile",
+    "calendar",
+    "cgi",
+    "cgitb",
+    "chunk",
+    "cmath",
+    "cmd",
+    "code",
+    "codecs",
+    "codeop",
+    "collections",
+    "colorsys",
+    "compileall",
+    "concurrent",
+    "configparser",
+    "contextlib",
+    "contextvars",
+    "copy",
+    "copyreg",
+    "crypt",
+    "csv",
+    "ctypes",
+    "curses",
+    "dataclasses",
+    "datetime",
+    "dbm",
+    "decimal",
+    "difflib",
+    "dis",
+    "distutils",
+    "doctest",
+    "email",
+    "encodings",
+    "ensurepip",
+    "enum",
+    "errno",
+    "faulthandler",
+    "fcntl",
+    "filecmp",
+    "fileinput",
+    "fnmatch",
+    "fractions",
+    "ftplib",
+    "functools",
+    "gc",
+    "genericpath",
+    "getopt",
+    "getpass",
+    "gettext",
+    "glob",
+    "graphlib",
+    "grp",
+    "gzip",
+    "hashlib",
+    "heapq",
+    "hmac",
+    "html",
+    "http",
+    "idlelib",
+    "imaplib",
+    "imghdr",
+    "imp",
+    "importlib",
+    "inspect",
+    "io",
+    "ipaddress",
+    "itertools",
+    "json",
+    "keyword",
+    "lib2to3",
+    "linecache",
+    "locale",
+    "logging",
+    "lzma",
+    "mailbox",
+    "mailcap",
+    "marshal",
+    "math",
+    "mimetypes",
+    "mmap",
+    "modulefinder",
+    "msilib",
+    "msvcrt",
+    "multiprocessing",
+    "netrc",
+    "nis",
+    "nntplib",
+    "nt",
+    "ntpath",
+    "nturl2path",
+    "numbers",
+    "opcode",
+    "operator",
+    "optparse",
+    "os",
+    "ossaudiodev",
+    "pathlib",
+    "pdb",
+    "pickle",
+    "pickletools",
+    "pipes",
+    "pkgutil",
+    "platform",
+    "plistlib",
+    "poplib",
+    "posix",
+    "posixpath",
+    "pprint",
+    "profile",
+    "pstats",
+    "pty",
+    "pwd",
+    "py_compile",
+    "pyclbr",
+    "pydoc",
+    "pydoc_data",
+    "pyexpat",
+    "queue",
+    "quopri",
+    "random",
+    "re",
+    "readline",
+    "reprlib",
+    "resource",
+    "rlcompleter",
+    "runpy",
+    "sched",
+    "secrets",
+    "select",
+    "selectors",
+    "shelve",
+    "shlex",
+    "shutil",
+    "signal",
+    "site",
+    "smtpd",
+    "smtplib",
+    "sndhdr",
+    "socket",
+    "socketserver",
+    "spwd",
+    "sqlite3",
+    "sre_compile",
+    "sre_constants",
+    "sre_parse",
+    "ssl",
+    "stat",
+    "statistics",
+    "string",
+    "stringprep",
+    "struct",
+    "subprocess",
+    "sunau",
+    "symtable",
+    "sys",
+    "sysconfig",
+    "syslog",
+    "tabnanny",
+    "tarfile",
+    "telnetlib",
+    "tempfile",
+    "termios",
+    "textwrap",
+    "this",
+    "threading",
+    "time",
+    "timeit",
+    "tkinter",
+    "token",
+    "tokenize",
+    "tomllib",
+    "trace",
+    "traceback",
+    "tracemalloc",
+    "tty",
+    "turtle",
+    "turtledemo",
+    "types",
+    "typing",
+    "unicodedata",
+    "unittest",
+    "urllib",
+    "uu",
+    "uuid",
+    "venv",
+    "warnings",
+    "wave",
+    "weakref",
+    "webbrowser",
+    "winreg",
+    "winsound",
+    "wsgiref",
+    "xdrlib",
+    "xml",
+    "xmlrpc",
+    "zipapp",
+    "zipfile",
+    "zipimport",
+    "zlib",
+    "zoneinfo",
+];
+
+/// Derive a valid Python package name from a bot name.
+///
+/// Non-ASCII characters are transliterated and everything that is not
+/// allowed in an identifier is replaced by `_`.
+/// The `bot_` prefix avoids leading digits, keywords, and clashes with the standard library.
+pub fn bot_id_from_name(name: &str) -> String {
+    let ascii = deunicode::deunicode(name).to_lowercase();
+    let mut id = String::from("bot_");
+    for c in ascii.chars() {
+        if c.is_ascii_alphanumeric() {
+            id.push(c);
+        } else if !id.ends_with('_') {
+            id.push('_');
+        }
+    }
+    let id = id.trim_end_matches('_');
+    if id.len() < "bot_".len() {
+        "bot".to_owned()
+    } else {
+        id.to_owned()
+    }
+}
+
+/// Why a bot id cannot be used as the name of a Python package.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BotIdError {
+    Empty,
+    /// The id starts with something other than a letter or '_'.
+    InvalidStart,
+    InvalidCharacter(char),
+    PythonKeyword(String),
+    /// The package would shadow a module of the standard library.
+    StdlibModule(String),
+    /// The launcher uses the id for its own bots, see [`RESERVED_BOT_IDS`].
+    Reserved(String),
+}
+
+impl fmt::Display for BotIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("The package name must not be empty"),
+            Self::InvalidStart => f.write_str("The package name must start with a letter or '_'"),
+            Self::InvalidCharacter(c) => write!(
+                f,
+                "The package name must only contain ASCII letters, digits, and '_', not '{c}'"
+            ),
+            Self::PythonKeyword(id) => write!(f, "'{id}' is a Python keyword"),
+            Self::StdlibModule(id) => write!(
+                f,
+                "'{id}' is the name of a module in Python's standard library"
+            ),
+            Self::Reserved(id) => write!(f, "'{id}' is reserved by the launcher"),
+        }
+    }
+}
+
+impl std::error::Error for BotIdError {}
+
+/// Check that `id` can be used as the name of a bot's Python package.
+pub fn validate_bot_id(id: &str) -> Result<(), BotIdError> {
+    let Some(first) = id.chars().next() else {
+        return Err(BotIdError::Empty);
+    };
+    if !(first.is_ascii_alphabetic() || first == '_') {
+        return Err(BotIdError::InvalidStart);
+    }
+    if let Some(c) = id
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || *c == '_'))
+    {
+        return Err(BotIdError::InvalidCharacter(c));
+    }
+    if PYTHON_KEYWORDS.contains(&id) {
+        return Err(BotIdError::PythonKeyword(id.to_owned()));
+    }
+    let lower = id.to_lowercase();
+    // Compare case-insensitively because file systems on Windows and macOS are.
+    if STDLIB_MODULES
+        .iter()
+        .any(|module| module.to_lowercase() == lower)
+    {
+        return Err(BotIdError::StdlibModule(id.to_owned()));
+    }
+    if RESERVED_BOT_IDS.contains(&lower.as_str()) {
+        return Err(BotIdError::Reserved(id.to_owned()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn bot_id_from_name_transliterates_and_replaces_invalid_characters() {
+        assert_eq!(bot_id_from_name("Jörg's Bot 2"), "bot_jorg_s_bot_2");
+        assert_eq!(bot_id_from_name("  Ω-Bot!! "), "bot_o_bot");
+        assert_eq!(bot_id_from_name("42"), "bot_42");
+        assert_eq!(bot_id_from_name("!!!"), "bot");
+    }
+
+    #[test]
+    fn generated_ids_are_valid() {
+        for name in ["Jörg's Bot 2", "class", "os", "", "北京"] {
+            let id = bot_id_from_name(name);
+            assert_eq!(validate_bot_id(&id), Ok(()), "{name} -> {id}");
+        }
+    }
+
+    #[test]
+    fn validate_bot_id_rejects_invalid_names() {
+        assert_eq!(validate_bot_id(""), Err(BotIdError::Empty));
+        assert_eq!(validate_bot_id("2fast"), Err(BotIdError::InvalidStart));
+        assert_eq!(
+            validate_bot_id("my-bot"),
+            Err(BotIdError::InvalidCharacter('-'))
+        );
+        assert_eq!(
+            validate_bot_id("jörg"),
+            Err(BotIdError::InvalidCharacter('ö'))
+        );
+        assert_eq!(
+            validate_bot_id("lambda"),
+            Err(BotIdError::PythonKeyword("lambda".into()))
+        );
+        assert_eq!(
+            validate_bot_id("Random"),
+            Err(BotIdError::StdlibModule("Random".into()))
+        );
+        assert_eq!(
+            validate_bot_id("template_bot"),
+            Err(BotIdError::Reserved("template_bot".into()))
+        );
+        assert_eq!(validate_bot_id("my_bot"), Ok(()));
+    }
+}
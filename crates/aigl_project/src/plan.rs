@@ -0,0 +1,442 @@
+use anyhow::{Result, bail};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::bot::BotArg;
+use crate::config;
+use crate::config::game::{GameConfig, VenvKind};
+use crate::package_id::TEMPLATE_BOT_ID;
+use crate::project::{Project, generated_opponents};
+use aigl_git::Repository;
+use aigl_system::disk::{available_space, is_network_path};
+use aigl_system::fs::path_available_as_output_directory;
+
+const MIB: u64 = 1024 * 1024;
+// Rough sizes for typical games, the real sizes are only known after installing.
+const PYTHON_DOWNLOAD_SIZE: u64 = 30 * MIB;
+const PYTHON_DISK_SIZE: u64 = 110 * MIB;
+/// The game's dependencies, downloaded once into the uv cache.
+const PACKAGES_DOWNLOAD_SIZE: u64 = 40 * MIB;
+/// The game's dependencies installed into one virtual environment.
+const PACKAGES_DISK_SIZE: u64 = 150 * MIB;
+/// Used for repositories whose host does not report a size.
+const REPO_SIZE: u64 = 10 * MIB;
+
+/// What [`Project::init`] would do with the same arguments.
+#[derive(Clone, Debug)]
+pub struct InstallPlan {
+    pub root: PathBuf,
+    pub game: RepoPlan,
+    pub bot_template: RepoPlan,
+    pub python_version: String,
+    pub player_bot: BotPlan,
+    /// The number of bots generated from the template to play against the player's bot.
+    pub n_opponents: usize,
+    /// Where the game config that selects the players is written to.
+    pub player_config_path: PathBuf,
+    pub venv: VenvPlan,
+    /// Rough number of bytes to download.
+    pub download_size: u64,
+    /// Rough number of bytes on disk after the installation, including caches.
+    pub disk_size: u64,
+    pub warnings: Vec<PlanWarning>,
+}
+
+/// A problem that does not prevent the installation but players should know about.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PlanWarning {
+    /// A repository cannot be reached to resolve its latest commit.
+    Unreachable { url: String, error: String },
+    /// The install location is on a network drive.
+    NetworkDrive(PathBuf),
+    /// There is little free space left, both sizes are in bytes.
+    LowDiskSpace { available: u64, needed: u64 },
+    /// The free space cannot be determined, the message explains why.
+    UnknownDiskSpace(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct RepoPlan {
+    pub url: String,
+    pub target: PathBuf,
+    pub reference: RepoRef,
+    /// Number of bytes as reported by the host, `None` if unknown.
+    pub size: Option<u64>,
+}
+
+/// The commit that a repository will be cloned at.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RepoRef {
+    /// The commit is pinned by the game config.
+    Pinned(String),
+    /// The latest commit on the default branch.
+    Latest {
+        branch: Option<String>,
+        commit: String,
+    },
+    /// The remote could not be reached, the message explains why.
+    Unresolved(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct BotPlan {
+    pub id: String,
+    pub name: String,
+    pub path: PathBuf,
+    /// Display names and rendered values of the template arguments.
+    pub args: Vec<(String, String)>,
+}
+
+#[derive(Clone, Debug)]
+pub struct VenvPlan {
+    pub path: PathBuf,
+    /// Installed as editable packages.
+    pub packages: Vec<PathBuf>,
+}
+
+impl Project {
+    /// Compute what installing a game into `path` would do without writing anything.
+    ///
+    /// Contacts the game and bot template repositories to resolve unpinned commits.
+    /// Fails if the installation cannot succeed.
+    pub fn plan(
+        path: &Path,
+        game_config: &GameConfig,
+        player_bot_id: &str,
+        player_bot_name: &str,
+        player_bot_args: &[BotArg],
+    ) -> Result<InstallPlan> {
+        path_available_as_output_directory(path)?;
+        if matches!(game_config.python.venv, VenvKind::PerBot) {
+            bail!("Per-bot virtual environments are not supported yet");
+        }
+        let root = std::path::absolute(path)?;
+        let n_opponents = generated_opponents(&game_config.players)?;
+        let Some(config_file_name) = game_config.game.base_config_in_repo.file_name() else {
+            bail!("No config file name");
+        };
+
+        let game = plan_repo(
+            &game_config.game.url,
+            root.join(&game_config.name),
+            game_config.game.commit.as_deref(),
+        );
+        let bot_template = plan_repo(
+            &game_config.bot.template_url,
+            config::bot_template_repo_dir(&root),
+            game_config.bot.template_commit.as_deref(),
+        );
+        let player_bot = BotPlan {
+            id: player_bot_id.to_owned(),
+            name: player_bot_name.to_owned(),
+            path: config::bot_dir(&root, player_bot_id),
+            args: player_bot_args
+                .iter()
+                .map(|arg| (arg.display.clone(), arg.value.render_value()))
+                .collect(),
+        };
+        let venv = VenvPlan {
+            path: root.join(".venv"),
+            packages: vec![
+                player_bot.path.clone(),
+                config::bot_dir(&root, TEMPLATE_BOT_ID),
+                game.target.clone(),
+            ],
+        };
+
+        let python_installed = aigl_python::python_is_installed(
+            &game_config.python.version,
+            &config::python_dir(&config::launcher_dir(&root)),
+        )?;
+        // Every package except the game is a bot, the opponents share the template bot.
+        let n_bots = venv.packages.len() as u64 - 1;
+        let (download_size, disk_size) = estimate_sizes(
+            &game,
+            &bot_template,
+            n_bots,
+            &game_config.python.venv,
+            python_installed,
+        );
+
+        let mut plan = InstallPlan {
+            player_config_path: root.join(config_file_name),
+            root,
+            game,
+            bot_template,
+            python_version: game_config.python.version.clone(),
+            player_bot,
+            n_opponents,
+            venv,
+            download_size,
+            disk_size,
+            warnings: Vec::new(),
+        };
+        plan.warnings = plan_warnings(&plan);
+        Ok(plan)
+    }
+}
+
+fn plan_repo(url: &str, target: PathBuf, pinned_commit: Option<&str>) -> RepoPlan {
+    let reference = match pinned_commit {
+        Some(commit) => RepoRef::Pinned(commit.to_owned()),
+        None => match Repository::remote_head(url) {
+            Ok(head) => RepoRef::Latest {
+                branch: head.branch,
+                commit: head.commit,
+            },
+            Err(err) => RepoRef::Unresolved(format!("{err:#}")),
+        },
+    };
+    RepoPlan {
+        url: url.to_owned(),
+        target,
+        reference,
+        // The size only improves the estimate, unreachable repositories are reported above.
+        size: Repository::remote_size(url).ok().flatten(),
+    }
+}
+
+/// Rough number of bytes to download and on disk after installing.
+fn estimate_sizes(
+    game: &RepoPlan,
+    bot_template: &RepoPlan,
+    n_bots: u64,
+    venv_kind: &VenvKind,
+    python_installed: bool,
+) -> (u64, u64) {
+    let game_size = game.size.unwrap_or(REPO_SIZE);
+    let template_size = bot_template.size.unwrap_or(REPO_SIZE);
+    let n_venvs = match venv_kind {
+        VenvKind::Single => 1,
+        VenvKind::PerBot => n_bots,
+    };
+    let python_download_size = if python_installed {
+        0
+    } else {
+        PYTHON_DOWNLOAD_SIZE
+    };
+
+    let download_size = python_download_size + PACKAGES_DOWNLOAD_SIZE + game_size + template_size;
+    // Downloads are kept in the cache, each clone has a checkout next to its history
+    // and each bot is rendered from a checkout of the template.
+    let python_disk_size = if python_installed {
+        0
+    } else {
+        python_download_size + PYTHON_DISK_SIZE
+    };
+    let disk_size = python_disk_size
+        + PACKAGES_DOWNLOAD_SIZE
+        + n_venvs * PACKAGES_DISK_SIZE
+        + 2 * game_size
+        + 2 * template_size
+        + n_bots * template_size;
+    (download_size, disk_size)
+}
+
+fn plan_warnings(plan: &InstallPlan) -> Vec<PlanWarning> {
+    let mut warnings = Vec::new();
+    for repo in [&plan.game, &plan.bot_template] {
+        if let RepoRef::Unresolved(error) = &repo.reference {
+            warnings.push(PlanWarning::Unreachable {
+                url: repo.url.clone(),
+                error: error.clone(),
+            });
+        }
+    }
+    if is_network_path(&plan.root) {
+        warnings.push(PlanWarning::NetworkDrive(plan.root.clone()));
+    }
+    match available_space(&plan.root) {
+        // The sizes are estimates, so leave a generous margin.
+        Ok(available) if available < 2 * plan.disk_size => {
+            warnings.push(PlanWarning::LowDiskSpace {
+                available,
+                needed: plan.disk_size,
+            })
+        }
+        Ok(_) => {}
+        Err(err) => warnings.push(PlanWarning::UnknownDiskSpace(format!("{err:#}"))),
+    }
+    warnings
+}
+
+impl fmt::Display for PlanWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unreachable { url, error } => write!(f, "Cannot reach {url}: {error}"),
+            Self::NetworkDrive(path) => write!(
+                f,
+                "{} is on a network drive, the game may be slow or break when the connection is lost",
+                path.display()
+            ),
+            Self::LowDiskSpace { available, needed } => write!(
+                f,
+                "Only {} of free space left, the game needs about {}",
+                format_size(*available),
+                format_size(*needed)
+            ),
+            Self::UnknownDiskSpace(error) => f.write_str(error),
+        }
+    }
+}
+
+/// Format a number of bytes for humans, e.g., "1.5 GiB".
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::BotArgValue;
+    use pretty_assertions::assert_eq;
+
+    const GAME_CONFIG: &str = r#"
+name = "Planets"
+
+[game]
+url = "https://example.com/planets.git"
+commit = "0123abc0123abc0123abc0123abc0123abc0123a"
+base_config_in_repo = "config/config.toml"
+launch_args = ["planets", "{config}"]
+
+[bot]
+template_url = "https://example.com/planets-bot.git"
+template_commit = "4567def4567def4567def4567def4567def4567d"
+
+[players]
+mode = "free-for-all"
+n_min = 2
+n_initial = 4
+
+[python]
+version = "3.13"
+"#;
+
+    fn plan() -> InstallPlan {
+        let game_config = GameConfig::load_toml_str(GAME_CONFIG).unwrap();
+        let args = vec![BotArg {
+            var: "color".into(),
+            display: "Color".into(),
+            description: None,
+            required: true,
+            value: BotArgValue::color_from_string("#ff0000"),
+        }];
+        let root = std::env::temp_dir().join(format!("aigl-test-plan-{}", std::process::id()));
+        Project::plan(&root, &game_config, "bot_me", "Me", &args).unwrap()
+    }
+
+    #[test]
+    fn plan_lists_what_is_installed_where() {
+        let plan = plan();
+        assert_eq!(
+            plan.game.reference,
+            RepoRef::Pinned("0123abc0123abc0123abc0123abc0123abc0123a".into())
+        );
+        assert_eq!(plan.game.target, plan.root.join("Planets"));
+        assert_eq!(
+            plan.bot_template.reference,
+            RepoRef::Pinned("4567def4567def4567def4567def4567def4567d".into())
+        );
+        assert_eq!(plan.player_bot.path, plan.root.join("bot_me"));
+        assert_eq!(
+            plan.player_bot.args,
+            vec![("Color".to_string(), "#ff0000".to_string())]
+        );
+        assert_eq!(plan.n_opponents, 3);
+        assert_eq!(plan.player_config_path, plan.root.join("config.toml"));
+        assert_eq!(plan.venv.path, plan.root.join(".venv"));
+        assert_eq!(
+            plan.venv.packages,
+            vec![
+                plan.root.join("bot_me"),
+                plan.root.join("template_bot"),
+                plan.root.join("Planets"),
+            ]
+        );
+        assert!(plan.disk_size > plan.download_size);
+    }
+
+    fn repo_plan(size: Option<u64>) -> RepoPlan {
+        RepoPlan {
+            url: "https://example.com/repo.git".into(),
+            target: PathBuf::from("repo"),
+            reference: RepoRef::Unresolved("offline".into()),
+            size,
+        }
+    }
+
+    #[test]
+    fn sizes_use_the_reported_repository_sizes() {
+        let unknown = estimate_sizes(
+            &repo_plan(None),
+            &repo_plan(None),
+            2,
+            &VenvKind::Single,
+            false,
+        );
+        let known = estimate_sizes(
+            &repo_plan(Some(100 * MIB)),
+            &repo_plan(None),
+            2,
+            &VenvKind::Single,
+            false,
+        );
+        assert_eq!(known.0 - unknown.0, 100 * MIB - REPO_SIZE);
+        assert_eq!(known.1 - unknown.1, 2 * (100 * MIB - REPO_SIZE));
+    }
+
+    #[test]
+    fn installed_python_is_not_downloaded_again() {
+        let repo = repo_plan(None);
+        let missing = estimate_sizes(&repo, &repo, 2, &VenvKind::Single, false);
+        let installed = estimate_sizes(&repo, &repo, 2, &VenvKind::Single, true);
+        assert_eq!(missing.0 - installed.0, PYTHON_DOWNLOAD_SIZE);
+        assert_eq!(
+            missing.1 - installed.1,
+            PYTHON_DOWNLOAD_SIZE + PYTHON_DISK_SIZE
+        );
+    }
+
+    #[test]
+    fn sizes_grow_with_the_bots_and_their_environments() {
+        let repo = repo_plan(None);
+        let single = estimate_sizes(&repo, &repo, 2, &VenvKind::Single, false);
+        let more_bots = estimate_sizes(&repo, &repo, 3, &VenvKind::Single, false);
+        let per_bot = estimate_sizes(&repo, &repo, 2, &VenvKind::PerBot, false);
+        assert_eq!(more_bots.0, single.0);
+        assert_eq!(more_bots.1 - single.1, REPO_SIZE);
+        assert_eq!(per_bot.0, single.0);
+        assert_eq!(per_bot.1 - single.1, PACKAGES_DISK_SIZE);
+    }
+
+    #[test]
+    fn plan_does_not_write_anything() {
+        let plan = plan();
+        assert!(!plan.root.exists());
+    }
+
+    #[test]
+    fn plan_fails_for_non_empty_directory() {
+        let game_config = GameConfig::load_toml_str(GAME_CONFIG).unwrap();
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        assert!(Project::plan(root, &game_config, "bot_me", "Me", &[]).is_err());
+    }
+
+    #[test]
+    fn format_size_uses_binary_units() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KiB");
+        assert_eq!(format_size(3 * 1024 * MIB), "3.0 GiB");
+    }
+}
@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::config::PREFERENCES_FILE_NAME;
+use crate::user_file::UserFile;
+
+/// Settings that the launcher remembers between runs.
+///
+/// Unlike [`crate::config::user::UserConfig`], this file is written by the launcher.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Preferences {
+    /// Zoom factor of the user interface, e.g., 1.5 for 150 %.
+    pub zoom_factor: Option<f32>,
+    /// Name of the chosen theme, `None` to follow the dark or light mode of the OS.
+    pub theme: Option<String>,
+    /// Code of the chosen language, `None` to use the language of the OS.
+    pub language: Option<String>,
+    /// Code of the game that was installed last.
+    pub game_code: Option<String>,
+    /// Name of the player's last bot.
+    pub player_name: Option<String>,
+    /// Color of the player's last bot as `#rrggbb`.
+    pub color: Option<String>,
+    /// Directory that the last game was installed into.
+    pub install_parent: Option<PathBuf>,
+}
+
+impl UserFile for Preferences {
+    const FILE_NAME: &'static str = PREFERENCES_FILE_NAME;
+    const DESCRIPTION: &'static str = "preferences";
+}
+
+impl Preferences {
+    /// Whether anything about the player is remembered, see [`Preferences::forget_player`].
+    pub fn remembers_player(&self) -> bool {
+        self.game_code.is_some()
+            || self.player_name.is_some()
+            || self.color.is_some()
+            || self.install_parent.is_some()
+    }
+
+    /// Forget everything about the player but keep the settings of the user interface.
+    pub fn forget_player(&mut self) {
+        *self = Self {
+            zoom_factor: self.zoom_factor,
+            theme: self.theme.take(),
+            language: self.language.take(),
+            ..Self::default()
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn preferences_round_trip_through_toml() {
+        let dir =
+            std::env::temp_dir().join(format!("aigl-test-preferences-{}", std::process::id()));
+        let path = dir.join("aigl").join(PREFERENCES_FILE_NAME);
+        let preferences = Preferences {
+            zoom_factor: Some(1.2),
+            theme: Some("Light".into()),
+            language: Some("de".into()),
+            game_code: Some("test_game".into()),
+            player_name: Some("Ada".into()),
+            color: Some("#ff8800".into()),
+            install_parent: Some(dir.join("games")),
+        };
+        preferences.save_toml(&path).unwrap();
+        assert_eq!(Preferences::load_toml(&path).unwrap(), preferences);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn forgetting_the_player_keeps_the_interface_settings() {
+        let mut preferences = Preferences {
+            zoom_factor: Some(2.0),
+            theme: Some("Dark".into()),
+            language: Some("de".into()),
+            player_name: Some("Ada".into()),
+            install_parent: Some(PathBuf::from("games")),
+            ..Default::default()
+        };
+        assert!(preferences.remembers_player());
+        preferences.forget_player();
+        assert!(!preferences.remembers_player());
+        assert_eq!(
+            preferences,
+            Preferences {
+                zoom_factor: Some(2.0),
+                theme: Some("Dark".into()),
+                language: Some("de".into()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn missing_preferences_use_defaults() {
+        let preferences: Preferences = toml_edit::de::from_str("").unwrap();
+        assert_eq!(preferences, Preferences::default());
+    }
+}
@@ -1,12 +1,13 @@
-use anyhow::{Result, bail};
+use anyhow::{Context, Result, bail};
 use async_lock::Mutex;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use crate::bot::{Bot, BotArg, BotArgValue};
+use crate::bot::{Bot, BotArg};
 use crate::config;
 use crate::config::game::Players;
+use crate::package_id::TEMPLATE_BOT_ID;
 use crate::unique_selection::UniqueRandomSelection;
 use aigl_git::Repository;
 use aigl_system::fs::create_output_directory;
@@ -27,14 +28,23 @@ impl Project {
         player_bot_name: String,
         player_bot_args: Vec<BotArg>,
     ) -> Result<Arc<Mutex<Self>>> {
-        create_output_directory(&path).await?;
-        let launcher_dir = init_launcher_dir(&path).await?;
-        let python_cache = init_python_cache(&launcher_dir)?;
+        create_output_directory(&path)
+            .await
+            .with_context(|| format!("Failed to create the game directory {}", path.display()))?;
+        let launcher_dir = init_launcher_dir(&path).await.with_context(|| {
+            format!(
+                "Failed to set up the launcher directory in {}",
+                path.display()
+            )
+        })?;
+        let python_cache =
+            init_python_cache(&launcher_dir).context("Failed to set up the Python cache")?;
 
         let project = Arc::new(Mutex::new(Self {
             root: path,
             python_cache,
             cfg: config::project::ProjectConfig {
+                schema_version: config::project::PROJECT_CONFIG_VERSION,
                 game_config,
                 game_path: PathBuf::new(),
                 bot_paths: Vec::new(),
@@ -63,6 +73,7 @@ impl Project {
     }
 
     pub async fn open(path: PathBuf) -> Result<Self> {
+        let path = std::path::absolute(path)?;
         if !path.exists() {
             bail!("Project directory does not exist: {}", path.display());
         }
@@ -87,6 +98,10 @@ impl Project {
         })
     }
 
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
     pub fn python_cache(&self) -> &aigl_python::Cache {
         &self.python_cache
     }
@@ -115,11 +130,92 @@ impl Project {
         aigl_python::VirtualEnvironment::open(self.venv_path()?.to_owned(), self.python_cache())
     }
 
-    async fn save_config(&self) -> Result<()> {
+    /// Command that runs Python in the project's venv.
+    ///
+    /// uv is pointed at the project's directories through the command's environment
+    /// so that other projects in the same process are not affected.
+    pub fn python_command(&self) -> Result<std::process::Command> {
+        let mut command = self.venv()?.prepare_python_command();
+        let launcher_dir = config::launcher_dir(&self.root);
+        command
+            .env("UV_PYTHON_INSTALL_DIR", config::python_dir(&launcher_dir))
+            .env("UV_CACHE_DIR", config::uv_cache_dir(&launcher_dir));
+        Ok(command)
+    }
+
+    pub fn bot_path(&self, bot_id: &str) -> Result<&Path> {
+        self.cfg
+            .bot_paths
+            .iter()
+            .find(|path| path.file_name().is_some_and(|name| name == bot_id))
+            .map(PathBuf::as_path)
+            .ok_or_else(|| anyhow::anyhow!("No bot with id '{bot_id}'"))
+    }
+
+    /// The game config file that selects the players.
+    pub fn player_config_path(&self) -> Result<PathBuf> {
+        let base_config = &self.cfg.game_config.game.base_config_in_repo;
+        Ok(self.root.join(
+            base_config
+                .file_name()
+                .ok_or_else(|| anyhow::anyhow!("No config file name"))?,
+        ))
+    }
+
+    pub(crate) async fn save_config(&self) -> Result<()> {
         self.cfg
             .save_json(&config::project_config_file(&self.root))
             .await
     }
+
+    pub(crate) async fn create_venv(&mut self) -> Result<()> {
+        let python_config = &self.cfg.game_config.python;
+        if matches!(python_config.venv, config::game::VenvKind::PerBot) {
+            bail!("Per-bot virtual environments are not supported yet");
+        };
+        let venv_dir = self.root.join(".venv");
+        let venv = aigl_python::VirtualEnvironment::create(
+            venv_dir.clone(),
+            &python_config.version,
+            &config::python_dir(&config::launcher_dir(&self.root)),
+            &self.python_cache,
+        )
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to create a virtual environment with Python {} in {}",
+                python_config.version,
+                venv_dir.display()
+            )
+        })?;
+        self.install_packages(&venv)
+            .await
+            .context("Failed to install the game and bots into the virtual environment")?;
+
+        self.cfg.venv_paths.insert("game".into(), venv_dir);
+        Ok(())
+    }
+
+    /// Install the game and all bots into `venv` as editable packages.
+    pub(crate) async fn install_packages(
+        &self,
+        venv: &aigl_python::VirtualEnvironment,
+    ) -> Result<()> {
+        let requirements: Vec<_> = self
+            .cfg
+            .bot_paths
+            .iter()
+            .chain(std::iter::once(&self.cfg.game_path))
+            .map(|path| aigl_python::RequirementsSource::Editable(path.display().to_string()))
+            .collect();
+        let player_bot_path = self
+            .cfg
+            .bot_paths
+            .first()
+            .ok_or_else(|| anyhow::anyhow!("The project has no bots"))?;
+        let groups = [(player_bot_path.join("pyproject.toml"), "dev".into())];
+        aigl_python::install(&requirements, groups, true, &self.python_cache, venv).await
+    }
 }
 
 async fn init_launcher_dir(project_root: &Path) -> Result<PathBuf> {
@@ -157,11 +253,57 @@ fn open_python_cache(launcher_dir: &Path) -> Result<aigl_python::Cache> {
     aigl_python::Cache::discover(&config::uv_cache_dir(launcher_dir))
 }
 
+/// Clone a repository at the pinned commit if given and at the default branch otherwise.
+///
+/// Removes the partial clone if the pinned commit cannot be checked out.
+fn clone_verified(
+    url: &str,
+    target: &Path,
+    shallow: bool,
+    pinned_commit: Option<&str>,
+) -> Result<Repository> {
+    let Some(commit) = pinned_commit else {
+        return Repository::clone(url, target, shallow)
+            .with_context(|| format!("Failed to clone {url} into {}", target.display()));
+    };
+    if !is_full_commit_hash(commit) {
+        bail!("The game config pins {url} to '{commit}' which is not a full commit hash");
+    }
+    let existed = target.exists();
+    Repository::clone_at_commit(url, target, &commit.to_ascii_lowercase(), shallow)
+        .and_then(|repo| {
+            let actual = repo.head_commit_id()?;
+            if !actual.eq_ignore_ascii_case(commit) {
+                bail!("Checked out commit {actual} instead of {commit}");
+            }
+            Ok(repo)
+        })
+        .inspect_err(|_| {
+            if !existed {
+                let _ = std::fs::remove_dir_all(target);
+            }
+        })
+        .with_context(|| {
+            format!(
+                "Failed to clone {url} at commit {commit} into {}",
+                target.display()
+            )
+        })
+}
+
+/// Check if `commit` is a full SHA-1 commit hash.
+///
+/// Abbreviated hashes are not accepted for pins because they are easy to collide.
+pub(crate) fn is_full_commit_hash(commit: &str) -> bool {
+    commit.len() == 40 && commit.chars().all(|c| c.is_ascii_hexdigit())
+}
+
 async fn clone_game_repo(project: Arc<Mutex<Project>>) -> Result<()> {
     let mut project = project.lock().await;
     let url = project.cfg.game_config.game.url.to_owned();
     let target = project.root.join(&project.cfg.game_config.name);
-    match Repository::clone(&url, &target, false) {
+    let expected_commit = project.cfg.game_config.game.commit.clone();
+    match clone_verified(&url, &target, false, expected_commit.as_deref()) {
         Ok(_) => {
             project.cfg.game_path = target.clone();
             Ok(())
@@ -173,8 +315,9 @@ async fn clone_game_repo(project: Arc<Mutex<Project>>) -> Result<()> {
 async fn clone_bot_template_repo(project: Arc<Mutex<Project>>) -> Result<()> {
     let mut project = project.lock().await;
     let url = project.cfg.game_config.bot.template_url.to_owned();
-    let target = config::bot_templates_dir(&project.root).join("template");
-    match Repository::clone(&url, &target, true) {
+    let target = config::bot_template_repo_dir(&project.root);
+    let expected_commit = project.cfg.game_config.bot.template_commit.clone();
+    match clone_verified(&url, &target, true, expected_commit.as_deref()) {
         Ok(_) => {
             project.cfg.bot_template_path = target.clone();
             Ok(())
@@ -199,12 +342,12 @@ async fn set_up_initial_bots(
         player_bot_args,
     )
     .await?;
-    render_template_bot(project.clone(), "template_bot".into()).await?;
+    render_template_bot(project.clone(), TEMPLATE_BOT_ID.into()).await?;
 
     Ok(())
 }
 
-async fn render_player_bot(
+pub(crate) async fn render_player_bot(
     project: Arc<Mutex<Project>>,
     bot_id: String,
     bot_name: String,
@@ -212,9 +355,11 @@ async fn render_player_bot(
 ) -> Result<Bot> {
     let target = {
         let lock = project.lock().await;
-        lock.root.join(&bot_id)
+        config::bot_dir(&lock.root, &bot_id)
     };
-    let bot = Bot::render_template(project.clone(), &target, bot_id, bot_name, args).await?;
+    let bot = Bot::render_template(project.clone(), &target, bot_id.clone(), bot_name, args)
+        .await
+        .with_context(|| format!("Failed to create the bot '{bot_id}' from the template"))?;
     tokio::fs::remove_dir_all(bot.root().join(".git")).await?;
     let repo = Repository::init(bot.root())?;
     repo.commit_all("Init")?;
@@ -224,52 +369,37 @@ async fn render_player_bot(
 async fn render_template_bot(project: Arc<Mutex<Project>>, bot_id: String) -> Result<Bot> {
     let (target, bot_name, args) = {
         let mut lock = project.lock().await;
+        let bot_name = lock.name_gen.pop();
         let mut args = Vec::new();
-        for arg_spec in lock.cfg.game_config.bot.template_args.clone().values() {
-            let arg_value = match &arg_spec.ty {
-                config::game::BotTemplateArgType::Color => {
-                    BotArgValue::color_from_string(&lock.color_gen.pop())
-                }
-                config::game::BotTemplateArgType::String => {
-                    return Err(anyhow::anyhow!("String args are not supported yet"));
-                }
-                config::game::BotTemplateArgType::Path => {
-                    return Err(anyhow::anyhow!("Path args are not supported yet"));
-                }
-            };
-            args.push(BotArg {
-                var: arg_spec.var.clone(),
-                display: arg_spec.display.clone(),
-                value: arg_value,
-            });
+        for arg_spec in lock.cfg.game_config.bot.template_args.clone().into_values() {
+            let var = arg_spec.var.clone();
+            let color = matches!(arg_spec.ty, config::game::BotTemplateArgType::Color)
+                .then(|| lock.color_gen.pop());
+            let arg = BotArg::for_generated_bot(arg_spec, &bot_name, color.as_deref()).map_err(
+                |err| anyhow::anyhow!("Cannot choose a value for template bot arg '{var}': {err}"),
+            )?;
+            args.push(arg);
         }
-        (lock.root.join(&bot_id), lock.name_gen.pop(), args)
+        (config::bot_dir(&lock.root, &bot_id), bot_name, args)
     };
 
-    Bot::render_template(project.clone(), &target, bot_id, bot_name, args).await
+    Bot::render_template(project.clone(), &target, bot_id.clone(), bot_name, args)
+        .await
+        .with_context(|| format!("Failed to create the bot '{bot_id}' from the template"))
 }
 
 async fn set_up_game(project: Arc<Mutex<Project>>, player_bot_id: &str) -> Result<()> {
     clone_game_repo(project.clone()).await?;
 
-    let (base_config, new_config, n_bots) = {
+    let (base_config, new_config, n_opponents) = {
         let lock = project.lock().await;
         let base_config = lock
             .cfg
             .game_path
             .join(&lock.cfg.game_config.game.base_config_in_repo);
-        let new_config = lock.root.join(
-            base_config
-                .file_name()
-                .ok_or_else(|| anyhow::anyhow!("No config file name"))?,
-        );
-        let n_bots = match lock.cfg.game_config.players {
-            Players::FFA {
-                n_min, n_initial, ..
-            } => n_initial.unwrap_or(n_min) - 1,
-            Players::Teams { .. } => todo!("Teams are not yet supported"),
-        };
-        (base_config, new_config, n_bots)
+        let new_config = lock.player_config_path()?;
+        let n_opponents = generated_opponents(&lock.cfg.game_config.players)?;
+        (base_config, new_config, n_opponents)
     };
 
     let mut config = tokio::fs::read_to_string(&base_config)
@@ -286,8 +416,8 @@ async fn set_up_game(project: Arc<Mutex<Project>>, player_bot_id: &str) -> Resul
             Some("\n# --- Select bots for the game ---\n# Your bot:\n"),
         ));
 
-        for i in 1..n_bots {
-            let comment = if i == 1 {
+        for i in 0..n_opponents {
+            let comment = if i == 0 {
                 Some(
                     "\n# Add a number of extra bots:
 # Override the name and color to distinguish them in the game.\n",
@@ -297,7 +427,7 @@ async fn set_up_game(project: Arc<Mutex<Project>>, player_bot_id: &str) -> Resul
             };
             players.push(make_player_config_table(
                 &mut lock,
-                "template_bot",
+                TEMPLATE_BOT_ID,
                 true,
                 comment,
             ));
@@ -310,7 +440,19 @@ async fn set_up_game(project: Arc<Mutex<Project>>, player_bot_id: &str) -> Resul
     Ok(())
 }
 
-fn make_player_config_table(
+/// The number of template bots that play against the player's bot in the initial game config.
+///
+/// Together with the player's bot, the initial config has `n_initial` bots.
+pub(crate) fn generated_opponents(players: &Players) -> Result<usize> {
+    match players {
+        Players::FFA {
+            n_min, n_initial, ..
+        } => Ok(n_initial.unwrap_or(*n_min).saturating_sub(1)),
+        Players::Teams { .. } => bail!("Teams are not yet supported"),
+    }
+}
+
+pub(crate) fn make_player_config_table(
     project: &mut Project,
     bot_id: &str,
     use_overrides: bool,
@@ -343,35 +485,47 @@ async fn set_up_repos(
         player_bot_args,
     )
     .await?;
-    set_up_game(project, &player_bot_id).await?;
+    set_up_game(project, &player_bot_id)
+        .await
+        .context("Failed to set up the game")?;
     Ok(())
 }
 
 async fn create_venvs(project: Arc<Mutex<Project>>) -> Result<()> {
-    let mut lock = project.lock().await;
-    let python_config = &lock.cfg.game_config.python;
-    if matches!(python_config.venv, config::game::VenvKind::PerBot) {
-        bail!("Per-bot virtual environments are not supported yet");
-    };
-    let venv_dir = lock.root.join(".venv");
-    let venv = aigl_python::VirtualEnvironment::create(
-        venv_dir.clone(),
-        &python_config.version,
-        &lock.python_cache,
-    )
-    .await?;
+    project.lock().await.create_venv().await
+}
 
-    let requirements: Vec<_> = lock
-        .cfg()
-        .bot_paths
-        .iter()
-        .chain(std::iter::once(&lock.cfg().game_path))
-        .map(|path| aigl_python::RequirementsSource::Editable(path.display().to_string()))
-        .collect();
-    let player_bot_path = lock.cfg().bot_paths.first().unwrap();
-    let groups = [(player_bot_path.join("pyproject.toml"), "dev".into())];
-    aigl_python::install(&requirements, groups, true, &lock.python_cache, &venv).await?;
-
-    lock.cfg.venv_paths.insert("game".into(), venv_dir);
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn ffa(n_min: usize, n_initial: Option<usize>) -> Players {
+        Players::FFA {
+            n_min,
+            n_max: None,
+            n_initial,
+        }
+    }
+
+    #[test]
+    fn initial_game_has_n_initial_bots_including_the_player() {
+        assert_eq!(generated_opponents(&ffa(2, None)).unwrap(), 1);
+        assert_eq!(generated_opponents(&ffa(2, Some(4))).unwrap(), 3);
+        assert_eq!(generated_opponents(&ffa(1, None)).unwrap(), 0);
+        assert_eq!(generated_opponents(&ffa(0, None)).unwrap(), 0);
+    }
+
+    #[test]
+    fn teams_are_not_supported_yet() {
+        let teams = Players::Teams {
+            n_teams_min: 2,
+            n_teams_max: None,
+            n_bots_per_team_min: 1,
+            n_bots_per_team_max: None,
+            team_names: Vec::new(),
+            team_colors: Vec::new(),
+        };
+        assert!(generated_opponents(&teams).is_err());
+    }
 }
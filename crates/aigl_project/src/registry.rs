@@ -0,0 +1,90 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::config::PROJECT_REGISTRY_FILE_NAME;
+use crate::user_file::UserFile;
+
+/// Projects that the user has installed or opened, most recently used first.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProjectRegistry {
+    #[serde(default, rename = "project")]
+    pub projects: Vec<RegisteredProject>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RegisteredProject {
+    pub path: PathBuf,
+    /// Name of the game, shown when the project cannot be opened.
+    pub game_name: String,
+}
+
+impl UserFile for ProjectRegistry {
+    const FILE_NAME: &'static str = PROJECT_REGISTRY_FILE_NAME;
+    const DESCRIPTION: &'static str = "project registry";
+}
+
+impl ProjectRegistry {
+    /// Add a project or move it to the front if it is already registered.
+    pub fn add(&mut self, path: PathBuf, game_name: String) {
+        self.remove(&path);
+        self.projects
+            .insert(0, RegisteredProject { path, game_name });
+    }
+
+    pub fn remove(&mut self, path: &Path) {
+        self.projects.retain(|project| project.path != path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn registered(path: &str, game_name: &str) -> RegisteredProject {
+        RegisteredProject {
+            path: path.into(),
+            game_name: game_name.into(),
+        }
+    }
+
+    #[test]
+    fn add_moves_existing_project_to_the_front() {
+        let mut registry = ProjectRegistry::default();
+        registry.add("/games/race".into(), "Race".into());
+        registry.add("/games/territory".into(), "Territory".into());
+        registry.add("/games/race".into(), "Race 2".into());
+        assert_eq!(
+            registry.projects,
+            vec![
+                registered("/games/race", "Race 2"),
+                registered("/games/territory", "Territory")
+            ]
+        );
+    }
+
+    #[test]
+    fn remove_forgets_project() {
+        let mut registry = ProjectRegistry {
+            projects: vec![registered("/games/race", "Race")],
+        };
+        registry.remove(Path::new("/games/race"));
+        assert_eq!(registry, ProjectRegistry::default());
+    }
+
+    #[test]
+    fn registry_round_trips_through_toml() {
+        let dir = std::env::temp_dir().join(format!("aigl-test-registry-{}", std::process::id()));
+        let path = dir.join("aigl").join(PROJECT_REGISTRY_FILE_NAME);
+        let registry = ProjectRegistry {
+            projects: vec![
+                registered("/games/race", "Race"),
+                registered("/games/territory", "Territory"),
+            ],
+        };
+        registry.save_toml(&path).unwrap();
+        assert_eq!(ProjectRegistry::load_toml(&path).unwrap(), registry);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
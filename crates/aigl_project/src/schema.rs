@@ -0,0 +1,135 @@
+use schemars::generate::SchemaSettings;
+use serde_json::json;
+
+use crate::config::game::GameConfig;
+
+pub const GAME_CONFIG_SCHEMA_FILE_NAME: &str = "game_config.schema.json";
+
+/// Generate a JSON schema for game config files.
+///
+/// The schema includes metadata for the Taplo TOML language server.
+pub fn game_config_schema() -> serde_json::Value {
+    let mut schema = SchemaSettings::draft07()
+        .for_deserialize()
+        .into_generator()
+        .into_root_schema_for::<GameConfig>();
+    schema.insert(
+        "x-taplo-info".into(),
+        json!({
+            "authors": ["Jan-Lukas Wynen"],
+            "patterns": [r"^(.*(/|\\)resources(/|\\)games(/|\\)[^/\\]+\.toml)$"],
+        }),
+    );
+    schema.insert(
+        "x-taplo".into(),
+        json!({
+            "initKeys": ["name", "python", "game", "bot", "players"],
+        }),
+    );
+    schema.to_value()
+}
+
+/// The game config schema as pretty printed JSON.
+pub fn game_config_schema_string() -> String {
+    let mut schema = serde_json::to_string_pretty(&game_config_schema())
+        .expect("a JSON value can always be serialized");
+    schema.push('\n');
+    schema
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::path::PathBuf;
+
+    /// Set this environment variable to update the schema in `resources`.
+    const UPDATE_ENV_VAR: &str = "AIGL_UPDATE_SCHEMA";
+
+    fn checked_in_schema_path() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("../../resources/schemas")
+            .join(GAME_CONFIG_SCHEMA_FILE_NAME)
+    }
+
+    #[test]
+    fn checked_in_game_config_schema_is_up_to_date() {
+        let path = checked_in_schema_path();
+        if std::env::var_os(UPDATE_ENV_VAR).is_some() {
+            std::fs::write(&path, game_config_schema_string()).unwrap();
+            return;
+        }
+        // Compare values because the key order depends on serde_json's features.
+        let checked_in = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|schema| serde_json::from_str::<serde_json::Value>(&schema).ok());
+        assert!(
+            checked_in.as_ref() == Some(&game_config_schema()),
+            "{} is stale, run the tests with {UPDATE_ENV_VAR}=1 or `aigl schema --output {}`",
+            path.display(),
+            path.display(),
+        );
+    }
+
+    #[test]
+    fn game_config_schema_accepts_colour_alias() {
+        let schema = game_config_schema();
+        let arg_types = &schema["definitions"]["BotTemplateArgType"]["enum"];
+        assert_eq!(
+            arg_types,
+            &json!([
+                "string", "color", "colour", "path", "integer", "float", "bool", "choice"
+            ])
+        );
+    }
+
+    #[test]
+    fn game_config_schema_matches_bot_template_arg_types() {
+        use crate::config::game::BotTemplateArgType;
+
+        // The schema of `BotTemplateArgType` is written by hand, keep this list complete.
+        let variants = [
+            BotTemplateArgType::String,
+            BotTemplateArgType::Color,
+            BotTemplateArgType::Path,
+            BotTemplateArgType::Integer,
+            BotTemplateArgType::Float,
+            BotTemplateArgType::Bool,
+            BotTemplateArgType::Choice,
+        ];
+        for variant in &variants {
+            // Fails to compile when a variant is added, add it to `variants` as well.
+            match variant {
+                BotTemplateArgType::String
+                | BotTemplateArgType::Color
+                | BotTemplateArgType::Path
+                | BotTemplateArgType::Integer
+                | BotTemplateArgType::Float
+                | BotTemplateArgType::Bool
+                | BotTemplateArgType::Choice => {}
+            }
+        }
+
+        let schema = game_config_schema();
+        let arg_types = schema["definitions"]["BotTemplateArgType"]["enum"]
+            .as_array()
+            .unwrap();
+        for variant in &variants {
+            let name = serde_json::to_value(variant).unwrap();
+            assert!(arg_types.contains(&name), "{name} is missing in the schema");
+        }
+        for name in arg_types {
+            assert!(
+                serde_json::from_value::<BotTemplateArgType>(name.clone()).is_ok(),
+                "{name} in the schema is not a valid type"
+            );
+        }
+    }
+
+    #[test]
+    fn game_config_schema_has_players_modes() {
+        let schema = game_config_schema_string();
+        assert!(schema.contains("\"free-for-all\""));
+        assert!(schema.contains("\"teams\""));
+    }
+}
@@ -0,0 +1,237 @@
+use anyhow::{Result, anyhow};
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
+use ring::signature::{ED25519, Ed25519KeyPair, KeyPair, UnparsedPublicKey};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::config::user::UserConfig;
+
+/// Extension appended to the file name of a game config to get its signature file.
+pub const SIGNATURE_EXTENSION: &str = "sig";
+
+/// Keys built into the launcher.
+const BUILTIN_PUBLISHERS: &str = include_str!("../../../resources/keys/publishers.toml");
+
+/// A publisher of game configs and its ed25519 public key.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PublisherKey {
+    pub name: String,
+    /// Base64 encoded public key.
+    pub key: String,
+}
+
+/// Which publishers are trusted and whether unsigned games may be installed.
+#[derive(Clone, Debug, Default)]
+pub struct TrustPolicy {
+    pub publishers: Vec<PublisherKey>,
+    pub require_signatures: bool,
+}
+
+/// Result of checking the signature of a game config.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Verification {
+    Verified {
+        publisher: String,
+    },
+    /// There is no signature.
+    Unsigned,
+    /// The signature does not match any trusted key.
+    /// Either the publisher is unknown or the config was modified.
+    UnknownSigner,
+}
+
+#[derive(Deserialize)]
+struct PublisherKeys {
+    #[serde(default, rename = "publisher")]
+    publishers: Vec<PublisherKey>,
+}
+
+impl TrustPolicy {
+    /// Combine the built-in publishers with the settings in the user config.
+    pub fn load() -> Result<Self> {
+        let user_config = UserConfig::load()?;
+        let mut publishers = builtin_publishers()?;
+        publishers.extend(user_config.trusted_publishers);
+        Ok(Self {
+            publishers,
+            require_signatures: user_config.require_signed_games,
+        })
+    }
+
+    /// Trust only the built-in publishers and require signatures.
+    ///
+    /// Used when the user config cannot be read, so that a broken config never
+    /// allows games that the config would have rejected.
+    pub fn builtin_only() -> Self {
+        Self {
+            publishers: builtin_publishers().unwrap_or_default(),
+            require_signatures: true,
+        }
+    }
+
+    /// Check a detached signature of `content`.
+    pub fn verify(&self, content: &[u8], signature: Option<&str>) -> Result<Verification> {
+        let Some(signature) = signature else {
+            return Ok(Verification::Unsigned);
+        };
+        let signature = BASE64
+            .decode(signature.trim())
+            .map_err(|err| anyhow!("Malformed signature: {err}"))?;
+        for publisher in &self.publishers {
+            let Ok(key) = BASE64.decode(publisher.key.trim()) else {
+                continue;
+            };
+            if UnparsedPublicKey::new(&ED25519, key)
+                .verify(content, &signature)
+                .is_ok()
+            {
+                return Ok(Verification::Verified {
+                    publisher: publisher.name.clone(),
+                });
+            }
+        }
+        Ok(Verification::UnknownSigner)
+    }
+}
+
+impl Verification {
+    pub fn is_verified(&self) -> bool {
+        matches!(self, Self::Verified { .. })
+    }
+
+    /// Explain why a game is not verified, `None` if it is.
+    pub fn warning(&self) -> Option<&'static str> {
+        match self {
+            Self::Verified { .. } => None,
+            Self::Unsigned => Some("Unverified publisher: the game config is not signed."),
+            Self::UnknownSigner => Some(
+                "Unverified publisher: the game config is not signed by a trusted publisher \
+                or it was modified.",
+            ),
+        }
+    }
+}
+
+/// The path of the detached signature of the game config at `config_path`.
+pub fn signature_path(config_path: &Path) -> PathBuf {
+    let mut path = config_path.as_os_str().to_owned();
+    path.push(format!(".{SIGNATURE_EXTENSION}"));
+    PathBuf::from(path)
+}
+
+fn builtin_publishers() -> Result<Vec<PublisherKey>> {
+    let keys: PublisherKeys = toml_edit::de::from_str(BUILTIN_PUBLISHERS)?;
+    Ok(keys.publishers)
+}
+
+/// Generate a new key pair.
+///
+/// Returns the base64 encoded private key in PKCS#8 format and the public key.
+pub fn generate_key_pair() -> Result<(String, String)> {
+    let pkcs8 = Ed25519KeyPair::generate_pkcs8(&ring::rand::SystemRandom::new())
+        .map_err(|_| anyhow!("Failed to generate a key pair"))?;
+    let key_pair = load_key_pair(pkcs8.as_ref())?;
+    Ok((
+        BASE64.encode(pkcs8.as_ref()),
+        BASE64.encode(key_pair.public_key()),
+    ))
+}
+
+/// Sign `content` with a base64 encoded private key from [`generate_key_pair`].
+///
+/// Returns the base64 encoded signature.
+pub fn sign(content: &[u8], private_key: &str) -> Result<String> {
+    let pkcs8 = BASE64
+        .decode(private_key.trim())
+        .map_err(|err| anyhow!("Malformed private key: {err}"))?;
+    let key_pair = load_key_pair(&pkcs8)?;
+    Ok(BASE64.encode(key_pair.sign(content)))
+}
+
+fn load_key_pair(pkcs8: &[u8]) -> Result<Ed25519KeyPair> {
+    Ed25519KeyPair::from_pkcs8(pkcs8).map_err(|err| anyhow!("Invalid private key: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const CONFIG: &[u8] = b"name = \"Test\"\n";
+
+    fn policy(public_key: String) -> TrustPolicy {
+        TrustPolicy {
+            publishers: vec![PublisherKey {
+                name: "Test University".into(),
+                key: public_key,
+            }],
+            require_signatures: true,
+        }
+    }
+
+    #[test]
+    fn signed_config_is_verified() {
+        let (private_key, public_key) = generate_key_pair().unwrap();
+        let signature = sign(CONFIG, &private_key).unwrap();
+        assert_eq!(
+            policy(public_key).verify(CONFIG, Some(&signature)).unwrap(),
+            Verification::Verified {
+                publisher: "Test University".into()
+            }
+        );
+    }
+
+    #[test]
+    fn modified_config_is_not_verified() {
+        let (private_key, public_key) = generate_key_pair().unwrap();
+        let signature = sign(CONFIG, &private_key).unwrap();
+        assert_eq!(
+            policy(public_key)
+                .verify(b"name = \"Evil\"\n", Some(&signature))
+                .unwrap(),
+            Verification::UnknownSigner
+        );
+    }
+
+    #[test]
+    fn signature_of_unknown_publisher_is_not_verified() {
+        let (private_key, _) = generate_key_pair().unwrap();
+        let (_, other_public_key) = generate_key_pair().unwrap();
+        let signature = sign(CONFIG, &private_key).unwrap();
+        assert_eq!(
+            policy(other_public_key)
+                .verify(CONFIG, Some(&signature))
+                .unwrap(),
+            Verification::UnknownSigner
+        );
+    }
+
+    #[test]
+    fn missing_signature_is_unsigned() {
+        let (_, public_key) = generate_key_pair().unwrap();
+        assert_eq!(
+            policy(public_key).verify(CONFIG, None).unwrap(),
+            Verification::Unsigned
+        );
+    }
+
+    #[test]
+    fn signature_is_next_to_config() {
+        assert_eq!(
+            signature_path(Path::new("games/game.toml")),
+            PathBuf::from("games/game.toml.sig")
+        );
+    }
+
+    #[test]
+    fn builtin_publishers_parse() {
+        assert!(builtin_publishers().is_ok());
+    }
+
+    #[test]
+    fn fallback_policy_requires_signatures() {
+        let policy = TrustPolicy::builtin_only();
+        assert!(policy.require_signatures);
+        assert_eq!(policy.publishers, builtin_publishers().unwrap());
+    }
+}
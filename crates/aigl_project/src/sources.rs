@@ -0,0 +1,169 @@
+use anyhow::{Result, bail};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::config::user::UserConfig;
+use crate::link::GAME_FILE_EXTENSION;
+
+/// Environment variable with game sources separated by `;`.
+pub const GAME_SOURCES_ENV_VAR: &str = "AIGL_GAME_SOURCES";
+
+/// The public game sources, always searched last.
+pub const DEFAULT_GAME_SOURCE: &str = "https://jl-wynen.github.io/aigl/resources/games";
+
+/// A place that provides game configs.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GameSource {
+    /// Base URL of a server that hosts `<code>.toml` and `index.toml`.
+    Url(String),
+    /// Directory that contains `<code>.toml` and optionally `index.toml`.
+    Dir(PathBuf),
+    /// A single game config whose code is the file stem,
+    /// either a `.toml` file or a downloaded `.aigl` file.
+    File(PathBuf),
+}
+
+/// Game sources in the order in which they are searched.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameSources(pub Vec<GameSource>);
+
+impl GameSource {
+    /// Parse a source, relative paths are resolved against `base`.
+    ///
+    /// Fails for `http://` URLs because game configs decide which code is downloaded and run.
+    pub fn parse(source: &str, base: &Path) -> Result<Self> {
+        let source = source.trim();
+        let scheme = source.get(..8).unwrap_or(source).to_ascii_lowercase();
+        if scheme.starts_with("http://") {
+            bail!(
+                "Game source '{source}' is not encrypted, use an https:// URL instead. \
+                Game configs decide which code is downloaded and run."
+            );
+        }
+        if scheme == "https://" {
+            Ok(Self::Url(source.trim_end_matches('/').to_owned()))
+        } else {
+            let path = base.join(source);
+            if path
+                .extension()
+                .is_some_and(|ext| ext == "toml" || ext == GAME_FILE_EXTENSION)
+            {
+                Ok(Self::File(path))
+            } else {
+                Ok(Self::Dir(path))
+            }
+        }
+    }
+}
+
+impl fmt::Display for GameSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Url(url) => f.write_str(url),
+            Self::Dir(path) | Self::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+impl GameSources {
+    /// Collect sources from, in this order, the command line,
+    /// the [`GAME_SOURCES_ENV_VAR`] environment variable, the user config,
+    /// and [`DEFAULT_GAME_SOURCE`].
+    pub fn resolve(cli_sources: &[String]) -> Result<Self> {
+        let cwd = std::env::current_dir()?;
+        let env_sources = std::env::var(GAME_SOURCES_ENV_VAR).unwrap_or_default();
+        let user_config = UserConfig::load()?;
+        let config_dir = UserConfig::path()
+            .and_then(|path| path.parent().map(Path::to_path_buf))
+            .unwrap_or_else(|| cwd.clone());
+        Self::from_layers(&[
+            (cli_sources, &cwd),
+            (&split_env_sources(&env_sources), &cwd),
+            (&user_config.game_sources, &config_dir),
+            (&[DEFAULT_GAME_SOURCE.to_owned()], &cwd),
+        ])
+    }
+
+    fn from_layers(layers: &[(&[String], &Path)]) -> Result<Self> {
+        let mut sources = Vec::new();
+        for (layer, base) in layers {
+            for source in layer.iter().filter(|source| !source.trim().is_empty()) {
+                let source = GameSource::parse(source, base)?;
+                if !sources.contains(&source) {
+                    sources.push(source);
+                }
+            }
+        }
+        Ok(Self(sources))
+    }
+}
+
+impl Default for GameSources {
+    fn default() -> Self {
+        Self(vec![GameSource::Url(DEFAULT_GAME_SOURCE.to_owned())])
+    }
+}
+
+fn split_env_sources(value: &str) -> Vec<String> {
+    value.split(';').map(str::to_owned).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parse_source_kinds() {
+        let base = Path::new("/home/user");
+        assert_eq!(
+            GameSource::parse("https://games.example.edu/aigl/", base).unwrap(),
+            GameSource::Url("https://games.example.edu/aigl".into())
+        );
+        assert_eq!(
+            GameSource::parse("games", base).unwrap(),
+            GameSource::Dir("/home/user/games".into())
+        );
+        assert_eq!(
+            GameSource::parse("/srv/arena.toml", base).unwrap(),
+            GameSource::File("/srv/arena.toml".into())
+        );
+        assert_eq!(
+            GameSource::parse("arena.aigl", base).unwrap(),
+            GameSource::File("/home/user/arena.aigl".into())
+        );
+    }
+
+    #[test]
+    fn plain_http_sources_are_rejected() {
+        let base = Path::new("/home/user");
+        for source in ["http://games.example.edu/aigl", "HTTP://games.example.edu"] {
+            let err = GameSource::parse(source, base).unwrap_err();
+            assert!(err.to_string().contains("https://"), "{err}");
+        }
+        let env = split_env_sources("https://a.example.com;http://b.example.com");
+        assert!(GameSources::from_layers(&[(&env, Path::new("/cwd"))]).is_err());
+    }
+
+    #[test]
+    fn layers_are_searched_in_order_without_duplicates() {
+        let cli = ["local.toml".to_owned()];
+        let env = split_env_sources("https://a.example.com; ;/srv/games");
+        let config = ["https://a.example.com".to_owned(), "games".to_owned()];
+        let sources = GameSources::from_layers(&[
+            (&cli, Path::new("/cwd")),
+            (&env, Path::new("/cwd")),
+            (&config, Path::new("/config")),
+        ])
+        .unwrap();
+        assert_eq!(
+            sources,
+            GameSources(vec![
+                GameSource::File("/cwd/local.toml".into()),
+                GameSource::Url("https://a.example.com".into()),
+                GameSource::Dir("/srv/games".into()),
+                GameSource::Dir("/config/games".into()),
+            ])
+        );
+    }
+}
@@ -0,0 +1,555 @@
+use anyhow::{Context, Result, bail};
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::bot::{BotArg, BotArgValue};
+use crate::config::game::{self, BotTemplateArg, ConditionalPath, RenderConfig};
+use crate::config::template::{PostRenderCommand, TemplateManifest};
+
+/// A bot template with the settings of its manifest and the game config combined.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct BotTemplate {
+    /// Args that only the manifest defines.
+    manifest_args: Vec<BotTemplateArg>,
+    /// Path of the package relative to the template root, may contain placeholders.
+    pub(crate) package: Option<String>,
+    pub(crate) render: RenderConfig,
+    pub(crate) post_render: Vec<PostRenderCommand>,
+}
+
+impl BotTemplate {
+    /// Merge a manifest with the bot section of the game config.
+    ///
+    /// Args of the game config override manifest args with the same key.
+    pub(crate) fn new(manifest: TemplateManifest, game_bot: &game::Bot) -> Self {
+        let mut manifest_args: Vec<_> = manifest
+            .args
+            .into_iter()
+            .filter(|(key, _)| !game_bot.template_args.contains_key(key))
+            .collect();
+        manifest_args.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut render = manifest.render;
+        render.files.extend(game_bot.render.files.iter().cloned());
+        render
+            .conditional
+            .extend(game_bot.render.conditional.iter().cloned());
+
+        Self {
+            manifest_args: manifest_args.into_iter().map(|(_, arg)| arg).collect(),
+            package: manifest.package,
+            render,
+            post_render: manifest.post_render,
+        }
+    }
+
+    /// Add default values for the args that only the manifest defines.
+    ///
+    /// Players configure their bot before the template is downloaded,
+    /// so they cannot choose values for these args.
+    pub(crate) fn complete_args(&self, mut args: Vec<BotArg>) -> Result<Vec<BotArg>> {
+        for template_arg in &self.manifest_args {
+            if args.iter().any(|arg| arg.var == template_arg.var) {
+                continue;
+            }
+            let value = BotArgValue::from_template_arg(template_arg).map_err(|err| {
+                anyhow::anyhow!(
+                    "Invalid default of template arg '{}': {err}",
+                    template_arg.var
+                )
+            })?;
+            let arg = BotArg {
+                value,
+                ..BotArg::default_from_template_arg(template_arg.clone())
+            };
+            if let Err(err) = arg.validate() {
+                bail!(
+                    "Template arg '{}' needs a value: {err}. \
+                    Add it to [bot.template_args] in the game config.",
+                    template_arg.var
+                );
+            }
+            args.push(arg);
+        }
+        Ok(args)
+    }
+
+    /// Check that the render conditions of the game config and the manifest
+    /// only use variables in `vars`, which include the args of both.
+    pub(crate) fn validate_conditions(&self, vars: &TemplateVars) -> Result<()> {
+        for conditional in &self.render.conditional {
+            let name = conditional.when.trim().trim_start_matches('!').trim();
+            if !vars.contains(name) {
+                bail!(
+                    "Unknown variable '{name}' in the render condition of '{}'. \
+                    It must be a template arg of the game config or the template manifest.",
+                    conditional.path
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Run the post-render commands in the bot directory.
+    pub(crate) async fn run_post_render(&self, root: &Path, vars: &TemplateVars) -> Result<()> {
+        for PostRenderCommand { command } in &self.post_render {
+            let Some((program, args)) = command.split_first() else {
+                continue;
+            };
+            let output = tokio::process::Command::new(vars.render(program))
+                .args(args.iter().map(|arg| vars.render(arg)))
+                .current_dir(root)
+                .output()
+                .await
+                .with_context(|| format!("Failed to run `{}`", command.join(" ")))?;
+            if !output.status.success() {
+                bail!(
+                    "Post-render command `{}` failed:\n{}",
+                    command.join(" "),
+                    String::from_utf8_lossy(&output.stderr).trim()
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Variables that can be used in placeholders and conditions.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TemplateVars {
+    vars: HashMap<String, TemplateVar>,
+}
+
+#[derive(Clone, Debug)]
+struct TemplateVar {
+    text: String,
+    truthy: bool,
+}
+
+impl TemplateVars {
+    pub(crate) fn new(bot_id: &str, bot_name: &str, args: &[BotArg]) -> Self {
+        let mut vars = Self::default();
+        vars.insert("bot_id", bot_id.to_owned(), !bot_id.is_empty());
+        vars.insert("bot_name", bot_name.to_owned(), !bot_name.is_empty());
+        for arg in args {
+            vars.insert(&arg.var, arg.value.render_value(), arg.value.is_truthy());
+        }
+        vars
+    }
+
+    fn insert(&mut self, name: &str, text: String, truthy: bool) {
+        self.vars
+            .insert(name.to_owned(), TemplateVar { text, truthy });
+    }
+
+    pub(crate) fn contains(&self, name: &str) -> bool {
+        self.vars.contains_key(name)
+    }
+
+    /// Replace all `{{ var }}` placeholders of known variables.
+    ///
+    /// Unknown placeholders are left unchanged because files may use
+    /// the same syntax for other tools, e.g., GitHub workflows.
+    pub(crate) fn render(&self, text: &str) -> String {
+        let mut rendered = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find("{{") {
+            let Some(len) = rest[start..].find("}}") else {
+                break;
+            };
+            let placeholder = &rest[start..start + len + 2];
+            rendered.push_str(&rest[..start]);
+            match self.vars.get(placeholder[2..len].trim()) {
+                Some(var) => rendered.push_str(&var.text),
+                None => rendered.push_str(placeholder),
+            }
+            rest = &rest[start + len + 2..];
+        }
+        rendered.push_str(rest);
+        rendered
+    }
+
+    /// Evaluate a condition of the form `var` or `!var`.
+    pub(crate) fn condition(&self, condition: &str) -> Result<bool> {
+        let condition = condition.trim();
+        let (negate, name) = match condition.strip_prefix('!') {
+            Some(name) => (true, name.trim()),
+            None => (false, condition),
+        };
+        match self.vars.get(name) {
+            Some(var) => Ok(var.truthy != negate),
+            None => bail!("Unknown variable '{name}' in condition '{condition}'"),
+        }
+    }
+}
+
+/// Render a copied template in place.
+pub(crate) fn render_template_dir(
+    root: &Path,
+    vars: &TemplateVars,
+    config: &RenderConfig,
+) -> Result<()> {
+    let renderer = Renderer {
+        vars,
+        files: build_glob_set(config.files.iter().map(String::as_str))?,
+        conditional: config
+            .conditional
+            .iter()
+            .map(|conditional| Ok((build_glob_set([conditional.path.as_str()])?, conditional)))
+            .collect::<Result<_>>()?,
+    };
+    renderer.render_dir(root, "")
+}
+
+pub(crate) fn build_glob_set<'a>(patterns: impl IntoIterator<Item = &'a str>) -> Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder
+            .add(Glob::new(pattern).with_context(|| format!("Invalid glob pattern '{pattern}'"))?);
+    }
+    Ok(builder.build()?)
+}
+
+struct Renderer<'a> {
+    vars: &'a TemplateVars,
+    files: GlobSet,
+    conditional: Vec<(GlobSet, &'a ConditionalPath)>,
+}
+
+impl Renderer<'_> {
+    /// Render all entries of `dir` whose rendered path relative to the root is `rel_dir`.
+    fn render_dir(&self, dir: &Path, rel_dir: &str) -> Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let name = entry.file_name();
+            let Some(name) = name.to_str() else {
+                continue;
+            };
+            if name == ".git" {
+                continue;
+            }
+            let rendered_name = self.render_name(name)?;
+            let rel_path = if rel_dir.is_empty() {
+                rendered_name.clone()
+            } else {
+                format!("{rel_dir}/{rendered_name}")
+            };
+            let path = entry.path();
+            let file_type = entry.file_type()?;
+            let is_dir = file_type.is_dir();
+
+            if !self.keep(&rel_path)? {
+                if is_dir {
+                    fs::remove_dir_all(&path)?;
+                } else {
+                    remove_file_or_link(&path)?;
+                }
+                continue;
+            }
+            // Reading, writing, or renaming through a link could modify files outside of the bot.
+            if file_type.is_symlink() {
+                continue;
+            }
+
+            if is_dir {
+                self.render_dir(&path, &rel_path)?;
+            } else if self.files.is_match(&rel_path) {
+                self.render_file(&path)?;
+            }
+            if rendered_name != name {
+                let target = path.with_file_name(&rendered_name);
+                if target.exists() {
+                    bail!("Cannot rename {name} to {rendered_name}, the target already exists");
+                }
+                fs::rename(&path, target)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Render a file name so that it stays a single path component.
+    ///
+    /// Variables may contain any text, e.g., a bot called "AC/DC".
+    fn render_name(&self, name: &str) -> Result<String> {
+        let rendered = self.vars.render(name);
+        if rendered == name {
+            return Ok(rendered);
+        }
+        let rendered = rendered.replace(['/', '\\', ':', '*', '?', '"', '<', '>', '|'], "_");
+        if rendered.trim_matches('.').is_empty() {
+            bail!("Cannot rename {name} to '{rendered}'");
+        }
+        Ok(rendered)
+    }
+
+    fn render_file(&self, path: &Path) -> Result<()> {
+        let bytes = fs::read(path)?;
+        // Binary files cannot contain placeholders.
+        let Ok(content) = String::from_utf8(bytes) else {
+            return Ok(());
+        };
+        let rendered = self.vars.render(&content);
+        if rendered != content {
+            fs::write(path, rendered)?;
+        }
+        Ok(())
+    }
+
+    fn keep(&self, rel_path: &str) -> Result<bool> {
+        for (glob, conditional) in &self.conditional {
+            if glob.is_match(rel_path) && !self.vars.condition(&conditional.when)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+/// Remove a file or a link, which may point to a directory.
+fn remove_file_or_link(path: &Path) -> Result<()> {
+    // Windows removes links to directories with `remove_dir`.
+    if fs::remove_file(path).is_err() {
+        fs::remove_dir(path)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BotArgValue;
+    use pretty_assertions::assert_eq;
+
+    fn vars() -> TemplateVars {
+        TemplateVars::new(
+            "bot_me",
+            "Me",
+            &[
+                BotArg {
+                    var: "USE_NUMPY".into(),
+                    display: "Use numpy".into(),
+                    description: None,
+                    required: false,
+                    value: BotArgValue::Bool(false),
+                },
+                BotArg {
+                    var: "BOT_COLOR".into(),
+                    display: "Color".into(),
+                    description: None,
+                    required: false,
+                    value: BotArgValue::Color([255, 0, 0]),
+                },
+            ],
+        )
+    }
+
+    fn string_arg(var: &str, default: Option<&str>, required: bool) -> BotTemplateArg {
+        BotTemplateArg {
+            var: var.into(),
+            display: var.into(),
+            ty: Default::default(),
+            description: None,
+            required,
+            default: default.map(|default| game::BotTemplateArgDefault::String(default.into())),
+            min: None,
+            max: None,
+            options: Vec::new(),
+        }
+    }
+
+    fn game_bot(args: &[(&str, BotTemplateArg)]) -> game::Bot {
+        game::Bot {
+            template_url: "https://example.com/bot.git".into(),
+            template_commit: None,
+            template_args: args
+                .iter()
+                .map(|(key, arg)| (key.to_string(), arg.clone()))
+                .collect(),
+            render: RenderConfig {
+                files: vec!["README.md".into()],
+                conditional: Vec::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn merge_manifest_with_game_config() {
+        let manifest = TemplateManifest {
+            args: [
+                ("style".to_owned(), string_arg("STYLE", Some("fast"), false)),
+                (
+                    "color".to_owned(),
+                    string_arg("MANIFEST_COLOR", None, false),
+                ),
+            ]
+            .into(),
+            package: Some("src/{{ bot_id }}".into()),
+            render: RenderConfig {
+                files: vec!["**/*.py".into()],
+                conditional: Vec::new(),
+            },
+            post_render: Vec::new(),
+        };
+        let template = BotTemplate::new(
+            manifest,
+            &game_bot(&[("color", string_arg("BOT_COLOR", None, false))]),
+        );
+        assert_eq!(template.render.files, ["**/*.py", "README.md"]);
+
+        let args = template.complete_args(Vec::new()).unwrap();
+        let vars: Vec<_> = args.iter().map(|arg| arg.var.as_str()).collect();
+        // The game config overrides `color`.
+        assert_eq!(vars, ["STYLE"]);
+        assert_eq!(args[0].value, BotArgValue::String("fast".into()));
+    }
+
+    #[test]
+    fn complete_args_requires_values_for_required_manifest_args() {
+        let manifest = TemplateManifest {
+            args: [("token".to_owned(), string_arg("TOKEN", None, true))].into(),
+            ..Default::default()
+        };
+        let template = BotTemplate::new(manifest, &game_bot(&[]));
+        assert!(template.complete_args(Vec::new()).is_err());
+    }
+
+    #[test]
+    fn conditions_may_use_args_of_the_game_config_and_the_manifest() {
+        let conditional = |when: &str| ConditionalPath {
+            path: "numpy.py".into(),
+            when: when.into(),
+        };
+        let manifest = TemplateManifest {
+            args: [("numpy".to_owned(), string_arg("USE_NUMPY", None, false))].into(),
+            render: RenderConfig {
+                files: Vec::new(),
+                conditional: vec![conditional("!USE_NUMPY")],
+            },
+            ..Default::default()
+        };
+        let mut game_bot = game_bot(&[("color", string_arg("BOT_COLOR", None, false))]);
+        game_bot.render.conditional = vec![conditional("BOT_COLOR")];
+        let template = BotTemplate::new(manifest, &game_bot);
+        let args = template
+            .complete_args(vec![BotArg::default_from_template_arg(string_arg(
+                "BOT_COLOR",
+                None,
+                false,
+            ))])
+            .unwrap();
+        let vars = TemplateVars::new("bot_me", "Me", &args);
+        assert!(template.validate_conditions(&vars).is_ok());
+
+        let mut game_bot = game_bot.clone();
+        game_bot.render.conditional = vec![conditional("TYPO")];
+        let template = BotTemplate::new(TemplateManifest::default(), &game_bot);
+        let err = template.validate_conditions(&vars).unwrap_err();
+        assert!(err.to_string().contains("'TYPO'"));
+    }
+
+    #[test]
+    fn render_replaces_known_placeholders() {
+        assert_eq!(
+            vars().render("import {{bot_id}}\n# {{ bot_name }} is {{ BOT_COLOR }}"),
+            "import bot_me\n# Me is #ff0000"
+        );
+    }
+
+    #[test]
+    fn render_keeps_unknown_and_unclosed_placeholders() {
+        assert_eq!(
+            vars().render("run: ${{ github.sha }} {{ bot_id"),
+            "run: ${{ github.sha }} {{ bot_id"
+        );
+    }
+
+    #[test]
+    fn conditions_use_truthiness() {
+        let vars = vars();
+        assert!(!vars.condition("USE_NUMPY").unwrap());
+        assert!(vars.condition("!USE_NUMPY").unwrap());
+        assert!(vars.condition("bot_name").unwrap());
+        assert!(vars.condition("missing").is_err());
+    }
+
+    #[test]
+    fn render_template_dir_renders_contents_names_and_conditions() {
+        let root = std::env::temp_dir().join(format!("aigl-test-render-{}", std::process::id()));
+        let package = root.join("src/{{ bot_id }}");
+        fs::create_dir_all(&package).unwrap();
+        fs::write(root.join("README.md"), "# {{ bot_name }}").unwrap();
+        fs::write(package.join("bot.py"), "from {{ bot_id }} import x").unwrap();
+        fs::write(package.join("numpy_utils.py"), "import numpy").unwrap();
+        fs::write(root.join("notes.txt"), "{{ bot_id }}").unwrap();
+
+        let config = RenderConfig {
+            files: vec!["**/*.py".into(), "README.md".into()],
+            conditional: vec![ConditionalPath {
+                path: "**/numpy_utils.py".into(),
+                when: "USE_NUMPY".into(),
+            }],
+        };
+        render_template_dir(&root, &vars(), &config).unwrap();
+
+        let package = root.join("src/bot_me");
+        assert_eq!(fs::read_to_string(root.join("README.md")).unwrap(), "# Me");
+        assert_eq!(
+            fs::read_to_string(package.join("bot.py")).unwrap(),
+            "from bot_me import x"
+        );
+        assert!(!package.join("numpy_utils.py").exists());
+        // Not selected for rendering.
+        assert_eq!(
+            fs::read_to_string(root.join("notes.txt")).unwrap(),
+            "{{ bot_id }}"
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn render_template_dir_sanitizes_rendered_names() {
+        let root =
+            std::env::temp_dir().join(format!("aigl-test-render-names-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("{{ bot_name }}.md"), "").unwrap();
+        let config = RenderConfig::default();
+
+        render_template_dir(&root, &TemplateVars::new("bot_ac", "AC/DC", &[]), &config).unwrap();
+        assert!(root.join("AC_DC.md").is_file());
+
+        fs::write(root.join("{{ bot_name }}"), "").unwrap();
+        assert!(
+            render_template_dir(&root, &TemplateVars::new("bot_up", "..", &[]), &config).is_err()
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn render_template_dir_does_not_follow_links() {
+        let dir =
+            std::env::temp_dir().join(format!("aigl-test-render-links-{}", std::process::id()));
+        let root = dir.join("bot");
+        fs::create_dir_all(&root).unwrap();
+        fs::write(dir.join("outside.md"), "{{ bot_name }}").unwrap();
+        std::os::unix::fs::symlink("../outside.md", root.join("notes.md")).unwrap();
+        std::os::unix::fs::symlink("../outside.md", root.join("{{ bot_id }}.md")).unwrap();
+
+        let config = RenderConfig {
+            files: vec!["*.md".into()],
+            conditional: Vec::new(),
+        };
+        render_template_dir(&root, &vars(), &config).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(dir.join("outside.md")).unwrap(),
+            "{{ bot_name }}"
+        );
+        assert!(root.join("{{ bot_id }}.md").is_symlink());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,53 @@
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::path::{Path, PathBuf};
+
+use crate::config::user_config_dir;
+
+/// A TOML file in the user config directory that the launcher reads and writes.
+pub trait UserFile: Default + Serialize + DeserializeOwned {
+    const FILE_NAME: &'static str;
+    /// What the file contains, used in error messages.
+    const DESCRIPTION: &'static str;
+
+    fn path() -> Option<PathBuf> {
+        user_config_dir().map(|dir| dir.join(Self::FILE_NAME))
+    }
+
+    /// Load the file or return the default if there is none.
+    fn load() -> Result<Self> {
+        match Self::path() {
+            Some(path) if path.exists() => Self::load_toml(&path),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    fn load_toml(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        toml_edit::de::from_str(&content)
+            .with_context(|| format!("Invalid {} {}", Self::DESCRIPTION, path.display()))
+    }
+
+    fn save(&self) -> Result<()> {
+        let Some(path) = Self::path() else {
+            anyhow::bail!("Cannot determine the user config directory");
+        };
+        self.save_toml(&path)
+    }
+
+    fn save_toml(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, toml_edit::ser::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Load the file, apply `modify`, and save it.
+    fn update(modify: impl FnOnce(&mut Self)) -> Result<()> {
+        let mut value = Self::load()?;
+        modify(&mut value);
+        value.save()
+    }
+}
@@ -0,0 +1,638 @@
+use std::fmt;
+use std::ops::Range;
+
+use crate::BotArgValue;
+use crate::catalog::parse_version;
+use crate::config::game::{BotTemplateArg, BotTemplateArgType, GameConfig, Players};
+use crate::template::build_glob_set;
+
+/// Bot config keys that the launcher always sets.
+const BUILTIN_BOT_VARS: [&str; 2] = ["id", "name"];
+
+/// A problem in a game config file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValidationError {
+    pub message: String,
+    pub span: Option<Span>,
+}
+
+/// A range in a TOML file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Span {
+    pub start: Location,
+    pub end: Location,
+}
+
+/// A 1-based line and column in a TOML file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// All problems found in a game config file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValidationErrors(pub Vec<ValidationError>);
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.span {
+            Some(span) => write!(
+                f,
+                "{}:{}: {}",
+                span.start.line, span.start.column, self.message
+            ),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, error) in self.0.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+impl GameConfig {
+    /// Load a game config and check it for mistakes that deserialization cannot catch.
+    ///
+    /// Returns all problems found in the file.
+    pub fn validate_toml_str(content: &str) -> Result<Self, ValidationErrors> {
+        let document = toml_edit::Document::parse(content).map_err(|err| {
+            ValidationErrors(vec![ValidationError {
+                message: err.message().to_owned(),
+                span: err.span().map(|span| Span::from_offsets(content, span)),
+            }])
+        })?;
+        let config: Self = toml_edit::de::from_str(content).map_err(|err| {
+            ValidationErrors(vec![ValidationError {
+                message: err.message().to_owned(),
+                span: err.span().map(|span| Span::from_offsets(content, span)),
+            }])
+        })?;
+
+        let validator = Validator {
+            content,
+            document: &document,
+            errors: Vec::new(),
+        };
+        let errors = validator.validate(&config);
+        if errors.is_empty() {
+            Ok(config)
+        } else {
+            Err(ValidationErrors(errors))
+        }
+    }
+}
+
+struct Validator<'a> {
+    content: &'a str,
+    document: &'a toml_edit::Document<&'a str>,
+    errors: Vec<ValidationError>,
+}
+
+impl Validator<'_> {
+    fn validate(mut self, config: &GameConfig) -> Vec<ValidationError> {
+        self.validate_game(config);
+        self.validate_bot(config);
+        self.validate_players(config);
+        self.validate_catalog(config);
+        self.validate_translations(config);
+        self.errors
+    }
+
+    fn validate_game(&mut self, config: &GameConfig) {
+        if config.game.url.trim().is_empty() {
+            self.error(&["game", "url"], "The game URL must not be empty");
+        }
+        if !config
+            .game
+            .launch_args
+            .iter()
+            .any(|arg| arg.contains("{config}"))
+        {
+            self.error(
+                &["game", "launch_args"],
+                "`launch_args` must contain '{config}' to pass the game config to the game",
+            );
+        }
+        if let Some(commit) = &config.game.commit {
+            self.validate_commit(&["game", "commit"], commit);
+        }
+    }
+
+    fn validate_bot(&mut self, config: &GameConfig) {
+        if config.bot.template_url.trim().is_empty() {
+            self.error(
+                &["bot", "template_url"],
+                "The bot template URL must not be empty",
+            );
+        }
+
+        if let Some(commit) = &config.bot.template_commit {
+            self.validate_commit(&["bot", "template_commit"], commit);
+        }
+
+        let mut args: Vec<_> = config.bot.template_args.iter().collect();
+        // Report in a deterministic order.
+        args.sort_by_key(|(key, _)| self.offset(&["bot", "template_args", key]));
+        let mut seen = Vec::new();
+        for (key, arg) in args {
+            let path = ["bot", "template_args", key.as_str(), "var"];
+            if BUILTIN_BOT_VARS.contains(&arg.var.as_str()) {
+                self.error(
+                    &path,
+                    format!(
+                        "Template arg '{key}' uses the built-in variable '{}'",
+                        arg.var
+                    ),
+                );
+            } else if seen.contains(&&arg.var) {
+                self.error(
+                    &path,
+                    format!("Variable '{}' is used by multiple template args", arg.var),
+                );
+            }
+            seen.push(&arg.var);
+
+            let arg_path = ["bot", "template_args", key.as_str()];
+            self.validate_template_arg(&arg_path, arg);
+        }
+
+        self.validate_render(config);
+    }
+
+    fn validate_render(&mut self, config: &GameConfig) {
+        let render = &config.bot.render;
+        for pattern in &render.files {
+            if let Err(err) = build_glob_set([pattern.as_str()]) {
+                self.error(&["bot", "render", "files"], format!("{err:#}"));
+            }
+        }
+        for conditional in &render.conditional {
+            if let Err(err) = build_glob_set([conditional.path.as_str()]) {
+                self.error(&["bot", "render", "conditional"], format!("{err:#}"));
+            }
+            // The variable may be an arg of the template manifest, which is only
+            // known at install time, see `BotTemplate::validate_conditions`.
+            let name = conditional.when.trim().trim_start_matches('!').trim();
+            if name.is_empty() {
+                self.error(
+                    &["bot", "render", "conditional"],
+                    format!("Condition '{}' does not name a variable", conditional.when),
+                );
+            }
+        }
+    }
+
+    fn validate_template_arg(&mut self, path: &[&str; 3], arg: &BotTemplateArg) {
+        let numeric = matches!(
+            arg.ty,
+            BotTemplateArgType::Integer | BotTemplateArgType::Float
+        );
+        let key_path = |key| [path[0], path[1], path[2], key];
+        if !numeric && (arg.min.is_some() || arg.max.is_some()) {
+            self.error(
+                &key_path(if arg.min.is_some() { "min" } else { "max" }),
+                "`min` and `max` are only allowed for integer and float args",
+            );
+        }
+        if let (Some(min), Some(max)) = (arg.min, arg.max) {
+            if min > max {
+                self.error(
+                    &key_path("max"),
+                    format!("`max` ({max}) must not be less than `min` ({min})"),
+                );
+            }
+        }
+        match arg.ty {
+            BotTemplateArgType::Choice if arg.options.is_empty() => {
+                self.error(path, "Choice args need at least one option in `options`");
+            }
+            BotTemplateArgType::Choice => {}
+            _ if !arg.options.is_empty() => {
+                self.error(
+                    &key_path("options"),
+                    "`options` is only allowed for choice args",
+                );
+            }
+            _ => {}
+        }
+        if arg.default.is_some() {
+            let result = BotArgValue::from_template_arg(arg)
+                .and_then(|value| value.validate().map_err(|err| err.to_string()));
+            if let Err(message) = result {
+                self.error(&key_path("default"), format!("Invalid default: {message}"));
+            }
+        }
+    }
+
+    fn validate_players(&mut self, config: &GameConfig) {
+        match &config.players {
+            Players::FFA {
+                n_min,
+                n_max,
+                n_initial,
+            } => {
+                if *n_min < 1 {
+                    self.error(&["players", "n_min"], "`n_min` must be at least 1");
+                }
+                if let Some(n_max) = n_max {
+                    if n_min > n_max {
+                        self.error(
+                            &["players", "n_max"],
+                            format!("`n_max` ({n_max}) must not be less than `n_min` ({n_min})"),
+                        );
+                    }
+                }
+                if let Some(n_initial) = n_initial {
+                    if *n_initial < 1 {
+                        self.error(&["players", "n_initial"], "`n_initial` must be at least 1");
+                    } else if n_initial < n_min || n_max.is_some_and(|n_max| *n_initial > n_max) {
+                        self.error(
+                            &["players", "n_initial"],
+                            "`n_initial` must be between `n_min` and `n_max`",
+                        );
+                    }
+                }
+            }
+            Players::Teams {
+                n_teams_min,
+                n_teams_max,
+                n_bots_per_team_min,
+                n_bots_per_team_max,
+                team_names,
+                team_colors,
+            } => {
+                if *n_teams_min < 1 {
+                    self.error(
+                        &["players", "n_teams_min"],
+                        "`n_teams_min` must be at least 1",
+                    );
+                }
+                if n_teams_max.is_some_and(|n_max| n_max < *n_teams_min) {
+                    self.error(
+                        &["players", "n_teams_max"],
+                        "`n_teams_max` must not be less than `n_teams_min`",
+                    );
+                }
+                if *n_bots_per_team_min < 1 {
+                    self.error(
+                        &["players", "n_bots_per_team_min"],
+                        "`n_bots_per_team_min` must be at least 1",
+                    );
+                }
+                if n_bots_per_team_max.is_some_and(|n_max| n_max < *n_bots_per_team_min) {
+                    self.error(
+                        &["players", "n_bots_per_team_max"],
+                        "`n_bots_per_team_max` must not be less than `n_bots_per_team_min`",
+                    );
+                }
+
+                let n_teams = n_teams_max.unwrap_or(*n_teams_min);
+                if team_names.is_empty() {
+                    self.error(
+                        &["players", "team_names"],
+                        "`team_names` must not be empty in teams mode",
+                    );
+                } else if team_names.len() < n_teams {
+                    self.error(
+                        &["players", "team_names"],
+                        format!("Need at least {n_teams} team names"),
+                    );
+                }
+                if !team_colors.is_empty() && team_colors.len() < n_teams {
+                    self.error(
+                        &["players", "team_colors"],
+                        format!("Need at least {n_teams} team colors"),
+                    );
+                }
+            }
+        }
+    }
+
+    fn validate_commit(&mut self, path: &[&str], commit: &str) {
+        if !crate::project::is_full_commit_hash(commit) {
+            self.error(
+                path,
+                format!("'{commit}' is not a full commit hash, expected 40 hexadecimal digits"),
+            );
+        }
+    }
+
+    fn validate_catalog(&mut self, config: &GameConfig) {
+        if let Some(version) = &config.catalog.min_launcher_version {
+            if parse_version(version).is_none() {
+                self.error(
+                    &["catalog", "min_launcher_version"],
+                    format!("Invalid launcher version '{version}', expected e.g. \"0.2.0\""),
+                );
+            }
+        }
+    }
+
+    fn validate_translations(&mut self, config: &GameConfig) {
+        for (language, translation) in &config.translations {
+            let valid_code = !language.is_empty()
+                && language.split('-').all(|part| {
+                    !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric())
+                });
+            if !valid_code {
+                self.error(
+                    &["translations", language],
+                    format!(
+                        "Invalid language code '{language}', expected e.g. \"de\" or \"pt-BR\""
+                    ),
+                );
+            }
+            for key in translation.template_args.keys() {
+                if !config.bot.template_args.contains_key(key) {
+                    self.error(
+                        &["translations", language, "template_args", key],
+                        format!("Unknown template arg '{key}'"),
+                    );
+                }
+            }
+        }
+    }
+
+    fn error(&mut self, path: &[&str], message: impl Into<String>) {
+        let span = self
+            .find_span(path)
+            .map(|span| Span::from_offsets(self.content, span));
+        self.errors.push(ValidationError {
+            message: message.into(),
+            span,
+        });
+    }
+
+    fn offset(&self, path: &[&str]) -> usize {
+        self.find_span(path).map_or(usize::MAX, |span| span.start)
+    }
+
+    /// Find the span of the value at `path` or of its closest existing parent.
+    fn find_span(&self, path: &[&str]) -> Option<Range<usize>> {
+        let mut item = self.document.as_item();
+        let mut span = None;
+        for key in path {
+            match item.get(key) {
+                Some(child) => {
+                    span = child.span().or(span);
+                    item = child;
+                }
+                None => break,
+            }
+        }
+        span
+    }
+}
+
+impl Span {
+    fn from_offsets(content: &str, span: Range<usize>) -> Self {
+        Self {
+            start: Location::from_offset(content, span.start),
+            end: Location::from_offset(content, span.end),
+        }
+    }
+}
+
+impl Location {
+    fn from_offset(content: &str, offset: usize) -> Self {
+        let before = &content[..offset.min(content.len())];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const VALID: &str = r#"name = "Test"
+
+[python]
+version = "3.13"
+
+[game]
+url = "https://example.com/game.git"
+base_config_in_repo = "config.toml"
+launch_args = ["game", "{config}"]
+
+[bot]
+template_url = "https://example.com/bot.git"
+
+[bot.template_args]
+color = { type = "color", display = "Color", var = "BOT_COLOR" }
+
+[players]
+mode = "free-for-all"
+n_min = 2
+"#;
+
+    fn errors(content: &str) -> Vec<ValidationError> {
+        GameConfig::validate_toml_str(content).unwrap_err().0
+    }
+
+    #[test]
+    fn valid_config_passes() {
+        assert!(GameConfig::validate_toml_str(VALID).is_ok());
+    }
+
+    #[test]
+    fn bundled_test_game_is_valid() {
+        let content = include_str!("../../../resources/games/test_game.toml");
+        assert!(GameConfig::validate_toml_str(content).is_ok());
+    }
+
+    #[test]
+    fn serde_errors_have_location() {
+        let content = VALID.replace(r#"version = "3.13""#, "version = 3.13");
+        let errors = errors(&content);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].span.unwrap().start.line, 4);
+    }
+
+    #[test]
+    fn n_min_greater_than_n_max_is_reported_at_n_max() {
+        let content = VALID.replace("n_min = 2", "n_min = 3\nn_max = 2");
+        let errors = errors(&content);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].span,
+            Some(Span {
+                start: Location {
+                    line: 20,
+                    column: 9
+                },
+                end: Location {
+                    line: 20,
+                    column: 10
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn n_initial_must_be_positive() {
+        let content = VALID.replace("n_min = 2", "n_min = 2\nn_initial = 0");
+        let errors = errors(&content);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.contains("n_initial"));
+        assert_eq!(errors[0].span.unwrap().start.line, 20);
+    }
+
+    #[test]
+    fn launch_args_require_config_placeholder() {
+        let content = VALID.replace(r#"["game", "{config}"]"#, r#"["game"]"#);
+        let errors = errors(&content);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].span.unwrap().start,
+            Location {
+                line: 9,
+                column: 15
+            }
+        );
+    }
+
+    #[test]
+    fn template_args_must_not_use_builtin_vars() {
+        let content = VALID.replace(
+            r#"var = "BOT_COLOR" }"#,
+            "var = \"BOT_COLOR\" }\nnick = { display = \"Nickname\", var = \"name\" }",
+        );
+        let errors = errors(&content);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.contains("'name'"));
+        assert_eq!(errors[0].span.unwrap().start.line, 16);
+    }
+
+    #[test]
+    fn translations_must_refer_to_existing_template_args() {
+        let content = format!(
+            "{VALID}\n[translations.de.template_args]\ncolor = {{ display = \"Farbe\" }}\nsize = {{ display = \"Größe\" }}\n"
+        );
+        let errors = errors(&content);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message, "Unknown template arg 'size'");
+        assert_eq!(errors[0].span.unwrap().start.line, 23);
+    }
+
+    #[test]
+    fn template_arg_defaults_are_checked() {
+        let content = VALID.replace(
+            r#"var = "BOT_COLOR" }"#,
+            "var = \"BOT_COLOR\" }\nlevel = { type = \"integer\", display = \"Level\", var = \"LEVEL\", min = 1, max = 3, default = 5 }",
+        );
+        let errors = errors(&content);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message, "Invalid default: Must be at most 3");
+        assert_eq!(errors[0].span.unwrap().start.line, 16);
+    }
+
+    #[test]
+    fn choice_args_need_options() {
+        let content = VALID.replace(
+            r#"var = "BOT_COLOR" }"#,
+            "var = \"BOT_COLOR\" }\nmode = { type = \"choice\", display = \"Mode\", var = \"MODE\" }",
+        );
+        let errors = errors(&content);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.contains("option"));
+    }
+
+    #[test]
+    fn render_conditions_may_use_template_manifest_args() {
+        let content = format!(
+            "{VALID}\n[[bot.render.conditional]]\npath = \"numpy.py\"\nwhen = \"!USE_NUMPY\"\n"
+        );
+        assert!(GameConfig::validate_toml_str(&content).is_ok());
+    }
+
+    #[test]
+    fn render_conditions_must_name_a_variable() {
+        let content =
+            format!("{VALID}\n[[bot.render.conditional]]\npath = \"numpy.py\"\nwhen = \"!\"\n");
+        let errors = errors(&content);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message, "Condition '!' does not name a variable");
+    }
+
+    #[test]
+    fn teams_require_team_names() {
+        let content = VALID.replace(
+            "mode = \"free-for-all\"\nn_min = 2",
+            "mode = \"teams\"\nn_teams_min = 2\nn_bots_per_team_min = 1",
+        );
+        let errors = errors(&content);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.contains("team_names"));
+        // Reported at the closest existing table.
+        assert_eq!(errors[0].span.unwrap().start.line, 17);
+    }
+
+    #[test]
+    fn all_problems_are_reported() {
+        let content = VALID
+            .replace(r#"["game", "{config}"]"#, "[]")
+            .replace("n_min = 2", "n_min = 0");
+        assert_eq!(errors(&content).len(), 2);
+    }
+
+    #[test]
+    fn commit_must_be_a_hash() {
+        let content = VALID.replace(
+            r#"launch_args = ["game", "{config}"]"#,
+            "launch_args = [\"game\", \"{config}\"]\ncommit = \"main\"",
+        );
+        let branch_errors = errors(&content);
+        assert_eq!(branch_errors.len(), 1);
+        assert_eq!(branch_errors[0].span.unwrap().start.line, 10);
+
+        let content = VALID.replace(
+            r#"template_url = "https://example.com/bot.git""#,
+            "template_url = \"https://example.com/bot.git\"\ntemplate_commit = \"3f2a9c1\"",
+        );
+        // Abbreviated hashes are too easy to collide.
+        assert_eq!(errors(&content).len(), 1);
+
+        let content = VALID.replace(
+            r#"template_url = "https://example.com/bot.git""#,
+            "template_url = \"https://example.com/bot.git\"\n\
+             template_commit = \"3f2a9c1e0b7d4c5a6f8e9d0c1b2a3f4e5d6c7b8a\"",
+        );
+        assert!(GameConfig::validate_toml_str(&content).is_ok());
+    }
+
+    #[test]
+    fn min_launcher_version_must_be_a_version() {
+        let content = format!("{VALID}\n[catalog]\nmin_launcher_version = \"latest\"\n");
+        let errors = errors(&content);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].span.unwrap().start.line, 22);
+    }
+
+    #[test]
+    fn display_includes_location() {
+        let error = ValidationError {
+            message: "bad".into(),
+            span: Some(Span {
+                start: Location { line: 3, column: 7 },
+                end: Location { line: 3, column: 9 },
+            }),
+        };
+        assert_eq!(error.to_string(), "3:7: bad");
+    }
+}
@@ -5,4 +5,4 @@ mod venv;
 
 pub use cache::Cache;
 pub use install::{RequirementsSource, install};
-pub use venv::VirtualEnvironment;
+pub use venv::{VirtualEnvironment, python_is_installed};
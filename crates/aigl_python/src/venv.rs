@@ -1,16 +1,16 @@
 use crate::cache::Cache;
 use crate::settings::uv_network_settings;
-use anyhow::Result;
-use std::path::PathBuf;
+use anyhow::{Result, bail};
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::str::FromStr;
 use uv::{commands, printer::Printer};
 use uv_configuration::{Concurrency, IndexStrategy, KeyringProviderType, Preview};
 use uv_distribution_types::{DependencyMetadata, IndexLocations};
 use uv_install_wheel::LinkMode;
+use uv_python::managed::ManagedPythonInstallations;
 use uv_python::{
-    ImplementationName, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest,
-    VersionRequest,
+    PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest, VersionRequest,
 };
 use uv_resolver::{ExcludeNewer, ExcludeNewerPackage};
 use uv_settings::PythonInstallMirrors;
@@ -21,18 +21,25 @@ pub struct VirtualEnvironment {
 }
 
 impl VirtualEnvironment {
-    pub async fn create(root: PathBuf, python_request: &str, cache: &Cache) -> Result<Self> {
-        let python_request = PythonRequest::ImplementationVersion(
-            ImplementationName::CPython,
-            VersionRequest::from_str(python_request)?,
-        );
+    /// Create a virtual environment in `root`.
+    ///
+    /// Python is downloaded into `python_install_dir` if no matching version is installed there.
+    /// The directory is passed to uv explicitly instead of through `UV_PYTHON_INSTALL_DIR`
+    /// so that projects can be set up concurrently.
+    pub async fn create(
+        root: PathBuf,
+        python_request: &str,
+        python_install_dir: &Path,
+        cache: &Cache,
+    ) -> Result<Self> {
+        let python = find_or_install_python(python_request, python_install_dir, &root).await?;
         let _ = commands::venv(
             &root,
             Some(root.clone()),
-            Some(python_request),
+            Some(PythonRequest::File(python)),
             PythonInstallMirrors::default(),
             PythonPreference::OnlyManaged,
-            PythonDownloads::Automatic,
+            PythonDownloads::Never,
             LinkMode::default(),
             &IndexLocations::default(),
             IndexStrategy::default(),
@@ -80,3 +87,67 @@ impl VirtualEnvironment {
         self.python_environment.clone()
     }
 }
+
+/// Check if a managed Python in `install_dir` matches `python_request`.
+///
+/// [`VirtualEnvironment::create`] does not download Python again in this case.
+pub fn python_is_installed(python_request: &str, install_dir: &Path) -> Result<bool> {
+    let version_request = VersionRequest::from_str(python_request)?;
+    Ok(find_managed_python(&version_request, install_dir)?.is_some())
+}
+
+/// Find the executable of a managed Python in `install_dir` that matches `python_request`.
+///
+/// Installs Python into `install_dir` first if there is no match.
+async fn find_or_install_python(
+    python_request: &str,
+    install_dir: &Path,
+    project_dir: &Path,
+) -> Result<PathBuf> {
+    let version_request = VersionRequest::from_str(python_request)?;
+    if let Some(python) = find_managed_python(&version_request, install_dir)? {
+        return Ok(python);
+    }
+
+    let _ = commands::python_install(
+        project_dir,
+        Some(install_dir.to_path_buf()),
+        vec![format!("cpython@{python_request}")],
+        false,
+        false,
+        Some(false), // Do not add executables to the user's PATH.
+        Some(false), // Do not register the installation on Windows.
+        false,
+        None,
+        None,
+        None,
+        uv_network_settings(),
+        false,
+        PythonDownloads::Automatic,
+        true,
+        Preview::default(),
+        Printer::Default, // TODO probably need more silent
+    )
+    .await?;
+
+    match find_managed_python(&version_request, install_dir)? {
+        Some(python) => Ok(python),
+        None => bail!(
+            "Python {python_request} was not found in {} after installing it",
+            install_dir.display()
+        ),
+    }
+}
+
+fn find_managed_python(
+    version_request: &VersionRequest,
+    install_dir: &Path,
+) -> Result<Option<PathBuf>> {
+    if !install_dir.exists() {
+        return Ok(None);
+    }
+    Ok(ManagedPythonInstallations::from_path(install_dir)
+        .find_all()?
+        .find(|installation| version_request.matches_version(&installation.version()))
+        .map(|installation| installation.executable(false)))
+}
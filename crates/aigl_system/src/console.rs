@@ -0,0 +1,20 @@
+/// Write the output of this process to the console of the process that started it.
+///
+/// Release builds on Windows use the GUI subsystem and have no console, so the output
+/// of commands would be lost when running them in a terminal.
+/// Does nothing on other platforms or if the parent has no console.
+pub fn attach_to_parent_console() {
+    #[cfg(target_os = "windows")]
+    {
+        const ATTACH_PARENT_PROCESS: u32 = u32::MAX;
+        #[link(name = "kernel32")]
+        unsafe extern "system" {
+            fn AttachConsole(process_id: u32) -> i32;
+        }
+        // Safety: AttachConsole only fails if there is no console to attach to,
+        // in which case the output stays hidden like before.
+        unsafe {
+            AttachConsole(ATTACH_PARENT_PROCESS);
+        }
+    }
+}
@@ -0,0 +1,196 @@
+use anyhow::{Context, Result, bail};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// An application that opens URLs and files with the given types, registered with a
+/// [desktop entry](https://specifications.freedesktop.org/desktop-entry-spec/latest/).
+#[derive(Clone, Debug)]
+pub struct MimeHandler<'a> {
+    /// File stem of the desktop entry and MIME package, e.g., "aigl".
+    pub id: &'a str,
+    pub name: &'a str,
+    pub executable: &'a Path,
+    /// URL schemes without `://`.
+    pub url_schemes: &'a [&'a str],
+    pub file_types: &'a [FileType<'a>],
+}
+
+/// A file type that is recognized by its extension.
+#[derive(Clone, Debug)]
+pub struct FileType<'a> {
+    /// E.g., "application/x-aigl".
+    pub mime_type: &'a str,
+    /// Description of the file type shown in file managers.
+    pub comment: &'a str,
+    /// Extension without the leading dot.
+    pub extension: &'a str,
+}
+
+impl MimeHandler<'_> {
+    /// Install the handler for the current user and make it the default for its types.
+    ///
+    /// Writes into the XDG data directory, usually `~/.local/share`.
+    /// Returns the path of the desktop entry.
+    pub fn install(&self) -> Result<PathBuf> {
+        let Some(data_dir) = dirs::data_dir() else {
+            bail!("Cannot determine the user data directory");
+        };
+        let applications_dir = data_dir.join("applications");
+        let entry_path = applications_dir.join(self.desktop_file_name());
+        write_file(&entry_path, &self.desktop_entry())?;
+
+        if !self.file_types.is_empty() {
+            let mime_dir = data_dir.join("mime");
+            write_file(
+                &mime_dir.join("packages").join(format!("{}.xml", self.id)),
+                &self.mime_package(),
+            )?;
+            run_tool(Command::new("update-mime-database").arg(&mime_dir))?;
+        }
+        // Only updates a cache, so it is fine if the tool is not installed.
+        let _ = Command::new("update-desktop-database")
+            .arg(&applications_dir)
+            .status();
+        run_tool(
+            Command::new("xdg-mime")
+                .arg("default")
+                .arg(self.desktop_file_name())
+                .args(self.mime_types()),
+        )?;
+        Ok(entry_path)
+    }
+
+    fn desktop_file_name(&self) -> String {
+        format!("{}.desktop", self.id)
+    }
+
+    fn mime_types(&self) -> Vec<String> {
+        self.url_schemes
+            .iter()
+            .map(|scheme| format!("x-scheme-handler/{scheme}"))
+            .chain(self.file_types.iter().map(|ty| ty.mime_type.to_owned()))
+            .collect()
+    }
+
+    pub fn desktop_entry(&self) -> String {
+        let mime_types: String = self
+            .mime_types()
+            .iter()
+            .map(|mime_type| format!("{mime_type};"))
+            .collect();
+        format!(
+            "[Desktop Entry]\n\
+             Type=Application\n\
+             Name={}\n\
+             Exec={} %u\n\
+             Terminal=false\n\
+             Categories=Game;\n\
+             MimeType={mime_types}\n",
+            self.name,
+            quote_exec_arg(&self.executable.to_string_lossy()),
+        )
+    }
+
+    /// A [shared MIME info](https://specifications.freedesktop.org/shared-mime-info-spec/latest/)
+    /// package that defines the file types.
+    pub fn mime_package(&self) -> String {
+        let mut xml = String::from(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <mime-info xmlns=\"http://www.freedesktop.org/standards/shared-mime-info\">\n",
+        );
+        for file_type in self.file_types {
+            xml.push_str(&format!(
+                "  <mime-type type=\"{}\">\n    <comment>{}</comment>\n    <glob pattern=\"*.{}\"/>\n  </mime-type>\n",
+                escape_xml(file_type.mime_type),
+                escape_xml(file_type.comment),
+                escape_xml(file_type.extension),
+            ));
+        }
+        xml.push_str("</mime-info>\n");
+        xml
+    }
+}
+
+fn write_file(path: &Path, content: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn run_tool(command: &mut Command) -> Result<()> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let status = command
+        .status()
+        .with_context(|| format!("Failed to run {program}"))?;
+    if !status.success() {
+        bail!("{program} failed with {status}");
+    }
+    Ok(())
+}
+
+/// Quote an argument of the `Exec` key as required by the desktop entry spec.
+fn quote_exec_arg(arg: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in arg.chars() {
+        if matches!(c, '"' | '`' | '$' | '\\') {
+            quoted.push('\\');
+        }
+        // A literal percent sign must be written as `%%`.
+        if c == '%' {
+            quoted.push('%');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn handler(executable: &Path) -> MimeHandler<'_> {
+        MimeHandler {
+            id: "aigl",
+            name: "AI Game Launcher",
+            executable,
+            url_schemes: &["aigl"],
+            file_types: &[FileType {
+                mime_type: "application/x-aigl",
+                comment: "AI game",
+                extension: "aigl",
+            }],
+        }
+    }
+
+    #[test]
+    fn desktop_entry_handles_schemes_and_file_types() {
+        let executable = Path::new("/opt/my games/100% $aigl");
+        assert_eq!(
+            handler(executable).desktop_entry(),
+            "[Desktop Entry]\n\
+             Type=Application\n\
+             Name=AI Game Launcher\n\
+             Exec=\"/opt/my games/100%% \\$aigl\" %u\n\
+             Terminal=false\n\
+             Categories=Game;\n\
+             MimeType=x-scheme-handler/aigl;application/x-aigl;\n"
+        );
+    }
+
+    #[test]
+    fn mime_package_defines_file_types() {
+        let package = handler(Path::new("/usr/bin/aigl")).mime_package();
+        assert!(package.contains("<mime-type type=\"application/x-aigl\">"));
+        assert!(package.contains("<glob pattern=\"*.aigl\"/>"));
+    }
+}
@@ -0,0 +1,103 @@
+use anyhow::Context;
+use std::path::{Path, PathBuf};
+
+/// File systems that are accessed over the network.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+const NETWORK_FILE_SYSTEMS: [&str; 9] = [
+    "nfs",
+    "nfs4",
+    "cifs",
+    "smb3",
+    "smbfs",
+    "fuse.sshfs",
+    "afs",
+    "ceph",
+    "glusterfs",
+];
+
+/// Free space in bytes on the disk that `path` is or would be created on.
+pub fn available_space(path: &Path) -> anyhow::Result<u64> {
+    let existing = existing_ancestor(path)?;
+    fs2::available_space(&existing)
+        .with_context(|| format!("Failed to get the free space of {}", existing.display()))
+}
+
+/// Check if `path` is or would be created on a network drive.
+///
+/// On Windows, only UNC paths like `\\server\share` are detected, not mapped drives.
+pub fn is_network_path(path: &Path) -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        let path = path.as_os_str().to_string_lossy();
+        // Verbatim paths like `\\?\C:\` are local unless they are UNC paths.
+        path.starts_with(r"\\?\UNC\")
+            || (path.starts_with(r"\\") && !path.starts_with(r"\\?\") && !path.starts_with(r"\\.\"))
+    }
+    #[cfg(target_os = "linux")]
+    {
+        let (Ok(path), Ok(mounts)) = (
+            existing_ancestor(path).and_then(|path| Ok(path.canonicalize()?)),
+            std::fs::read_to_string("/proc/mounts"),
+        ) else {
+            return false;
+        };
+        mount_is_network(&mounts, &path)
+    }
+    #[cfg(not(any(target_os = "windows", target_os = "linux")))]
+    {
+        let _ = path;
+        false
+    }
+}
+
+/// The closest ancestor of `path` (including itself) that exists.
+fn existing_ancestor(path: &Path) -> anyhow::Result<PathBuf> {
+    let path = std::path::absolute(path)?;
+    path.ancestors()
+        .find(|ancestor| ancestor.exists())
+        .map(Path::to_path_buf)
+        .ok_or_else(|| anyhow::anyhow!("No part of {} exists", path.display()))
+}
+
+/// Check if the mount point in `mounts` (in the format of `/proc/mounts`)
+/// that contains `path` has a network file system.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn mount_is_network(mounts: &str, path: &Path) -> bool {
+    mounts
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let _device = fields.next()?;
+            // Spaces in mount points are escaped as octal.
+            let mount_point = PathBuf::from(fields.next()?.replace("\\040", " "));
+            let file_system = fields.next()?;
+            Some((mount_point, file_system))
+        })
+        .filter(|(mount_point, _)| path.starts_with(mount_point))
+        .max_by_key(|(mount_point, _)| mount_point.components().count())
+        .is_some_and(|(_, file_system)| NETWORK_FILE_SYSTEMS.contains(&file_system))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOUNTS: &str = "/dev/nvme0n1p2 / ext4 rw,relatime 0 0
+server:/export/home /home/shared nfs4 rw,relatime 0 0
+//nas/games /mnt/my\\040games cifs rw 0 0
+tmpfs /home/shared/tmp tmpfs rw 0 0
+";
+
+    #[test]
+    fn network_mounts_are_detected() {
+        assert!(mount_is_network(MOUNTS, Path::new("/home/shared/ai-game")));
+        assert!(mount_is_network(MOUNTS, Path::new("/mnt/my games/ai")));
+    }
+
+    #[test]
+    fn nested_local_mounts_are_not_network_mounts() {
+        assert!(!mount_is_network(MOUNTS, Path::new("/home/me/ai-game")));
+        assert!(!mount_is_network(MOUNTS, Path::new("/home/shared/tmp/ai")));
+        assert!(!mount_is_network(MOUNTS, Path::new("/home/sharedness")));
+    }
+}
@@ -1,5 +1,7 @@
 use anyhow::{Context, bail};
-use std::fs;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use std::fs::{self, File, FileTimes};
+use std::io;
 use std::path::{Path, PathBuf};
 
 pub async fn create_output_directory(path: &Path) -> anyhow::Result<()> {
@@ -30,25 +32,522 @@ pub fn directory_is_empty(path: &Path) -> bool {
     children.next().is_none()
 }
 
+/// Options of [`copy_dir`].
+#[derive(Clone, Debug, Default)]
+pub struct CopyOptions {
+    /// Patterns in `.gitignore` syntax of entries to skip, relative to the source directory.
+    pub ignore: Vec<String>,
+    /// Also skip the entries that `.gitignore` files in the source directory ignore.
+    pub gitignore: bool,
+}
+
+/// Progress of [`copy_dir`], reported after each copied entry.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CopyProgress {
+    pub entries_copied: usize,
+    pub entries_total: usize,
+    pub bytes_copied: u64,
+    pub bytes_total: u64,
+}
+
 pub async fn copy_dir_recursive(
     src: impl Into<PathBuf>,
     dst: impl Into<PathBuf>,
+) -> anyhow::Result<()> {
+    copy_dir(src, dst, CopyOptions::default(), |_| {}).await
+}
+
+/// Copy the contents of `src` into `dst`, creating `dst` if needed.
+///
+/// Symlinks are copied as links, and files keep their permissions and modification times.
+/// Links with absolute targets or targets outside of `src` are skipped because code that
+/// writes to the copy would otherwise modify files outside of `dst`.
+/// On error, the entries created so far are removed again; entries that already existed in
+/// `dst` are left alone but may have been overwritten.
+pub async fn copy_dir(
+    src: impl Into<PathBuf>,
+    dst: impl Into<PathBuf>,
+    options: CopyOptions,
+    mut progress: impl FnMut(&CopyProgress) + Send + 'static,
 ) -> anyhow::Result<()> {
     let src = src.into();
     let dst = dst.into();
-    tokio::task::spawn_blocking(move || copy_dir_recursive_blocking(&src, &dst)).await?
+    tokio::task::spawn_blocking(move || copy_dir_blocking(&src, &dst, &options, &mut progress))
+        .await?
+}
+
+pub fn copy_dir_blocking(
+    src: &Path,
+    dst: &Path,
+    options: &CopyOptions,
+    progress: &mut dyn FnMut(&CopyProgress),
+) -> anyhow::Result<()> {
+    let entries = collect_entries(src, options)
+        .with_context(|| format!("When listing files to copy from {}", src.display()))?;
+    let mut created = Vec::new();
+    let result = copy_entries(src, dst, &entries, &mut created, progress);
+    if result.is_err() {
+        remove_created(&created);
+    }
+    result.with_context(|| format!("When copying {} to {}", src.display(), dst.display()))
 }
 
-fn copy_dir_recursive_blocking(src: &Path, dst: &Path) -> anyhow::Result<()> {
-    fs::create_dir_all(dst)?;
-    for entry in fs::read_dir(src)? {
-        let entry = entry?;
-        let ty = entry.file_type()?;
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum EntryKind {
+    Dir,
+    File,
+    Symlink,
+}
+
+#[derive(Debug)]
+struct CopyEntry {
+    /// Path relative to the source directory.
+    path: PathBuf,
+    kind: EntryKind,
+    len: u64,
+}
+
+/// Matches entries against the explicit patterns and the `.gitignore` files of the
+/// directories that are currently visited, deepest last.
+struct IgnoreStack {
+    patterns: Gitignore,
+    gitignores: Vec<Gitignore>,
+}
+
+impl IgnoreStack {
+    fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        // Like in git, deeper files override shallower ones and negated patterns re-include.
+        std::iter::once(&self.patterns)
+            .chain(self.gitignores.iter().rev())
+            .map(|gitignore| gitignore.matched(path, is_dir))
+            .find(|matched| !matched.is_none())
+            .is_some_and(|matched| matched.is_ignore())
+    }
+}
+
+fn collect_entries(src: &Path, options: &CopyOptions) -> anyhow::Result<Vec<CopyEntry>> {
+    let mut builder = GitignoreBuilder::new(src);
+    for pattern in &options.ignore {
+        builder
+            .add_line(None, pattern)
+            .with_context(|| format!("Invalid ignore pattern '{pattern}'"))?;
+    }
+    let mut ignores = IgnoreStack {
+        patterns: builder.build()?,
+        gitignores: Vec::new(),
+    };
+    let mut entries = Vec::new();
+    collect_dir_entries(src, Path::new(""), options, &mut ignores, &mut entries)?;
+    Ok(entries)
+}
+
+fn collect_dir_entries(
+    src: &Path,
+    relative: &Path,
+    options: &CopyOptions,
+    ignores: &mut IgnoreStack,
+    entries: &mut Vec<CopyEntry>,
+) -> anyhow::Result<()> {
+    let dir = src.join(relative);
+    let gitignore_path = dir.join(".gitignore");
+    let has_gitignore = options.gitignore && gitignore_path.is_file();
+    if has_gitignore {
+        let (gitignore, error) = Gitignore::new(&gitignore_path);
+        if let Some(error) = error {
+            return Err(error).with_context(|| format!("Invalid {}", gitignore_path.display()));
+        }
+        ignores.gitignores.push(gitignore);
+    }
+
+    let mut children = fs::read_dir(&dir)?.collect::<io::Result<Vec<_>>>()?;
+    children.sort_by_key(|child| child.file_name());
+    for child in children {
+        let path = relative.join(child.file_name());
+        let ty = child.file_type()?;
+        if ignores.is_ignored(&src.join(&path), ty.is_dir()) {
+            continue;
+        }
         if ty.is_dir() {
-            copy_dir_recursive_blocking(&entry.path(), &dst.join(entry.file_name()))?;
+            entries.push(CopyEntry {
+                path: path.clone(),
+                kind: EntryKind::Dir,
+                len: 0,
+            });
+            collect_dir_entries(src, &path, options, ignores, entries)?;
+        } else if ty.is_symlink() {
+            if !link_stays_inside(&path, &fs::read_link(child.path())?) {
+                continue;
+            }
+            entries.push(CopyEntry {
+                path,
+                kind: EntryKind::Symlink,
+                len: 0,
+            });
         } else {
-            fs::copy(entry.path(), dst.join(entry.file_name()))?;
+            entries.push(CopyEntry {
+                path,
+                kind: EntryKind::File,
+                len: child.metadata()?.len(),
+            });
+        }
+    }
+
+    if has_gitignore {
+        ignores.gitignores.pop();
+    }
+    Ok(())
+}
+
+/// Check if a link at `link` pointing to `target` resolves to a path inside the root
+/// that `link` is relative to.
+///
+/// The check is lexical, which is enough because all links in the root are checked.
+fn link_stays_inside(link: &Path, target: &Path) -> bool {
+    use std::path::Component;
+
+    let mut depth = link.components().count().saturating_sub(1);
+    for component in target.components() {
+        match component {
+            Component::Normal(_) => depth += 1,
+            Component::CurDir => {}
+            Component::ParentDir => match depth.checked_sub(1) {
+                Some(parent) => depth = parent,
+                None => return false,
+            },
+            Component::RootDir | Component::Prefix(_) => return false,
         }
     }
+    true
+}
+
+fn copy_entries(
+    src: &Path,
+    dst: &Path,
+    entries: &[CopyEntry],
+    created: &mut Vec<PathBuf>,
+    progress: &mut dyn FnMut(&CopyProgress),
+) -> anyhow::Result<()> {
+    // Only the outermost missing ancestor has to be removed on rollback.
+    if let Some(missing) = dst.ancestors().take_while(|path| !path.exists()).last() {
+        fs::create_dir_all(dst)?;
+        created.push(missing.to_path_buf());
+    }
+
+    let mut state = CopyProgress {
+        entries_total: entries.len(),
+        bytes_total: entries.iter().map(|entry| entry.len).sum(),
+        ..Default::default()
+    };
+    for entry in entries {
+        let from = src.join(&entry.path);
+        let to = dst.join(&entry.path);
+        copy_entry(&from, &to, entry.kind, created)
+            .with_context(|| format!("When copying {}", from.display()))?;
+        state.entries_copied += 1;
+        state.bytes_copied += entry.len;
+        progress(&state);
+    }
+
+    // Read-only directories would prevent copying their contents, so set permissions last.
+    for entry in entries.iter().filter(|entry| entry.kind == EntryKind::Dir) {
+        let permissions = fs::metadata(src.join(&entry.path))?.permissions();
+        fs::set_permissions(dst.join(&entry.path), permissions)?;
+    }
     Ok(())
 }
+
+fn copy_entry(
+    from: &Path,
+    to: &Path,
+    kind: EntryKind,
+    created: &mut Vec<PathBuf>,
+) -> anyhow::Result<()> {
+    let existing = fs::symlink_metadata(to).ok();
+    let existed = existing.is_some();
+    match kind {
+        EntryKind::Dir => {
+            if existing.is_some_and(|meta| meta.is_dir()) {
+                return Ok(());
+            }
+            fs::create_dir(to)?;
+        }
+        EntryKind::File => {
+            let metadata = fs::metadata(from)?;
+            // Replace links instead of writing to their targets.
+            if existing.is_some_and(|meta| meta.is_symlink()) {
+                fs::remove_file(to)?;
+            }
+            let mut reader = File::open(from)?;
+            let mut writer = File::create(to)?;
+            if !existed {
+                created.push(to.to_path_buf());
+            }
+            io::copy(&mut reader, &mut writer)?;
+            writer.set_times(FileTimes::new().set_modified(metadata.modified()?))?;
+            // Set after writing, so that read-only files can be written.
+            fs::set_permissions(to, metadata.permissions())?;
+            return Ok(());
+        }
+        EntryKind::Symlink => {
+            if existing.is_some_and(|meta| !meta.is_dir()) {
+                fs::remove_file(to)?;
+            }
+            create_symlink(&fs::read_link(from)?, from, to)?;
+        }
+    }
+    if !existed {
+        created.push(to.to_path_buf());
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn create_symlink(target: &Path, _original: &Path, link: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(target, link)
+}
+
+#[cfg(windows)]
+fn create_symlink(target: &Path, original: &Path, link: &Path) -> io::Result<()> {
+    // Windows distinguishes links to directories, so check what the original points to.
+    if original.is_dir() {
+        std::os::windows::fs::symlink_dir(target, link)
+    } else {
+        std::os::windows::fs::symlink_file(target, link)
+    }
+}
+
+/// Remove the paths in reverse order, so that children go before their directories.
+///
+/// Best effort, since the error of the copy is more useful than one of the cleanup.
+fn remove_created(created: &[PathBuf]) {
+    for path in created.iter().rev() {
+        let _ = match fs::symlink_metadata(path) {
+            Ok(meta) if meta.is_dir() => fs::remove_dir_all(path),
+            Ok(_) => fs::remove_file(path),
+            Err(_) => continue,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::time::{Duration, SystemTime};
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("aigl-test-copy-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("src")).unwrap();
+        dir
+    }
+
+    fn write(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    fn list(dir: &Path) -> Vec<String> {
+        let mut paths = Vec::new();
+        let mut stack = vec![dir.to_path_buf()];
+        while let Some(current) = stack.pop() {
+            for entry in fs::read_dir(current).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() && !path.is_symlink() {
+                    stack.push(path.clone());
+                }
+                let relative = path.strip_prefix(dir).unwrap();
+                paths.push(relative.to_string_lossy().replace('\\', "/"));
+            }
+        }
+        paths.sort();
+        paths
+    }
+
+    #[test]
+    fn files_keep_their_metadata() {
+        let dir = test_dir("metadata");
+        let src = dir.join("src");
+        write(&src.join("bot.py"), "print('hi')");
+        write(&src.join("data/weights.bin"), "0123456789");
+        let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(1_600_000_000);
+        File::options()
+            .write(true)
+            .open(src.join("bot.py"))
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+        let mut permissions = fs::metadata(src.join("data/weights.bin"))
+            .unwrap()
+            .permissions();
+        permissions.set_readonly(true);
+        fs::set_permissions(src.join("data/weights.bin"), permissions).unwrap();
+
+        let dst = dir.join("dst/bot");
+        let mut reports = Vec::new();
+        copy_dir_blocking(&src, &dst, &CopyOptions::default(), &mut |progress| {
+            reports.push(progress.clone())
+        })
+        .unwrap();
+
+        assert_eq!(list(&dst), ["bot.py", "data", "data/weights.bin"]);
+        assert_eq!(
+            fs::read_to_string(dst.join("bot.py")).unwrap(),
+            "print('hi')"
+        );
+        assert_eq!(
+            fs::metadata(dst.join("bot.py"))
+                .unwrap()
+                .modified()
+                .unwrap(),
+            modified
+        );
+        assert!(
+            fs::metadata(dst.join("data/weights.bin"))
+                .unwrap()
+                .permissions()
+                .readonly()
+        );
+        assert_eq!(
+            reports.last(),
+            Some(&CopyProgress {
+                entries_copied: 3,
+                entries_total: 3,
+                bytes_copied: 21,
+                bytes_total: 21,
+            })
+        );
+        assert_eq!(reports.len(), 3);
+
+        let mut permissions = fs::metadata(src.join("data/weights.bin"))
+            .unwrap()
+            .permissions();
+        #[allow(clippy::permissions_set_readonly_false)]
+        permissions.set_readonly(false);
+        fs::set_permissions(src.join("data/weights.bin"), permissions.clone()).unwrap();
+        fs::set_permissions(dst.join("data/weights.bin"), permissions).unwrap();
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinks_are_preserved() {
+        let dir = test_dir("symlinks");
+        let src = dir.join("src");
+        write(&src.join("lib/util.py"), "");
+        std::os::unix::fs::symlink("lib/util.py", src.join("util.py")).unwrap();
+        std::os::unix::fs::symlink("lib", src.join("modules")).unwrap();
+        std::os::unix::fs::symlink("missing", src.join("dangling")).unwrap();
+
+        let dst = dir.join("dst");
+        copy_dir_blocking(&src, &dst, &CopyOptions::default(), &mut |_| {}).unwrap();
+
+        for (link, target) in [
+            ("util.py", "lib/util.py"),
+            ("modules", "lib"),
+            ("dangling", "missing"),
+        ] {
+            assert_eq!(fs::read_link(dst.join(link)).unwrap(), Path::new(target));
+        }
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinks_out_of_the_source_are_skipped() {
+        let dir = test_dir("escaping-symlinks");
+        let src = dir.join("src");
+        write(&dir.join("outside.md"), "outside");
+        write(&src.join("sub/inside.md"), "inside");
+        std::os::unix::fs::symlink("../outside.md", src.join("notes.md")).unwrap();
+        std::os::unix::fs::symlink("../../outside.md", src.join("sub/notes.md")).unwrap();
+        std::os::unix::fs::symlink("../sub/../../outside.md", src.join("sub/tricky.md")).unwrap();
+        std::os::unix::fs::symlink(dir.join("outside.md"), src.join("absolute.md")).unwrap();
+        std::os::unix::fs::symlink("../sub/inside.md", src.join("sub/kept.md")).unwrap();
+
+        let dst = dir.join("dst");
+        copy_dir_blocking(&src, &dst, &CopyOptions::default(), &mut |_| {}).unwrap();
+        assert_eq!(list(&dst), ["sub", "sub/inside.md", "sub/kept.md"]);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn ignored_entries_are_skipped() {
+        let dir = test_dir("ignore");
+        let src = dir.join("src");
+        write(&src.join(".gitignore"), "build/\n*.tmp\n!keep.tmp\n");
+        write(&src.join(".git/HEAD"), "");
+        write(&src.join("bot.py"), "");
+        write(&src.join("debug.log"), "");
+        write(&src.join("build/out.txt"), "");
+        write(&src.join("cache.tmp"), "");
+        write(&src.join("keep.tmp"), "");
+        write(&src.join("sub/.gitignore"), "/local.txt\n!*.tmp\n");
+        write(&src.join("sub/local.txt"), "");
+        write(&src.join("sub/nested/local.txt"), "");
+        write(&src.join("sub/nested/cache.tmp"), "");
+
+        let options = CopyOptions {
+            ignore: vec![".git".into(), "*.log".into()],
+            gitignore: true,
+        };
+        let dst = dir.join("dst");
+        copy_dir_blocking(&src, &dst, &options, &mut |_| {}).unwrap();
+        assert_eq!(
+            list(&dst),
+            [
+                ".gitignore",
+                "bot.py",
+                "keep.tmp",
+                "sub",
+                "sub/.gitignore",
+                "sub/nested",
+                "sub/nested/cache.tmp",
+                "sub/nested/local.txt",
+            ]
+        );
+
+        let dst = dir.join("dst-all");
+        copy_dir_blocking(&src, &dst, &CopyOptions::default(), &mut |_| {}).unwrap();
+        assert_eq!(list(&dst).len(), list(&src).len());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn created_entries_are_removed_on_error() {
+        let dir = test_dir("rollback");
+        let src = dir.join("src");
+        write(&src.join("a.txt"), "a");
+        write(&src.join("b/c.txt"), "c");
+        // A file where the copy needs a directory makes it fail after copying `a.txt`.
+        let dst = dir.join("dst");
+        write(&dst.join("b"), "existing");
+
+        assert!(copy_dir_blocking(&src, &dst, &CopyOptions::default(), &mut |_| {}).is_err());
+        assert_eq!(list(&dst), ["b"]);
+        assert_eq!(fs::read_to_string(dst.join("b")).unwrap(), "existing");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn created_destination_is_removed_on_error() {
+        let dir = test_dir("rollback-dst");
+        let src = dir.join("src");
+        write(&src.join("a/b.txt"), "b");
+        // Sockets cannot be opened like files.
+        let _socket = std::os::unix::net::UnixListener::bind(src.join("z.sock")).unwrap();
+
+        let missing = dir.join("missing");
+        let result = copy_dir_blocking(
+            &src,
+            &missing.join("dst"),
+            &CopyOptions::default(),
+            &mut |_| {},
+        );
+        assert!(result.is_err());
+        assert!(!missing.exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
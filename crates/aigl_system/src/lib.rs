@@ -1 +1,6 @@
+pub mod console;
+pub mod desktop;
+pub mod disk;
 pub mod fs;
+pub mod open;
+pub mod process;
@@ -0,0 +1,64 @@
+use anyhow::{Context, bail};
+use std::path::Path;
+use std::process::Command;
+
+/// Open a file or directory with the default application of the operating system.
+pub fn open_in_default_app(path: &Path) -> anyhow::Result<()> {
+    #[cfg(target_os = "windows")]
+    let mut command = Command::new("explorer");
+    #[cfg(target_os = "macos")]
+    let mut command = Command::new("open");
+    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    let mut command = Command::new("xdg-open");
+
+    command
+        .arg(path)
+        .spawn()
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    Ok(())
+}
+
+/// Open a source file in a text editor, at `line` if the editor supports it.
+///
+/// Unlike [`open_in_default_app`], this never hands the file to the file manager
+/// because the default action for some files is to run them,
+/// e.g., for `.py` files on Windows with the Python launcher.
+/// Uses VS Code if it is installed and a plain text editor of the platform otherwise.
+pub fn open_in_editor(path: &Path, line: usize) -> anyhow::Result<()> {
+    #[cfg(target_os = "windows")]
+    const VS_CODE: &str = "code.cmd";
+    #[cfg(not(target_os = "windows"))]
+    const VS_CODE: &str = "code";
+
+    let mut vs_code = Command::new(VS_CODE);
+    vs_code
+        .arg("--goto")
+        .arg(format!("{}:{line}", path.display()));
+    if vs_code.spawn().is_ok() {
+        return Ok(());
+    }
+    for editor in TEXT_EDITORS {
+        let mut command = Command::new(editor[0]);
+        command.args(&editor[1..]).arg(path);
+        if command.spawn().is_ok() {
+            return Ok(());
+        }
+    }
+    bail!("Failed to open {} in a text editor", path.display())
+}
+
+/// Fallback editors as program and arguments that are tried in order.
+#[cfg(target_os = "windows")]
+const TEXT_EDITORS: &[&[&str]] = &[&["notepad"]];
+#[cfg(target_os = "macos")]
+const TEXT_EDITORS: &[&[&str]] = &[&["open", "-t"]];
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+const TEXT_EDITORS: &[&[&str]] = &[
+    &["gnome-text-editor"],
+    &["gedit"],
+    &["kate"],
+    &["kwrite"],
+    &["mousepad"],
+    &["xed"],
+    &["pluma"],
+];
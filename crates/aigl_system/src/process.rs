@@ -0,0 +1,69 @@
+use std::process::{Child, Command};
+
+/// Start the process of `command` in a new process group
+/// so that [`kill_process_tree`] also reaches the processes that it starts.
+pub fn spawn_in_own_group(command: &mut Command) -> std::io::Result<Child> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        command.process_group(0);
+    }
+    command.spawn()
+}
+
+/// Kill a child process and the processes that it has started.
+///
+/// Killing only the child leaves its children running, e.g., the bots of a game,
+/// and they keep the child's output pipes open.
+/// Falls back to killing only the child if the tree cannot be killed.
+pub fn kill_process_tree(child: &mut Child) -> std::io::Result<()> {
+    let id = child.id().to_string();
+    #[cfg(target_os = "windows")]
+    let mut command = {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        let mut command = Command::new("taskkill");
+        command
+            .args(["/T", "/F", "/PID", &id])
+            .creation_flags(CREATE_NO_WINDOW);
+        command
+    };
+    // The group of a process started by `spawn_in_own_group` has the process' id.
+    #[cfg(not(target_os = "windows"))]
+    let mut command = {
+        let mut command = Command::new("kill");
+        command.args(["-KILL", "--", &format!("-{id}")]);
+        command
+    };
+    let killed = command
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success());
+    if killed { Ok(()) } else { child.kill() }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    use std::process::Stdio;
+
+    #[test]
+    fn killing_the_tree_closes_pipes_held_by_grandchildren() {
+        let mut command = Command::new("sh");
+        command
+            .args(["-c", "sleep 30 & sleep 30"])
+            .stdout(Stdio::piped());
+        let mut child = spawn_in_own_group(&mut command).unwrap();
+        let mut stdout = child.stdout.take().unwrap();
+
+        kill_process_tree(&mut child).unwrap();
+        child.wait().unwrap();
+        // Blocks until the background `sleep` is killed as well.
+        let mut output = String::new();
+        stdout.read_to_string(&mut output).unwrap();
+        assert_eq!(output, "");
+    }
+}
@@ -0,0 +1,203 @@
+# Deutsche Texte der Benutzeroberfläche, siehe en.ftl.
+
+app-title = KI-Spiel installieren
+
+## Shared
+
+error = Fehler: { $error }
+cancel = Abbrechen
+browse = Durchsuchen
+play = Spielen
+uninstall = Deinstallieren
+fix = Beheben
+save = Speichern
+saved = Gespeichert
+no-problems = Keine Probleme gefunden.
+check-code = Code prüfen
+run-tests = Tests ausführen
+theme = Farbschema
+theme-system = System
+theme-system-hint = Dunkel oder hell wie das Betriebssystem
+language = Sprache
+language-system = System
+language-system-hint = Die Sprache des Betriebssystems
+search = Suchen
+zoom-in = Vergrößern
+zoom-out = Verkleinern
+zoom-reset = Zoom zurücksetzen
+
+## Screen headings
+
+screen-home = Deine Spiele
+screen-select-game = Spiel auswählen
+screen-add-bot = Bot hinzufügen
+screen-configure-player = Spieler einrichten
+screen-select-location = Installationsort
+screen-overview = Übersicht
+screen-installing = Installation läuft
+screen-finished = Installation abgeschlossen
+screen-doctor = Installation prüfen
+screen-bot-tests = Bot-Tests
+screen-lint = Codequalität
+screen-match-setup = Partie einrichten
+screen-console = Spielausgabe
+
+## Navigation
+
+nav-next = Weiter
+nav-install = Installieren
+nav-finish = Fertig
+nav-back = Zurück
+nav-exit = Beenden
+
+## Home
+
+home-install-new = Neues Spiel installieren
+home-open-folder = Ordner öffnen…
+home-forget-me = Vergiss mich
+home-forget-me-hint = Das letzte Spiel, Name und Farbe deines Bots und den Installationsordner vergessen
+confirm-uninstall = { $path } mit allen deinen Bots löschen? Das kann nicht rückgängig gemacht werden.
+home-removed = { $path } aus der Liste entfernt
+home-added-bot = { $bot } zu { $game } hinzugefügt
+home-updated-game = { $game } aktualisiert
+home-game-up-to-date = { $game } ist aktuell
+home-updated-template = Die Bot-Vorlage wurde aktualisiert, neue Bots verwenden sie
+home-template-up-to-date = Die Bot-Vorlage ist aktuell
+home-uninstalled = { $game } aus { $path } deinstalliert
+projects-empty = Du hast noch keine Spiele installiert.
+projects-bots = Bots:
+projects-add-bot = Bot hinzufügen
+projects-set-up-match = Partie einrichten
+projects-update-game = Spiel aktualisieren
+projects-update-template = Vorlage aktualisieren
+projects-open-folder = Ordner öffnen
+projects-check = Prüfen
+projects-forget = Aus der Liste entfernen
+projects-problems =
+    { $count ->
+        [one] { $count } Problem gefunden, mit Prüfen kannst du es beheben
+       *[other] { $count } Probleme gefunden, mit Prüfen kannst du sie beheben
+    }
+
+## Selecting a game
+
+select-game-choose = Wähle ein Spiel:
+select-game-enter-code = Oder gib einen Spielcode ein:
+select-game-fetch = Laden
+select-game-loaded-from = Geladen von { $source }
+select-game-code-hint = Deine Spielleitung gibt dir den Spielcode, falls das Spiel nicht aufgeführt ist.
+select-game-check-installed = Installiertes Spiel prüfen
+catalog-no-matches = Keine passenden Spiele.
+catalog-requires-version = Benötigt Launcher-Version { $version } oder neuer
+game-name = Spielname:
+verification-published = Veröffentlicht von { $publisher }
+verification-untrusted = Installiere es nur, wenn du der Quelle vertraust.
+
+## Configuring the player's bot
+
+configure-name-prompt = Gib deinem Bot einen eindeutigen Namen:
+configure-name = Name
+configure-package-prompt = Benenne dein Python-Paket (optional):
+configure-package = Paket
+
+## Installing
+
+location-prompt = Wähle einen Ordner für die Installation:
+overview-preparing = Installation wird vorbereitet
+plan-location = Installationsort
+plan-game = Spiel
+plan-bot-template = Bot-Vorlage
+plan-python = Python
+plan-python-download = Python { $version } wird heruntergeladen
+plan-player-bot = Dein Bot
+plan-opponents = Gegner
+plan-opponents-generated = { $count } aus der Bot-Vorlage erzeugt
+plan-players-selected-in = Die Spieler werden ausgewählt in
+plan-venv = Virtuelle Umgebung
+plan-venv-packages = mit bearbeitbaren Installationen von
+plan-size = Größe (grobe Schätzung)
+plan-size-value = etwa { $download } Download, { $disk } auf der Festplatte
+plan-size-hint = Geschätzt aus den Größen der Repositories, die der Host meldet, und typischen Größen der Pakete des Spiels. Python wird nicht erneut heruntergeladen, wenn es schon installiert ist.
+plan-commit-pinned = bei Commit { $commit }
+plan-commit-latest-on = neuester Stand von { $branch }, derzeit { $commit }
+plan-commit-latest = neuester Stand, derzeit { $commit }
+plan-commit-unknown = neuester Stand, der aktuelle Commit ist unbekannt
+installing-into = Installation in { $path }
+installing-thread-missing = Installations-Thread fehlt
+error-caused-by = Verursacht durch:
+error-details = Details
+error-copy-details = Details kopieren
+error-saved-to = Gespeichert in { $path }
+error-open-log = Protokolldatei öffnen
+
+## Finished
+
+finished-installed = { $game } wurde in { $path } installiert
+finished-running = Das Spiel starten
+finished-run-yourself = Oder starte es selbst:
+finished-step-terminal = 1. Öffne ein Terminal im Spielordner
+finished-step-activate = 2. Aktiviere die virtuelle Umgebung:
+finished-step-run = 3. Starte das Spiel mit:
+finished-step-edit = 4. Bearbeite deine KI
+finished-testing = Deine KI testen
+finished-uninstalling = Deinstallieren
+finished-uninstall-hint = Lösche einfach den Spielordner unter
+
+## Tools
+
+doctor-checking = { $path } wird geprüft
+doctor-check-again = Erneut prüfen
+bot-tests-title = Tests von { $bot }
+bot-tests-run-all = Alle ausführen
+bot-tests-rerun-failed = Fehlgeschlagene wiederholen
+tests-captured-output = Aufgezeichnete Ausgabe:
+tests-summary = { $passed } bestanden, { $failed } fehlgeschlagen, { $errors } Fehler, { $skipped } übersprungen
+tests-passed = Bestanden: { $name }
+tests-failed = Fehlgeschlagen: { $name }
+tests-skipped = Übersprungen: { $name }
+lint-title = Code von { $bot }
+lint-format = Code formatieren
+lint-fix-all = Alle beheben
+lint-fix-scope = Behebt alle { $code }-Probleme in { $file }, nicht nur dieses.
+lint-problems =
+    { $count ->
+        [one] { $count } Problem gefunden
+       *[other] { $count } Probleme gefunden
+    }
+match-title = Wähle die Bots für eine Partie in { $path }
+match-needs-range = Das Spiel braucht { $min } bis { $max } Bots.
+match-needs-at-least = Das Spiel braucht mindestens { $min } Bots.
+match-copies = Anzahl der Kopien jedes Bots:
+match-players = Spieler:
+match-remove-player = Diesen Spieler entfernen
+match-name = Name
+match-name-hint = Den Namen aus der Konfiguration des Bots überschreiben
+match-color = Farbe
+match-color-hint = Die Farbe aus der Konfiguration des Bots überschreiben
+console-stop = Stoppen
+console-restart = Neu starten
+console-open-file = Datei öffnen
+
+## Problems
+
+verification-unsigned = Nicht verifizierter Herausgeber: Die Spielkonfiguration ist nicht signiert.
+verification-unknown-signer = Nicht verifizierter Herausgeber: Die Spielkonfiguration ist nicht von einem vertrauenswürdigen Herausgeber signiert oder wurde verändert.
+verification-refused = Spiele von nicht verifizierten Herausgebern werden nicht installiert.
+bot-id-empty = Der Paketname darf nicht leer sein
+bot-id-invalid-start = Der Paketname muss mit einem Buchstaben oder '_' beginnen
+bot-id-invalid-character = Der Paketname darf nur ASCII-Buchstaben, Ziffern und '_' enthalten, nicht '{ $character }'
+bot-id-python-keyword = '{ $id }' ist ein Python-Schlüsselwort
+bot-id-stdlib-module = '{ $id }' ist der Name eines Moduls der Python-Standardbibliothek
+bot-id-reserved = '{ $id }' ist für den Launcher reserviert
+bot-arg-required = { $arg } ist erforderlich
+bot-arg-too-small = Muss mindestens { $min } sein
+bot-arg-too-large = Darf höchstens { $max } sein
+bot-arg-not-an-option = Muss eins von { $options } sein
+fix-recreate-venv = Die virtuelle Umgebung neu erstellen
+fix-reinstall-packages = Das Spiel und die Bots neu installieren
+fix-relocate-paths = Die Pfade auf den aktuellen Ort des Projekts ändern
+fix-remove-unknown-players = Spieler entfernen, die { $packages } verwenden
+plan-warning-unreachable = { $url } ist nicht erreichbar: { $error }
+plan-warning-network-drive = { $path } liegt auf einem Netzlaufwerk, das Spiel kann langsam sein oder abbrechen, wenn die Verbindung unterbrochen wird
+plan-warning-low-space = Nur noch { $available } frei, das Spiel braucht etwa { $needed }
+plan-warning-unknown-space = { $error }
@@ -0,0 +1,210 @@
+# Messages of the launcher's user interface.
+#
+# The messages use the Fluent syntax, see https://projectfluent.org.
+# Every message must also be translated in the other files in this directory.
+
+app-title = Install AI Game
+
+## Shared
+
+error = Error: { $error }
+cancel = Cancel
+browse = Browse
+play = Play
+uninstall = Uninstall
+fix = Fix
+save = Save
+saved = Saved
+no-problems = No problems found.
+check-code = Check code
+run-tests = Run tests
+theme = Theme
+theme-system = System
+theme-system-hint = Dark or light like the operating system
+language = Language
+language-system = System
+language-system-hint = The language of the operating system
+search = Search
+zoom-in = Zoom in
+zoom-out = Zoom out
+zoom-reset = Reset zoom
+
+## Screen headings
+
+screen-home = Your games
+screen-select-game = Select game
+screen-add-bot = Add bot
+screen-configure-player = Configure player
+screen-select-location = Install location
+screen-overview = Overview
+screen-installing = Installing
+screen-finished = Installation finished
+screen-doctor = Check installation
+screen-bot-tests = Bot tests
+screen-lint = Code quality
+screen-match-setup = Set up match
+screen-console = Game output
+
+## Navigation
+
+nav-next = Next
+nav-install = Install
+nav-finish = Finish
+nav-back = Back
+nav-exit = Exit
+
+## Home
+
+home-install-new = Install a new game
+home-open-folder = Open folder…
+home-forget-me = Forget me
+home-forget-me-hint = Forget the last game, your bot's name and color, and the install folder
+confirm-uninstall = Delete { $path } including all of your bots? This cannot be undone.
+home-removed = Removed { $path } from the list
+home-added-bot = Added { $bot } to { $game }
+home-updated-game = Updated { $game }
+home-game-up-to-date = { $game } is up to date
+home-updated-template = Updated the bot template, new bots will use it
+home-template-up-to-date = The bot template is up to date
+home-uninstalled = Uninstalled { $game } from { $path }
+projects-empty = You have not installed any games yet.
+projects-bots = Bots:
+projects-add-bot = Add bot
+projects-set-up-match = Set up match
+projects-update-game = Update game
+projects-update-template = Update template
+projects-open-folder = Open folder
+projects-check = Check
+projects-forget = Remove from list
+projects-problems =
+    { $count ->
+        [one] { $count } problem found
+       *[other] { $count } problems found
+    }, use Check to fix them
+
+## Selecting a game
+
+select-game-choose = Choose a game:
+select-game-enter-code = Or enter a game code:
+select-game-fetch = Fetch
+select-game-loaded-from = Loaded from { $source }
+select-game-code-hint = Your game master will provide the game code if the game is not listed.
+select-game-check-installed = Check an installed game
+catalog-no-matches = No matching games.
+catalog-requires-version = Requires launcher version { $version } or newer
+game-name = Game name:
+verification-published = Published by { $publisher }
+verification-untrusted = Only install it if you trust where it came from.
+
+## Configuring the player's bot
+
+configure-name-prompt = Give your bot a unique name:
+configure-name = Name
+configure-package-prompt = Name your Python package (optional):
+configure-package = Package
+
+## Installing
+
+location-prompt = Select a folder to install into:
+overview-preparing = Preparing the installation
+plan-location = Install location
+plan-game = Game
+plan-bot-template = Bot template
+plan-python = Python
+plan-python-download = Python { $version } will be downloaded
+plan-player-bot = Your bot
+plan-opponents = Opponents
+plan-opponents-generated = { $count } generated from the template bot
+plan-players-selected-in = Players are selected in
+plan-venv = Virtual environment
+plan-venv-packages = with editable installs of
+plan-size = Size (rough estimate)
+plan-size-value = about { $download } download, { $disk } on disk
+plan-size-hint = Estimated from the repository sizes that the host reports and typical sizes of the game's packages. Python is not downloaded again if it is already installed.
+plan-commit-pinned = at commit { $commit }
+plan-commit-latest-on = latest on { $branch }, currently { $commit }
+plan-commit-latest = latest, currently { $commit }
+plan-commit-unknown = latest, the current commit is unknown
+installing-into = Installing into { $path }
+installing-thread-missing = Install thread missing
+error-caused-by = Caused by:
+error-details = Details
+error-copy-details = Copy details
+error-saved-to = Saved to { $path }
+error-open-log = Open the log file
+
+## Finished
+
+finished-installed = Finished installing { $game } into { $path }
+finished-running = Running the game
+finished-run-yourself = Or run it yourself:
+finished-step-terminal = 1. Open a terminal in the game folder
+finished-step-activate = 2. Activate the virtual environment:
+finished-step-run = 3. Run the game using:
+finished-step-edit = 4. Edit your AI
+finished-testing = Testing your AI
+finished-uninstalling = Uninstalling
+finished-uninstall-hint = Simply remove the game folder at
+
+## Tools
+
+doctor-checking = Checking { $path }
+doctor-check-again = Check again
+bot-tests-title = Tests of { $bot }
+bot-tests-run-all = Run all
+bot-tests-rerun-failed = Rerun failed
+tests-captured-output = Captured output:
+tests-summary =
+    { $passed } passed, { $failed } failed, { $errors ->
+        [one] { $errors } error
+       *[other] { $errors } errors
+    }, { $skipped } skipped
+tests-passed = Passed: { $name }
+tests-failed = Failed: { $name }
+tests-skipped = Skipped: { $name }
+lint-title = Code of { $bot }
+lint-format = Format code
+lint-fix-all = Fix all
+lint-fix-scope = Fixes every { $code } problem in { $file }, not only this one.
+lint-problems =
+    { $count ->
+        [one] { $count } problem found
+       *[other] { $count } problems found
+    }
+match-title = Choose the bots for a match in { $path }
+match-needs-range = The game needs { $min } to { $max } bots.
+match-needs-at-least = The game needs at least { $min } bots.
+match-copies = Number of copies of each bot:
+match-players = Players:
+match-remove-player = Remove this player
+match-name = Name
+match-name-hint = Override the name from the bot's config
+match-color = Color
+match-color-hint = Override the color from the bot's config
+console-stop = Stop
+console-restart = Restart
+console-open-file = Open the file
+
+## Problems
+
+verification-unsigned = Unverified publisher: the game config is not signed.
+verification-unknown-signer = Unverified publisher: the game config is not signed by a trusted publisher or it was modified.
+verification-refused = Refusing to install games from unverified publishers.
+bot-id-empty = The package name must not be empty
+bot-id-invalid-start = The package name must start with a letter or '_'
+bot-id-invalid-character = The package name must only contain ASCII letters, digits, and '_', not '{ $character }'
+bot-id-python-keyword = '{ $id }' is a Python keyword
+bot-id-stdlib-module = '{ $id }' is the name of a module in Python's standard library
+bot-id-reserved = '{ $id }' is reserved by the launcher
+bot-arg-required = { $arg } is required
+bot-arg-too-small = Must be at least { $min }
+bot-arg-too-large = Must be at most { $max }
+bot-arg-not-an-option = Must be one of { $options }
+fix-recreate-venv = Re-create the virtual environment
+fix-reinstall-packages = Reinstall the game and bots
+fix-relocate-paths = Update paths to the current project location
+fix-remove-unknown-players = Remove players using { $packages }
+plan-warning-unreachable = Cannot reach { $url }: { $error }
+plan-warning-network-drive = { $path } is on a network drive, the game may be slow or break when the connection is lost
+plan-warning-low-space = Only { $available } of free space left, the game needs about { $needed }
+plan-warning-unknown-space = { $error }
@@ -40,3 +40,18 @@ function setUpTabs() {
     else
         selectTab('#tab_linux', tabs, tabInfos);
 }
+
+// Link that opens the launcher with a game selected, see `aigl --help`.
+function makeInstallLink(gameCode, botName) {
+    let link = `aigl://install/${encodeURIComponent(gameCode)}`;
+    if (botName)
+        link += `?name=${encodeURIComponent(botName)}`;
+    return link;
+}
+
+// Point all `<a data-aigl-game="code" data-aigl-name="optional bot name">` elements at the launcher.
+function setUpInstallLinks() {
+    document.querySelectorAll('a[data-aigl-game]').forEach(anchor => {
+        anchor.href = makeInstallLink(anchor.dataset.aiglGame, anchor.dataset.aiglName);
+    })
+}